@@ -0,0 +1,18 @@
+-- Migration: 003_add_event_token
+-- Records the token of token-denominated events (released, refunded) and
+-- enforces the de-duplication key that `insert_events` relies on.
+
+ALTER TABLE events ADD COLUMN token TEXT;
+
+-- Drop duplicates that slipped in before the unique index existed.
+DELETE FROM events
+WHERE id NOT IN (
+    SELECT MIN(id)
+    FROM   events
+    GROUP  BY ledger, tx_hash, event_type, project_id, token
+);
+
+-- A release pays out every accepted token in one transaction, so the token
+-- is part of the key. COALESCE keeps token-less events de-duplicated too.
+CREATE UNIQUE INDEX IF NOT EXISTS idx_events_dedup
+    ON events (ledger, COALESCE(tx_hash, ''), event_type, COALESCE(project_id, ''), COALESCE(token, ''));
@@ -0,0 +1,4 @@
+-- Migration: 004_add_event_memo
+-- Records the optional donor memo carried by `funded` events.
+
+ALTER TABLE events ADD COLUMN memo TEXT;
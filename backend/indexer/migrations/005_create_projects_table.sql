@@ -0,0 +1,14 @@
+-- Migration: 005_create_projects_table
+-- One row per project, rolled up from its events by the ingest path so
+-- `GET /projects` does not scan the events table.
+
+CREATE TABLE IF NOT EXISTS projects (
+    project_id   TEXT    PRIMARY KEY,
+    status       TEXT    NOT NULL,
+    -- Decimal string, like event amounts, so `i128` values survive.
+    net_balance  TEXT    NOT NULL DEFAULT '0',
+    event_count  INTEGER NOT NULL DEFAULT 0,
+    last_ledger  INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_projects_status ON projects (status);
@@ -0,0 +1,6 @@
+-- Migration: 006_add_safe_ledger
+-- Highest ledger the indexer has fully ingested at the configured
+-- confirmation depth. Events above it may still be reorged away, so ingest
+-- re-reads from the ledger after it on every poll.
+
+ALTER TABLE indexer_cursor ADD COLUMN safe_ledger INTEGER NOT NULL DEFAULT 0;
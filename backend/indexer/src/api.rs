@@ -1,22 +1,35 @@
 //! Axum REST API handlers.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
     http::StatusCode,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     Json,
 };
+use ed25519_dalek::{Signature, VerifyingKey};
 use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
+use tokio::sync::broadcast::{self, error::RecvError};
 
 use crate::db;
-use crate::events::EventRecord;
+use crate::errors::IndexerError;
+use crate::events::{EventRecord, PifpEvent};
+use crate::rate_limit::RateLimiter;
 
-#[derive(Clone)]
 pub struct ApiState {
     pub pool: SqlitePool,
+    /// Events stored by the indexer, fanned out to `GET /ws/events`.
+    pub events_tx: broadcast::Sender<PifpEvent>,
+    /// Throttles `POST /projects/:id/vote` per oracle address.
+    pub vote_limiter: RateLimiter,
+    /// Oracle addresses whose votes are accepted.
+    pub oracles: HashSet<String>,
 }
 
 // ─────────────────────────────────────────────────────────
@@ -34,6 +47,57 @@ pub struct EventsResponse {
 pub struct AllEventsResponse {
     pub count: usize,
     pub events: Vec<EventRecord>,
+    /// Id of the last event in this page; pass it as `after_id` to fetch the
+    /// next one. `None` once there is nothing left.
+    pub next_cursor: Option<i64>,
+}
+
+/// Page size when `limit` is omitted.
+const DEFAULT_PAGE_LIMIT: i64 = 100;
+/// Largest page a client may request.
+const MAX_PAGE_LIMIT: i64 = 1000;
+
+#[derive(Deserialize)]
+pub struct EventsQuery {
+    /// Return only events with an id greater than this.
+    pub after_id: Option<i64>,
+    /// Page size; defaults to 100 and is clamped to 1..=1000.
+    pub limit: Option<i64>,
+}
+
+impl EventsQuery {
+    fn limit(&self) -> i64 {
+        self.limit
+            .unwrap_or(DEFAULT_PAGE_LIMIT)
+            .clamp(1, MAX_PAGE_LIMIT)
+    }
+}
+
+#[derive(Deserialize)]
+pub struct StreamQuery {
+    /// Only forward events for this project.
+    pub project_id: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct BalancesResponse {
+    pub project_id: String,
+    pub balances: Vec<db::TokenBalance>,
+}
+
+#[derive(Serialize)]
+pub struct ProjectsResponse {
+    pub count: usize,
+    pub projects: Vec<db::ProjectSummary>,
+}
+
+#[derive(Serialize)]
+pub struct ConsensusResponse {
+    pub project_id: String,
+    pub consensus_hash: String,
+    pub threshold: u32,
+    /// Votes cast for `consensus_hash`.
+    pub votes: u32,
 }
 
 #[derive(Serialize)]
@@ -42,15 +106,91 @@ pub struct HealthResponse {
     pub version: &'static str,
 }
 
-#[derive(Serialize)]
-pub struct ErrorResponse {
-    pub error: String,
+/// Kind of an API failure, so clients can branch without parsing messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    NotFound,
+    Validation,
+    Unauthorized,
+    RateLimited,
+    Internal,
+}
+
+impl ErrorCode {
+    fn status(self) -> StatusCode {
+        match self {
+            Self::NotFound => StatusCode::NOT_FOUND,
+            Self::Validation => StatusCode::BAD_REQUEST,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            Self::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Error body of every handler: `{ code, message }`, sent with the status
+/// matching `code`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl ApiError {
+    fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NotFound, message)
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Validation, message)
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Unauthorized, message)
+    }
+
+    pub fn rate_limited(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::RateLimited, message)
+    }
+}
+
+impl From<IndexerError> for ApiError {
+    fn from(error: IndexerError) -> Self {
+        // Client input is checked before it reaches these errors; the rest
+        // are failures of the indexer itself.
+        let code = match &error {
+            IndexerError::Database(sqlx::Error::RowNotFound) => ErrorCode::NotFound,
+            IndexerError::Database(_)
+            | IndexerError::Migrate(_)
+            | IndexerError::Http(_)
+            | IndexerError::Json(_)
+            | IndexerError::Config(_)
+            | IndexerError::EventParse(_) => ErrorCode::Internal,
+        };
+        Self::new(code, error.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.code.status(), Json(self)).into_response()
+    }
 }
 
 #[derive(Deserialize)]
 pub struct VoteRequest {
     pub oracle: String,
     pub proof_hash: String,
+    /// Hex ed25519 signature by `oracle` over [`vote_message`].
+    pub signature: String,
 }
 
 #[derive(Deserialize)]
@@ -64,6 +204,115 @@ pub struct VoteResponse {
     pub message: String,
 }
 
+impl VoteRequest {
+    /// Reject votes whose fields would not match an honest vote byte for
+    /// byte, so formatting differences cannot split consensus.
+    fn validate(&self) -> Result<(), String> {
+        if !is_proof_hash(&self.proof_hash) {
+            return Err("proof_hash must be 64 lowercase hex characters".to_string());
+        }
+        if !is_account_address(&self.oracle) {
+            return Err("oracle must be a valid Stellar G-address".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// The bytes an oracle signs to vote `proof_hash` for `project_id`.
+///
+/// Binds both fields, so a signature cannot be replayed for another
+/// project or hash.
+pub fn vote_message(project_id: &str, proof_hash: &str) -> Vec<u8> {
+    format!("pifp-vote:{project_id}:{proof_hash}").into_bytes()
+}
+
+/// Whether `signature` (hex) is `oracle`'s ed25519 signature over the vote
+/// message for `project_id` and `proof_hash`.
+pub fn verify_vote_signature(
+    oracle: &str,
+    project_id: &str,
+    proof_hash: &str,
+    signature: &str,
+) -> bool {
+    let Some(public_key) = decode_account_address(oracle) else {
+        return false;
+    };
+    let Ok(key) = VerifyingKey::from_bytes(&public_key) else {
+        return false;
+    };
+    let Some(signature) = hex::decode(signature)
+        .ok()
+        .and_then(|bytes| Signature::from_slice(&bytes).ok())
+    else {
+        return false;
+    };
+    key.verify_strict(&vote_message(project_id, proof_hash), &signature)
+        .is_ok()
+}
+
+/// A 32-byte hash in lowercase hex.
+fn is_proof_hash(hash: &str) -> bool {
+    hash.len() == 64 && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+/// StrKey version byte of an ed25519 account (`G...`) address.
+const ACCOUNT_VERSION_BYTE: u8 = 6 << 3;
+
+/// A Stellar account address: base32 of version byte, 32-byte key and a
+/// CRC16-XModem checksum, 56 characters starting with `G`.
+fn is_account_address(address: &str) -> bool {
+    decode_account_address(address).is_some()
+}
+
+/// The ed25519 public key of a valid account address.
+fn decode_account_address(address: &str) -> Option<[u8; 32]> {
+    if address.len() != 56 || !address.starts_with('G') {
+        return None;
+    }
+
+    // Decode base32 (RFC 4648 alphabet, no padding) into 35 bytes.
+    let mut bytes = Vec::with_capacity(35);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in address.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    if bytes.len() != 35 || bytes[0] != ACCOUNT_VERSION_BYTE {
+        return None;
+    }
+
+    let (payload, checksum) = bytes.split_at(33);
+    if crc16_xmodem(payload).to_le_bytes() != checksum {
+        return None;
+    }
+    payload[1..].try_into().ok()
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
 // ─────────────────────────────────────────────────────────
 // Handlers
 // ─────────────────────────────────────────────────────────
@@ -76,56 +325,126 @@ pub async fn health() -> impl IntoResponse {
     })
 }
 
+/// `GET /projects`
+///
+/// Returns every indexed project with its latest status and net balance,
+/// read from the `projects` rollup table.
+pub async fn list_projects(
+    State(state): State<Arc<ApiState>>,
+) -> Result<Json<ProjectsResponse>, ApiError> {
+    let projects = db::list_projects(&state.pool).await?;
+    Ok(Json(ProjectsResponse {
+        count: projects.len(),
+        projects,
+    }))
+}
+
+/// `GET /projects/:id`
+///
+/// Returns one project's rollup, or `not_found` if none of its events has
+/// been indexed.
+pub async fn get_project(
+    State(state): State<Arc<ApiState>>,
+    Path(project_id): Path<String>,
+) -> Result<Json<db::ProjectSummary>, ApiError> {
+    match db::get_project(&state.pool, &project_id).await? {
+        Some(project) => Ok(Json(project)),
+        None => Err(ApiError::not_found(format!(
+            "project {project_id} not found"
+        ))),
+    }
+}
+
 /// `GET /projects/:id/events`
 ///
 /// Returns all indexed events for the given project identifier.
 pub async fn get_project_events(
     State(state): State<Arc<ApiState>>,
     Path(project_id): Path<String>,
-) -> impl IntoResponse {
-    match db::get_events_for_project(&state.pool, &project_id).await {
-        Ok(events) => {
-            let count = events.len();
-            (
-                StatusCode::OK,
-                Json(serde_json::json!(EventsResponse {
-                    project_id,
-                    count,
-                    events,
-                })),
-            )
-                .into_response()
-        }
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!(ErrorResponse {
-                error: e.to_string()
-            })),
-        )
-            .into_response(),
-    }
+) -> Result<Json<EventsResponse>, ApiError> {
+    let events = db::get_events_for_project(&state.pool, &project_id).await?;
+    Ok(Json(EventsResponse {
+        project_id,
+        count: events.len(),
+        events,
+    }))
 }
 
-/// `GET /events`
+/// `GET /projects/:id/balances`
 ///
-/// Returns all indexed events across all projects.
-pub async fn get_all_events(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
-    match db::get_all_events(&state.pool).await {
-        Ok(events) => {
-            let count = events.len();
-            (
-                StatusCode::OK,
-                Json(serde_json::json!(AllEventsResponse { count, events })),
-            )
-                .into_response()
+/// Returns per-token balances reconstructed from the project's indexed
+/// `funded`, `released`, `refunded` and `fee_coll` events. Projects with no
+/// such events return an empty list.
+pub async fn get_project_balances(
+    State(state): State<Arc<ApiState>>,
+    Path(project_id): Path<String>,
+) -> Result<Json<BalancesResponse>, ApiError> {
+    let balances = db::get_project_balances(&state.pool, &project_id).await?;
+    Ok(Json(BalancesResponse {
+        project_id,
+        balances,
+    }))
+}
+
+/// `GET /events?after_id=&limit=`
+///
+/// Returns one page of indexed events across all projects, ordered by id.
+pub async fn get_all_events(
+    State(state): State<Arc<ApiState>>,
+    Query(query): Query<EventsQuery>,
+) -> Result<Json<AllEventsResponse>, ApiError> {
+    let after_id = query.after_id.unwrap_or(0);
+    let events = db::get_events_page(&state.pool, after_id, query.limit()).await?;
+    Ok(Json(AllEventsResponse {
+        count: events.len(),
+        next_cursor: events.last().map(|e| e.id),
+        events,
+    }))
+}
+
+/// `GET /ws/events?project_id=`
+///
+/// Upgrades to a WebSocket that receives every newly indexed event as a JSON
+/// text message, optionally only those of one project. Clients that fall
+/// more than the channel capacity behind skip the events they missed.
+pub async fn stream_events(
+    ws: WebSocketUpgrade,
+    State(state): State<Arc<ApiState>>,
+    Query(query): Query<StreamQuery>,
+) -> impl IntoResponse {
+    // Subscribe before upgrading so nothing stored meanwhile is lost.
+    let rx = state.events_tx.subscribe();
+    ws.on_upgrade(move |socket| forward_events(socket, rx, query.project_id))
+}
+
+async fn forward_events(
+    mut socket: WebSocket,
+    mut rx: broadcast::Receiver<PifpEvent>,
+    project_id: Option<String>,
+) {
+    loop {
+        tokio::select! {
+            received = rx.recv() => {
+                let event = match received {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+                if project_id.is_some() && event.project_id != project_id {
+                    continue;
+                }
+                let Ok(text) = serde_json::to_string(&event) else {
+                    continue;
+                };
+                if socket.send(Message::Text(text)).await.is_err() {
+                    break;
+                }
+            }
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
         }
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!(ErrorResponse {
-                error: e.to_string()
-            })),
-        )
-            .into_response(),
     }
 }
 
@@ -135,62 +454,65 @@ pub async fn get_all_events(State(state): State<Arc<ApiState>>) -> impl IntoResp
 pub async fn set_quorum_threshold(
     State(state): State<Arc<ApiState>>,
     Json(payload): Json<ThresholdRequest>,
-) -> impl IntoResponse {
-    match db::set_quorum_threshold(&state.pool, payload.threshold).await {
-        Ok(_) => (
-            StatusCode::OK,
-            Json(serde_json::json!({ "status": "updated" })),
-        )
-            .into_response(),
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!(ErrorResponse {
-                error: e.to_string()
-            })),
-        )
-            .into_response(),
-    }
+) -> Result<Json<serde_json::Value>, ApiError> {
+    db::set_quorum_threshold(&state.pool, payload.threshold).await?;
+    Ok(Json(serde_json::json!({ "status": "updated" })))
 }
 
 /// `POST /projects/:id/vote`
 ///
-/// Submits an oracle vote for a project.
+/// Submits an oracle vote for a project. Fails with `validation` (400)
+/// unless `proof_hash` is 64 lowercase hex characters and `oracle` a valid
+/// G-address, `unauthorized` (401) unless `signature` is `oracle`'s
+/// signature over [`vote_message`] and `oracle` is on the configured
+/// allow-list, and `rate_limited` (429) once the oracle exceeds its vote
+/// budget.
 pub async fn submit_vote(
     State(state): State<Arc<ApiState>>,
     Path(project_id): Path<String>,
     Json(payload): Json<VoteRequest>,
-) -> impl IntoResponse {
-    match db::record_vote(
+) -> Result<(StatusCode, Json<VoteResponse>), ApiError> {
+    payload.validate().map_err(ApiError::validation)?;
+
+    if !verify_vote_signature(
+        &payload.oracle,
+        &project_id,
+        &payload.proof_hash,
+        &payload.signature,
+    ) {
+        return Err(ApiError::unauthorized("signature does not match oracle"));
+    }
+    if !state.oracles.contains(&payload.oracle) {
+        return Err(ApiError::unauthorized("oracle is not allowed to vote"));
+    }
+
+    // Checked after the signature so forged votes cannot spend an honest
+    // oracle's budget.
+    if !state.vote_limiter.try_acquire(&payload.oracle) {
+        return Err(ApiError::rate_limited(
+            "too many votes from this oracle; retry later",
+        ));
+    }
+
+    let accepted = db::record_vote(
         &state.pool,
         &project_id,
         &payload.oracle,
         &payload.proof_hash,
     )
-    .await
-    {
-        Ok(accepted) => {
-            let (status, message) = if accepted {
-                (StatusCode::CREATED, "Vote recorded")
-            } else {
-                (StatusCode::OK, "Duplicate vote ignored")
-            };
-            (
-                status,
-                Json(VoteResponse {
-                    accepted,
-                    message: message.to_string(),
-                }),
-            )
-                .into_response()
-        }
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!(ErrorResponse {
-                error: e.to_string()
-            })),
-        )
-            .into_response(),
-    }
+    .await?;
+    let (status, message) = if accepted {
+        (StatusCode::CREATED, "Vote recorded")
+    } else {
+        (StatusCode::OK, "Duplicate vote ignored")
+    };
+    Ok((
+        status,
+        Json(VoteResponse {
+            accepted,
+            message: message.to_string(),
+        }),
+    ))
 }
 
 /// `GET /projects/:id/quorum`
@@ -199,15 +521,412 @@ pub async fn submit_vote(
 pub async fn get_project_quorum(
     State(state): State<Arc<ApiState>>,
     Path(project_id): Path<String>,
-) -> impl IntoResponse {
-    match db::get_quorum_status(&state.pool, &project_id).await {
-        Ok(status) => (StatusCode::OK, Json(status)).into_response(),
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!(ErrorResponse {
-                error: e.to_string()
-            })),
+) -> Result<Json<db::QuorumStatus>, ApiError> {
+    Ok(Json(
+        db::get_quorum_status(&state.pool, &project_id, &state.oracles).await?,
+    ))
+}
+
+/// `GET /projects/:id/consensus`
+///
+/// Returns the proof hash a project's oracles agreed on, for an oracle to
+/// submit on-chain. Only votes of allow-listed oracles count. `not_found`
+/// (404) until exactly one hash has met the threshold.
+pub async fn get_project_consensus(
+    State(state): State<Arc<ApiState>>,
+    Path(project_id): Path<String>,
+) -> Result<Json<ConsensusResponse>, ApiError> {
+    let Some(consensus_hash) =
+        db::get_consensus_hash(&state.pool, &project_id, &state.oracles).await?
+    else {
+        return Err(ApiError::not_found("consensus not reached"));
+    };
+    let status = db::get_quorum_status(&state.pool, &project_id, &state.oracles).await?;
+    let votes = status
+        .votes
+        .iter()
+        .find(|v| v.proof_hash == consensus_hash)
+        .map_or(0, |v| v.count);
+    Ok(Json(ConsensusResponse {
+        project_id,
+        consensus_hash,
+        threshold: status.threshold,
+        votes,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::{routing::get, Router};
+    use ed25519_dalek::{Signer, SigningKey};
+    use futures_util::StreamExt;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use super::*;
+    use crate::indexer;
+
+    fn query(limit: Option<i64>) -> EventsQuery {
+        EventsQuery {
+            after_id: None,
+            limit,
+        }
+    }
+
+    #[test]
+    fn page_limit_defaults_and_clamps() {
+        assert_eq!(query(None).limit(), 100);
+        assert_eq!(query(Some(25)).limit(), 25);
+        assert_eq!(query(Some(5000)).limit(), 1000);
+        assert_eq!(query(Some(0)).limit(), 1);
+        assert_eq!(query(Some(-3)).limit(), 1);
+    }
+
+    fn funded_event(project_id: &str, tx_hash: &str) -> PifpEvent {
+        PifpEvent {
+            event_type: "project_funded".to_string(),
+            project_id: Some(project_id.to_string()),
+            actor: Some("GDONATOR".to_string()),
+            amount: Some("100".to_string()),
+            token: Some("CTOKEN".to_string()),
+            memo: None,
+            ledger: 1000,
+            timestamp: 1_704_067_200,
+            contract_id: "CONTRACT1".to_string(),
+            tx_hash: Some(tx_hash.to_string()),
+        }
+    }
+
+    const ORACLE: &str = "GAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB7JZX";
+
+    /// A deterministic oracle key and its G-address.
+    fn oracle_key(seed: u8) -> (SigningKey, String) {
+        let key = SigningKey::from_bytes(&[seed; 32]);
+        let mut payload = vec![ACCOUNT_VERSION_BYTE];
+        payload.extend_from_slice(key.verifying_key().as_bytes());
+        let checksum = crc16_xmodem(&payload).to_le_bytes();
+        payload.extend_from_slice(&checksum);
+
+        // Base32 (RFC 4648 alphabet, no padding); 35 bytes make 56 characters.
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+        let mut address = String::new();
+        let (mut buffer, mut bits) = (0u32, 0);
+        for byte in payload {
+            buffer = (buffer << 8) | byte as u32;
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                address.push(ALPHABET[((buffer >> bits) & 31) as usize] as char);
+            }
+        }
+        if bits > 0 {
+            address.push(ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+        }
+        (key, address)
+    }
+
+    fn sign_vote(key: &SigningKey, project_id: &str, proof_hash: &str) -> String {
+        hex::encode(key.sign(&vote_message(project_id, proof_hash)).to_bytes())
+    }
+
+    async fn test_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+        pool
+    }
+
+    /// Addresses of the oracles behind seeds 1 to 3.
+    fn allowed() -> HashSet<String> {
+        (1..=3).map(|seed| oracle_key(seed).1).collect()
+    }
+
+    fn api_state(pool: &SqlitePool, votes_per_minute: u32) -> Arc<ApiState> {
+        let (events_tx, _) = broadcast::channel(16);
+        Arc::new(ApiState {
+            pool: pool.clone(),
+            events_tx,
+            vote_limiter: RateLimiter::new(votes_per_minute),
+            oracles: allowed(),
+        })
+    }
+
+    async fn vote(pool: &SqlitePool, seed: u8, proof_hash: &str) -> StatusCode {
+        vote_with(api_state(pool, 60), seed, proof_hash).await
+    }
+
+    /// Submit a vote for project 42 correctly signed by oracle `seed`.
+    async fn vote_with(state: Arc<ApiState>, seed: u8, proof_hash: &str) -> StatusCode {
+        let (key, oracle) = oracle_key(seed);
+        let signature = sign_vote(&key, "42", proof_hash);
+        submit(state, &oracle, proof_hash, &signature).await
+    }
+
+    async fn submit(
+        state: Arc<ApiState>,
+        oracle: &str,
+        proof_hash: &str,
+        signature: &str,
+    ) -> StatusCode {
+        let payload = VoteRequest {
+            oracle: oracle.to_string(),
+            proof_hash: proof_hash.to_string(),
+            signature: signature.to_string(),
+        };
+        submit_vote(State(state), Path("42".to_string()), Json(payload))
+            .await
+            .into_response()
+            .status()
+    }
+
+    #[test]
+    fn proof_hash_must_be_lowercase_hex() {
+        assert!(is_proof_hash(&"ab".repeat(32)));
+        assert!(!is_proof_hash(&"AB".repeat(32)));
+        assert!(!is_proof_hash(&"ab".repeat(31)));
+        assert!(!is_proof_hash(&format!("0x{}", "ab".repeat(31))));
+        assert!(!is_proof_hash(&"zz".repeat(32)));
+    }
+
+    #[test]
+    fn account_address_checks_checksum() {
+        assert!(is_account_address(ORACLE));
+        assert!(is_account_address(
+            "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF"
+        ));
+        // One character changed breaks the checksum.
+        assert!(!is_account_address(
+            "GAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB7JZY"
+        ));
+        assert!(!is_account_address(&ORACLE.to_lowercase()));
+        assert!(!is_account_address("oracle_1"));
+        // A contract (`C...`) address is not an account.
+        assert!(!is_account_address(
+            "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF"
+        ));
+    }
+
+    /// Split a handler response into its status and error body.
+    async fn error_of(response: Response) -> (StatusCode, ApiError) {
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn missing_project_is_not_found() {
+        let pool = test_pool().await;
+
+        let response = get_project(State(api_state(&pool, 60)), Path("404".to_string()))
+            .await
+            .into_response();
+
+        let (status, error) = error_of(response).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(error.code, ErrorCode::NotFound);
+        let body = serde_json::to_value(&error).unwrap();
+        assert_eq!(body["code"], "not_found");
+    }
+
+    #[tokio::test]
+    async fn indexed_project_is_found() {
+        let pool = test_pool().await;
+        let (events_tx, _) = broadcast::channel(16);
+        indexer::store_events(&pool, &events_tx, &[funded_event("42", "TX1")])
+            .await
+            .unwrap();
+
+        let Json(project) = get_project(State(api_state(&pool, 60)), Path("42".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(project.project_id, "42");
+    }
+
+    #[tokio::test]
+    async fn rejected_vote_carries_error_code() {
+        let pool = test_pool().await;
+        let (_, oracle) = oracle_key(1);
+
+        let response = submit_vote(
+            State(api_state(&pool, 60)),
+            Path("42".to_string()),
+            Json(VoteRequest {
+                oracle,
+                proof_hash: "AB".repeat(32),
+                signature: "00".repeat(64),
+            }),
         )
-            .into_response(),
+        .await
+        .into_response();
+
+        let (status, error) = error_of(response).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(error.code, ErrorCode::Validation);
+    }
+
+    #[tokio::test]
+    async fn malformed_vote_is_rejected() {
+        let pool = test_pool().await;
+
+        let status = vote(&pool, 1, &"AB".repeat(32)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let state = api_state(&pool, 60);
+        let status = submit(state, "oracle_1", &"ab".repeat(32), &"00".repeat(64)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let quorum = db::get_quorum_status(&pool, "42", &allowed())
+            .await
+            .unwrap();
+        assert!(quorum.votes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn well_formed_vote_is_recorded() {
+        let pool = test_pool().await;
+
+        assert_eq!(vote(&pool, 1, &"ab".repeat(32)).await, StatusCode::CREATED);
+
+        let quorum = db::get_quorum_status(&pool, "42", &allowed())
+            .await
+            .unwrap();
+        assert_eq!(quorum.votes.len(), 1);
+        assert_eq!(quorum.votes[0].proof_hash, "ab".repeat(32));
+    }
+
+    #[test]
+    fn vote_signature_is_checked_against_oracle_key() {
+        let (key, oracle) = oracle_key(1);
+        let (other_key, _) = oracle_key(2);
+        let hash = "ab".repeat(32);
+        let signature = sign_vote(&key, "42", &hash);
+
+        assert!(verify_vote_signature(&oracle, "42", &hash, &signature));
+        // Signed by another key, or for another project or hash.
+        let forged = sign_vote(&other_key, "42", &hash);
+        assert!(!verify_vote_signature(&oracle, "42", &hash, &forged));
+        assert!(!verify_vote_signature(&oracle, "43", &hash, &signature));
+        assert!(!verify_vote_signature(
+            &oracle,
+            "42",
+            &"cd".repeat(32),
+            &signature
+        ));
+        assert!(!verify_vote_signature(&oracle, "42", &hash, "not hex"));
+    }
+
+    #[tokio::test]
+    async fn forged_vote_is_unauthorized() {
+        let pool = test_pool().await;
+        let (_, oracle) = oracle_key(1);
+        let (impostor, _) = oracle_key(2);
+        let hash = "ab".repeat(32);
+        let forged = sign_vote(&impostor, "42", &hash);
+
+        let status = submit(api_state(&pool, 60), &oracle, &hash, &forged).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let quorum = db::get_quorum_status(&pool, "42", &allowed())
+            .await
+            .unwrap();
+        assert!(quorum.votes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn vote_from_unlisted_oracle_is_unauthorized() {
+        let pool = test_pool().await;
+
+        // Correctly signed, but by a key that is not an oracle.
+        let status = vote(&pool, 9, &"ab".repeat(32)).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let quorum = db::get_quorum_status(&pool, "42", &allowed())
+            .await
+            .unwrap();
+        assert!(quorum.votes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn consensus_ignores_unlisted_oracles() {
+        let pool = test_pool().await;
+        db::set_quorum_threshold(&pool, 2).await.unwrap();
+        let hash = "ab".repeat(32);
+        let (_, stranger) = oracle_key(9);
+        db::record_vote(&pool, "42", &stranger, &hash)
+            .await
+            .unwrap();
+        assert_eq!(vote(&pool, 1, &hash).await, StatusCode::CREATED);
+
+        let consensus = |pool: SqlitePool| async move {
+            get_project_consensus(State(api_state(&pool, 60)), Path("42".to_string()))
+                .await
+                .into_response()
+                .status()
+        };
+        assert_eq!(consensus(pool.clone()).await, StatusCode::NOT_FOUND);
+
+        assert_eq!(vote(&pool, 2, &hash).await, StatusCode::CREATED);
+        assert_eq!(consensus(pool).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn votes_beyond_rate_limit_are_throttled_per_oracle() {
+        let pool = test_pool().await;
+        let state = api_state(&pool, 3);
+
+        // Re-submitting is a duplicate, but still spends the oracle's budget.
+        for _ in 0..3 {
+            let status = vote_with(state.clone(), 1, &"ab".repeat(32)).await;
+            assert_ne!(status, StatusCode::TOO_MANY_REQUESTS);
+        }
+        assert_eq!(
+            vote_with(state.clone(), 1, &"ab".repeat(32)).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+
+        assert_eq!(
+            vote_with(state, 2, &"ab".repeat(32)).await,
+            StatusCode::CREATED
+        );
+    }
+
+    #[tokio::test]
+    async fn ws_streams_newly_stored_events_for_project() {
+        let pool = test_pool().await;
+        let state = api_state(&pool, 60);
+        let events_tx = state.events_tx.clone();
+        let app = Router::new()
+            .route("/ws/events", get(stream_events))
+            .with_state(state);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let (mut socket, _) =
+            tokio_tungstenite::connect_async(format!("ws://{addr}/ws/events?project_id=42"))
+                .await
+                .unwrap();
+
+        let events = [funded_event("7", "TX1"), funded_event("42", "TX2")];
+        let stored = indexer::store_events(&pool, &events_tx, &events)
+            .await
+            .unwrap();
+        assert_eq!(stored, 2);
+
+        let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+            .await
+            .expect("no event arrived")
+            .unwrap()
+            .unwrap();
+        let received: PifpEvent = serde_json::from_str(message.to_text().unwrap()).unwrap();
+        assert_eq!(received.project_id.as_deref(), Some("42"));
+        assert_eq!(received.tx_hash.as_deref(), Some("TX2"));
+        assert_eq!(received.amount.as_deref(), Some("100"));
     }
 }
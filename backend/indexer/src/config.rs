@@ -18,6 +18,13 @@ pub struct Config {
     pub events_per_page: u32,
     /// Ledger to start from if no cursor is saved
     pub start_ledger: u32,
+    /// How many ledgers behind the network tip an event must be before it
+    /// is persisted; closer ledgers may still be reorged away
+    pub confirmation_depth: u32,
+    /// Votes each oracle address may submit per minute before getting 429
+    pub votes_per_minute: u32,
+    /// Oracle G-addresses allowed to vote; anyone else's vote gets 401
+    pub oracle_addresses: Vec<String>,
 }
 
 impl Config {
@@ -46,6 +53,21 @@ impl Config {
                 .unwrap_or_else(|_| "0".to_string())
                 .parse()
                 .map_err(|_| IndexerError::Config("Invalid START_LEDGER".to_string()))?,
+            confirmation_depth: env_var("CONFIRMATION_DEPTH")
+                .unwrap_or_else(|_| "1".to_string())
+                .parse()
+                .map_err(|_| IndexerError::Config("Invalid CONFIRMATION_DEPTH".to_string()))?,
+            votes_per_minute: env_var("VOTES_PER_MINUTE")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .map_err(|_| IndexerError::Config("Invalid VOTES_PER_MINUTE".to_string()))?,
+            oracle_addresses: env_var("ORACLE_ADDRESSES")
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|address| !address.is_empty())
+                .map(str::to_string)
+                .collect(),
         })
     }
 }
@@ -1,5 +1,7 @@
 //! Database layer — migrations, queries, and cursor management.
 
+use std::collections::{BTreeMap, HashSet};
+
 use serde::Serialize;
 use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
 use tracing::info;
@@ -23,6 +25,10 @@ pub async fn init_pool(database_url: &str) -> Result<SqlitePool> {
 
     sqlx::migrate!("./migrations").run(&pool).await?;
     info!("Database migrations applied successfully");
+
+    if backfill_projects(&pool).await? > 0 {
+        info!("Project rollups backfilled from existing events");
+    }
     Ok(pool)
 }
 
@@ -53,6 +59,24 @@ pub async fn save_cursor(
     Ok(())
 }
 
+/// Read the highest ledger ingested at the confirmation depth.
+/// Returns `0` when nothing has been confirmed yet.
+pub async fn get_safe_ledger(pool: &SqlitePool) -> Result<i64> {
+    let row: Option<(i64,)> = sqlx::query_as("SELECT safe_ledger FROM indexer_cursor WHERE id = 1")
+        .fetch_optional(pool)
+        .await?;
+    Ok(row.map(|(v,)| v).unwrap_or(0))
+}
+
+/// Persist the highest ledger ingested at the confirmation depth.
+pub async fn save_safe_ledger(pool: &SqlitePool, safe_ledger: i64) -> Result<()> {
+    sqlx::query("UPDATE indexer_cursor SET safe_ledger = ?1 WHERE id = 1")
+        .bind(safe_ledger)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
 /// Read back the raw cursor string (used to resume pagination mid-ledger).
 pub async fn get_cursor_string(pool: &SqlitePool) -> Result<Option<String>> {
     let row: Option<(Option<String>,)> =
@@ -66,23 +90,26 @@ pub async fn get_cursor_string(pool: &SqlitePool) -> Result<Option<String>> {
 // Event writes
 // ─────────────────────────────────────────────────────────
 
-/// Persist a batch of decoded events.  Events that share the same
-/// `(ledger, tx_hash, event_type, project_id)` tuple are silently ignored
-/// to make the indexer idempotent.
-pub async fn insert_events(pool: &SqlitePool, events: &[PifpEvent]) -> Result<usize> {
-    let mut count = 0usize;
+/// Persist a batch of decoded events and return the ones that were new.
+/// Events that share the same `(ledger, tx_hash, event_type, project_id,
+/// token)` tuple are silently ignored to make the indexer idempotent.
+pub async fn insert_events(pool: &SqlitePool, events: &[PifpEvent]) -> Result<Vec<PifpEvent>> {
+    let mut inserted = Vec::new();
+    let mut touched: Vec<&str> = Vec::new();
     for ev in events {
         let rows_affected = sqlx::query(
             r#"
             INSERT OR IGNORE INTO events
-                (event_type, project_id, actor, amount, ledger, timestamp, contract_id, tx_hash)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                (event_type, project_id, actor, amount, token, memo, ledger, timestamp, contract_id, tx_hash)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
             "#,
         )
         .bind(&ev.event_type)
         .bind(&ev.project_id)
         .bind(&ev.actor)
         .bind(&ev.amount)
+        .bind(&ev.token)
+        .bind(&ev.memo)
         .bind(ev.ledger)
         .bind(ev.timestamp)
         .bind(&ev.contract_id)
@@ -91,9 +118,20 @@ pub async fn insert_events(pool: &SqlitePool, events: &[PifpEvent]) -> Result<us
         .await?
         .rows_affected();
 
-        count += rows_affected as usize;
+        if rows_affected > 0 {
+            inserted.push(ev.clone());
+        }
+        if let Some(project_id) = ev.project_id.as_deref() {
+            if rows_affected > 0 && !touched.contains(&project_id) {
+                touched.push(project_id);
+            }
+        }
     }
-    Ok(count)
+
+    for project_id in touched {
+        refresh_project(pool, project_id).await?;
+    }
+    Ok(inserted)
 }
 
 // ─────────────────────────────────────────────────────────
@@ -107,7 +145,7 @@ pub async fn get_events_for_project(
 ) -> Result<Vec<EventRecord>> {
     let rows = sqlx::query_as::<_, EventRecord>(
         r#"
-        SELECT id, event_type, project_id, actor, amount, ledger, timestamp,
+        SELECT id, event_type, project_id, actor, amount, token, memo, ledger, timestamp,
                contract_id, tx_hash, created_at
         FROM   events
         WHERE  project_id = ?1
@@ -120,21 +158,231 @@ pub async fn get_events_for_project(
     Ok(rows)
 }
 
-/// Fetch all events, ordered by ledger ascending.
+/// Page size used by [`get_all_events`].
+const ALL_EVENTS_LIMIT: i64 = 1_000_000;
+
+/// Fetch all events, ordered by id ascending.
+///
+/// Kept for backward compatibility; delegates to [`get_events_page`] with a
+/// high limit. Prefer paging explicitly.
+#[allow(dead_code)]
 pub async fn get_all_events(pool: &SqlitePool) -> Result<Vec<EventRecord>> {
+    get_events_page(pool, 0, ALL_EVENTS_LIMIT).await
+}
+
+/// Fetch up to `limit` events with an id greater than `after_id`, ordered by
+/// id ascending. Pass the last id of one page as `after_id` to get the next.
+pub async fn get_events_page(
+    pool: &SqlitePool,
+    after_id: i64,
+    limit: i64,
+) -> Result<Vec<EventRecord>> {
     let rows = sqlx::query_as::<_, EventRecord>(
         r#"
-        SELECT id, event_type, project_id, actor, amount, ledger, timestamp,
+        SELECT id, event_type, project_id, actor, amount, token, memo, ledger, timestamp,
                contract_id, tx_hash, created_at
         FROM   events
+        WHERE  id > ?1
+        ORDER  BY id ASC
+        LIMIT  ?2
+        "#,
+    )
+    .bind(after_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Net amount of one token a project still holds, per the indexed events.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct TokenBalance {
+    pub token: String,
+    /// Decimal string, like event amounts, so `i128` values survive JSON.
+    pub net: String,
+}
+
+/// Reconstruct a project's per-token balances from its indexed events.
+///
+/// `funded` adds; `released`, `refunded` and `fee_collected` subtract.
+/// Tokens are listed in the order they were first seen. Amounts that fail
+/// to parse are skipped.
+pub async fn get_project_balances(
+    pool: &SqlitePool,
+    project_id: &str,
+) -> Result<Vec<TokenBalance>> {
+    let rows: Vec<(String, String, Option<String>)> = sqlx::query_as(
+        r#"
+        SELECT token, event_type, amount
+        FROM   events
+        WHERE  project_id = ?1
+          AND  token IS NOT NULL
+          AND  event_type IN ('project_funded', 'funds_released',
+                              'donator_refunded', 'fee_collected')
         ORDER  BY ledger ASC, id ASC
         "#,
     )
+    .bind(project_id)
+    .fetch_all(pool)
+    .await?;
+
+    let mut balances: Vec<(String, i128)> = Vec::new();
+    for (token, event_type, amount) in rows {
+        let Some(amount) = amount.and_then(|a| a.parse::<i128>().ok()) else {
+            continue;
+        };
+        let delta = if event_type == "project_funded" {
+            amount
+        } else {
+            -amount
+        };
+        match balances.iter_mut().find(|(t, _)| *t == token) {
+            Some((_, net)) => *net += delta,
+            None => balances.push((token, delta)),
+        }
+    }
+
+    Ok(balances
+        .into_iter()
+        .map(|(token, net)| TokenBalance {
+            token,
+            net: net.to_string(),
+        })
+        .collect())
+}
+
+// ─────────────────────────────────────────────────────────
+// Project rollups
+// ─────────────────────────────────────────────────────────
+
+/// One row of the `projects` table: a project's latest status and net
+/// balance, as derived from its indexed events.
+#[derive(Debug, Serialize, PartialEq, Eq, sqlx::FromRow)]
+pub struct ProjectSummary {
+    pub project_id: String,
+    /// `funding`, `completed`, `expired` or `refunded`.
+    pub status: String,
+    /// Sum of the per-token nets of [`get_project_balances`]. Raw amounts
+    /// are added across tokens regardless of their decimals.
+    pub net_balance: String,
+    pub event_count: i64,
+    pub last_ledger: i64,
+}
+
+/// Order in which statuses may follow each other; a rollup never moves
+/// back to a lower rank, so a late `funded` event cannot reopen a project.
+fn status_rank(status: &str) -> u8 {
+    match status {
+        "funding" => 0,
+        "completed" | "expired" => 1,
+        _ => 2,
+    }
+}
+
+/// Status of a project after `event_type`, given its status before.
+fn status_after(current: &'static str, event_type: &str) -> &'static str {
+    let next = match event_type {
+        "project_created" | "project_funded" => "funding",
+        "project_verified" | "funds_released" => "completed",
+        "project_expired" => "expired",
+        "donator_refunded" => "refunded",
+        _ => return current,
+    };
+    if status_rank(next) >= status_rank(current) {
+        next
+    } else {
+        current
+    }
+}
+
+/// Recompute a project's row in `projects` from its events.
+pub async fn refresh_project(pool: &SqlitePool, project_id: &str) -> Result<()> {
+    let events = get_events_for_project(pool, project_id).await?;
+    let Some(last) = events.last() else {
+        return Ok(());
+    };
+    let last_ledger = last.ledger;
+
+    let status = events
+        .iter()
+        .fold("funding", |status, ev| status_after(status, &ev.event_type));
+    let net_balance: i128 = get_project_balances(pool, project_id)
+        .await?
+        .iter()
+        .filter_map(|b| b.net.parse::<i128>().ok())
+        .sum();
+
+    sqlx::query(
+        r#"
+        INSERT INTO projects (project_id, status, net_balance, event_count, last_ledger)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT (project_id) DO UPDATE SET
+            status      = excluded.status,
+            net_balance = excluded.net_balance,
+            event_count = excluded.event_count,
+            last_ledger = excluded.last_ledger
+        "#,
+    )
+    .bind(project_id)
+    .bind(status)
+    .bind(net_balance.to_string())
+    .bind(events.len() as i64)
+    .bind(last_ledger)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Fill an empty `projects` table from events indexed before it existed.
+/// Returns how many projects were rolled up; 0 when the table already has
+/// rows.
+async fn backfill_projects(pool: &SqlitePool) -> Result<usize> {
+    let (existing,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM projects")
+        .fetch_one(pool)
+        .await?;
+    if existing > 0 {
+        return Ok(0);
+    }
+
+    let ids: Vec<(String,)> =
+        sqlx::query_as("SELECT DISTINCT project_id FROM events WHERE project_id IS NOT NULL")
+            .fetch_all(pool)
+            .await?;
+    for (project_id,) in &ids {
+        refresh_project(pool, project_id).await?;
+    }
+    Ok(ids.len())
+}
+
+/// List every project with its rolled-up status, ordered by project id.
+pub async fn list_projects(pool: &SqlitePool) -> Result<Vec<ProjectSummary>> {
+    let rows = sqlx::query_as::<_, ProjectSummary>(
+        r#"
+        SELECT project_id, status, net_balance, event_count, last_ledger
+        FROM   projects
+        ORDER  BY CAST(project_id AS INTEGER) ASC, project_id ASC
+        "#,
+    )
     .fetch_all(pool)
     .await?;
     Ok(rows)
 }
 
+/// Fetch one project's rollup; `None` if no event of it has been indexed.
+pub async fn get_project(pool: &SqlitePool, project_id: &str) -> Result<Option<ProjectSummary>> {
+    let row = sqlx::query_as::<_, ProjectSummary>(
+        r#"
+        SELECT project_id, status, net_balance, event_count, last_ledger
+        FROM   projects
+        WHERE  project_id = ?1
+        "#,
+    )
+    .bind(project_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
 // ─────────────────────────────────────────────────────────
 // Quorum management
 // ─────────────────────────────────────────────────────────
@@ -189,24 +437,32 @@ pub struct VoteInfo {
     pub count: u32,
 }
 
-/// Fetch the current quorum status for a project.
-pub async fn get_quorum_status(pool: &SqlitePool, project_id: &str) -> Result<QuorumStatus> {
+/// Fetch the current quorum status for a project, counting only the votes
+/// of `oracles`: a vote recorded before its oracle left the allow-list does
+/// not count.
+pub async fn get_quorum_status(
+    pool: &SqlitePool,
+    project_id: &str,
+    oracles: &HashSet<String>,
+) -> Result<QuorumStatus> {
     let threshold = get_quorum_threshold(pool).await?;
 
-    // Query to count matching votes per hash for the given project
-    let votes = sqlx::query_as::<_, (String, i32)>(
-        "SELECT proof_hash, COUNT(*) as count FROM oracle_votes WHERE project_id = ?1 GROUP BY proof_hash",
+    let votes = sqlx::query_as::<_, (String, String)>(
+        "SELECT oracle_address, proof_hash FROM oracle_votes WHERE project_id = ?1",
     )
     .bind(project_id)
     .fetch_all(pool)
     .await?;
 
-    let vote_info: Vec<VoteInfo> = votes
+    let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+    for (oracle, proof_hash) in votes {
+        if oracles.contains(&oracle) {
+            *counts.entry(proof_hash).or_default() += 1;
+        }
+    }
+    let vote_info: Vec<VoteInfo> = counts
         .into_iter()
-        .map(|(proof_hash, count)| VoteInfo {
-            proof_hash,
-            count: count as u32,
-        })
+        .map(|(proof_hash, count)| VoteInfo { proof_hash, count })
         .collect();
 
     let consensus_reached = vote_info.iter().any(|v| v.count >= threshold);
@@ -219,10 +475,37 @@ pub async fn get_quorum_status(pool: &SqlitePool, project_id: &str) -> Result<Qu
     })
 }
 
+/// Fetch the proof hash a project's oracles reached consensus on, if any.
+///
+/// `None` unless exactly one hash met the threshold: a threshold low enough
+/// for two hashes to meet it is no consensus.
+pub async fn get_consensus_hash(
+    pool: &SqlitePool,
+    project_id: &str,
+    oracles: &HashSet<String>,
+) -> Result<Option<String>> {
+    let status = get_quorum_status(pool, project_id, oracles).await?;
+    let mut reached = status
+        .votes
+        .into_iter()
+        .filter(|v| v.count >= status.threshold);
+    Ok(match (reached.next(), reached.next()) {
+        (Some(winner), None) => Some(winner.proof_hash),
+        _ => None,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn oracles() -> HashSet<String> {
+        ["oracle_1", "oracle_2", "oracle_3"]
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
     async fn setup_test_db() -> SqlitePool {
         let pool = SqlitePoolOptions::new()
             .connect("sqlite::memory:")
@@ -245,6 +528,232 @@ mod tests {
         pool
     }
 
+    async fn setup_events_db() -> SqlitePool {
+        // A single connection so every query sees the same in-memory database.
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+        pool
+    }
+
+    fn token_event(event_type: &str, tx_hash: &str, token: &str, amount: &str) -> PifpEvent {
+        PifpEvent {
+            event_type: event_type.to_string(),
+            project_id: Some("42".to_string()),
+            actor: None,
+            amount: Some(amount.to_string()),
+            token: Some(token.to_string()),
+            memo: None,
+            ledger: 1000,
+            timestamp: 1_704_067_200,
+            contract_id: "CONTRACT1".to_string(),
+            tx_hash: Some(tx_hash.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_released_and_refunded_events_round_trip() {
+        let pool = setup_events_db().await;
+        let events = vec![
+            token_event("funds_released", "TX1", "CTOKEN_A", "600"),
+            token_event("funds_released", "TX1", "CTOKEN_B", "400"),
+            PifpEvent {
+                actor: Some("GDONATOR".to_string()),
+                ..token_event("donator_refunded", "TX2", "CTOKEN_A", "750")
+            },
+        ];
+
+        assert_eq!(insert_events(&pool, &events).await.unwrap().len(), 3);
+        // Re-inserting the same batch is a no-op.
+        assert_eq!(insert_events(&pool, &events).await.unwrap().len(), 0);
+
+        let stored = get_events_for_project(&pool, "42").await.unwrap();
+        assert_eq!(stored.len(), 3);
+        assert_eq!(stored[0].event_type, "funds_released");
+        assert_eq!(stored[0].token.as_deref(), Some("CTOKEN_A"));
+        assert_eq!(stored[0].amount.as_deref(), Some("600"));
+        assert_eq!(stored[1].token.as_deref(), Some("CTOKEN_B"));
+        assert_eq!(stored[2].event_type, "donator_refunded");
+        assert_eq!(stored[2].actor.as_deref(), Some("GDONATOR"));
+        assert_eq!(stored[2].token.as_deref(), Some("CTOKEN_A"));
+    }
+
+    #[tokio::test]
+    async fn test_funded_memo_round_trip() {
+        let pool = setup_events_db().await;
+        let events = [PifpEvent {
+            memo: Some("in memory of Grace".to_string()),
+            ..token_event("project_funded", "TX1", "CTOKEN_A", "100")
+        }];
+
+        insert_events(&pool, &events).await.unwrap();
+
+        let stored = get_events_for_project(&pool, "42").await.unwrap();
+        assert_eq!(stored[0].memo.as_deref(), Some("in memory of Grace"));
+    }
+
+    #[tokio::test]
+    async fn test_token_less_events_are_deduplicated() {
+        let pool = setup_events_db().await;
+        let event = PifpEvent {
+            token: None,
+            ..token_event("project_funded", "TX3", "", "5000")
+        };
+
+        let batch = [event];
+        assert_eq!(insert_events(&pool, &batch).await.unwrap().len(), 1);
+        assert_eq!(insert_events(&pool, &batch).await.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_project_balances_net_out_events() {
+        let pool = setup_events_db().await;
+        let events = vec![
+            token_event("project_funded", "TX1", "CTOKEN_A", "1000"),
+            token_event("project_funded", "TX2", "CTOKEN_B", "300"),
+            token_event("project_funded", "TX3", "CTOKEN_A", "500"),
+            token_event("donator_refunded", "TX4", "CTOKEN_B", "100"),
+            token_event("fee_collected", "TX5", "CTOKEN_A", "50"),
+            token_event("funds_released", "TX5", "CTOKEN_A", "950"),
+            // Events for other projects must not leak in.
+            PifpEvent {
+                project_id: Some("7".to_string()),
+                ..token_event("project_funded", "TX6", "CTOKEN_A", "999")
+            },
+        ];
+        insert_events(&pool, &events).await.unwrap();
+
+        let balances = get_project_balances(&pool, "42").await.unwrap();
+        assert_eq!(
+            balances,
+            vec![
+                TokenBalance {
+                    token: "CTOKEN_A".to_string(),
+                    net: "500".to_string(),
+                },
+                TokenBalance {
+                    token: "CTOKEN_B".to_string(),
+                    net: "200".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_events_page_walks_forward() {
+        let pool = setup_events_db().await;
+        let events: Vec<PifpEvent> = (0..5)
+            .map(|i| token_event("project_funded", &format!("TX{i}"), "CTOKEN", "10"))
+            .collect();
+        insert_events(&pool, &events).await.unwrap();
+
+        let first = get_events_page(&pool, 0, 2).await.unwrap();
+        assert_eq!(first.len(), 2);
+        let second = get_events_page(&pool, first[1].id, 2).await.unwrap();
+        assert_eq!(second.len(), 2);
+        assert!(second[0].id > first[1].id);
+        let last = get_events_page(&pool, second[1].id, 2).await.unwrap();
+        assert_eq!(last.len(), 1);
+        let past_end = get_events_page(&pool, last[0].id, 2).await.unwrap();
+        assert!(past_end.is_empty());
+
+        assert_eq!(get_all_events(&pool).await.unwrap().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_project_balances_empty_project() {
+        let pool = setup_events_db().await;
+        assert!(get_project_balances(&pool, "42").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_created_then_verified_project_rolls_up_to_completed() {
+        let pool = setup_events_db().await;
+        let events = vec![
+            token_event("project_created", "TX1", "CTOKEN_A", "1000"),
+            token_event("project_funded", "TX2", "CTOKEN_A", "1000"),
+            token_event("project_verified", "TX3", "CTOKEN_A", "0"),
+            token_event("funds_released", "TX3", "CTOKEN_A", "1000"),
+            PifpEvent {
+                project_id: Some("7".to_string()),
+                ..token_event("project_funded", "TX4", "CTOKEN_A", "250")
+            },
+        ];
+        insert_events(&pool, &events).await.unwrap();
+
+        let projects = list_projects(&pool).await.unwrap();
+        assert_eq!(
+            projects,
+            vec![
+                ProjectSummary {
+                    project_id: "7".to_string(),
+                    status: "funding".to_string(),
+                    net_balance: "250".to_string(),
+                    event_count: 1,
+                    last_ledger: 1000,
+                },
+                ProjectSummary {
+                    project_id: "42".to_string(),
+                    status: "completed".to_string(),
+                    net_balance: "0".to_string(),
+                    event_count: 4,
+                    last_ledger: 1000,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rollup_status_does_not_move_backwards() {
+        let pool = setup_events_db().await;
+        insert_events(
+            &pool,
+            &[
+                token_event("project_funded", "TX1", "CTOKEN_A", "500"),
+                token_event("project_expired", "TX2", "CTOKEN_A", "0"),
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(list_projects(&pool).await.unwrap()[0].status, "expired");
+
+        // A refund moves an expired project on; nothing moves it back.
+        insert_events(
+            &pool,
+            &[
+                token_event("donator_refunded", "TX3", "CTOKEN_A", "200"),
+                token_event("project_funded", "TX4", "CTOKEN_A", "1"),
+            ],
+        )
+        .await
+        .unwrap();
+        let project = &list_projects(&pool).await.unwrap()[0];
+        assert_eq!(project.status, "refunded");
+        assert_eq!(project.net_balance, "301");
+    }
+
+    #[tokio::test]
+    async fn test_backfill_rolls_up_existing_events() {
+        let pool = setup_events_db().await;
+        insert_events(
+            &pool,
+            &[token_event("project_funded", "TX1", "CTOKEN_A", "500")],
+        )
+        .await
+        .unwrap();
+        sqlx::query("DELETE FROM projects")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(backfill_projects(&pool).await.unwrap(), 1);
+        assert_eq!(backfill_projects(&pool).await.unwrap(), 0);
+        assert_eq!(list_projects(&pool).await.unwrap()[0].event_count, 1);
+    }
+
     #[tokio::test]
     async fn test_quorum_threshold() {
         let pool = setup_test_db().await;
@@ -272,7 +781,9 @@ mod tests {
             .unwrap();
         assert!(accepted);
 
-        let status = get_quorum_status(&pool, project_id).await.unwrap();
+        let status = get_quorum_status(&pool, project_id, &oracles())
+            .await
+            .unwrap();
         assert_eq!(status.threshold, 2);
         assert_eq!(status.votes.len(), 1);
         assert_eq!(status.votes[0].count, 1);
@@ -288,7 +799,9 @@ mod tests {
         record_vote(&pool, project_id, "oracle_2", hash_b)
             .await
             .unwrap();
-        let status = get_quorum_status(&pool, project_id).await.unwrap();
+        let status = get_quorum_status(&pool, project_id, &oracles())
+            .await
+            .unwrap();
         assert_eq!(status.votes.len(), 2);
         assert!(!status.consensus_reached);
 
@@ -296,7 +809,9 @@ mod tests {
         record_vote(&pool, project_id, "oracle_3", hash_a)
             .await
             .unwrap();
-        let status = get_quorum_status(&pool, project_id).await.unwrap();
+        let status = get_quorum_status(&pool, project_id, &oracles())
+            .await
+            .unwrap();
         assert!(status.consensus_reached);
         assert_eq!(
             status
@@ -308,4 +823,65 @@ mod tests {
             2
         );
     }
+
+    #[tokio::test]
+    async fn test_consensus_hash_reached_and_not_reached() {
+        let pool = setup_test_db().await;
+        set_quorum_threshold(&pool, 2).await.unwrap();
+
+        record_vote(&pool, "proj_1", "oracle_1", "hash_aaaa")
+            .await
+            .unwrap();
+        record_vote(&pool, "proj_1", "oracle_2", "hash_bbbb")
+            .await
+            .unwrap();
+        assert_eq!(
+            get_consensus_hash(&pool, "proj_1", &oracles())
+                .await
+                .unwrap(),
+            None
+        );
+
+        record_vote(&pool, "proj_1", "oracle_3", "hash_aaaa")
+            .await
+            .unwrap();
+        assert_eq!(
+            get_consensus_hash(&pool, "proj_1", &oracles())
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("hash_aaaa")
+        );
+        assert_eq!(
+            get_consensus_hash(&pool, "proj_2", &oracles())
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unlisted_oracle_votes_do_not_count() {
+        let pool = setup_test_db().await;
+        set_quorum_threshold(&pool, 2).await.unwrap();
+
+        record_vote(&pool, "proj_1", "oracle_1", "hash_aaaa")
+            .await
+            .unwrap();
+        record_vote(&pool, "proj_1", "stranger", "hash_aaaa")
+            .await
+            .unwrap();
+
+        let status = get_quorum_status(&pool, "proj_1", &oracles())
+            .await
+            .unwrap();
+        assert_eq!(status.votes[0].count, 1);
+        assert!(!status.consensus_reached);
+        assert_eq!(
+            get_consensus_hash(&pool, "proj_1", &oracles())
+                .await
+                .unwrap(),
+            None
+        );
+    }
 }
@@ -19,14 +19,26 @@ pub enum EventKind {
     FundsReleased,
     /// Donator funds were refunded from an expired project (`refunded` topic).
     DonatorRefunded,
-    /// A role was granted or replaced (`role_set` topic).
+    /// A project passed its deadline without completing (`expired` topic).
+    ProjectExpired,
+    /// A role was granted or replaced (`role_set` topic). The data is a
+    /// `RoleGranted` struct; `granter` is the actor and is null for the
+    /// initial SuperAdmin.
     RoleSet,
-    /// A role was revoked (`role_del` topic).
+    /// A role was revoked (`role_del` topic). The data is a `RoleRevoked`
+    /// struct; `revoker` is the actor.
     RoleDel,
+    /// The SuperAdmin role moved to a new address (`sa_xfer` topic). The new
+    /// SuperAdmin is the second topic; the data's `old` field is the actor.
+    SuperAdminTransferred,
     /// Protocol was paused (`paused` topic).
     ProtocolPaused,
     /// Protocol was unpaused (`unpaused` topic).
     ProtocolUnpaused,
+    /// A creator paid the registration fee for a new project (`reg_fee` topic).
+    RegistrationFeePaid,
+    /// A protocol fee was taken from a release (`fee_coll` topic).
+    FeeCollected,
     /// An event from this contract that we don't recognise yet.
     Unknown,
 }
@@ -40,10 +52,14 @@ impl EventKind {
             "verified" => Self::ProjectVerified,
             "released" => Self::FundsReleased,
             "refunded" => Self::DonatorRefunded,
+            "expired" => Self::ProjectExpired,
             "role_set" => Self::RoleSet,
             "role_del" => Self::RoleDel,
+            "sa_xfer" => Self::SuperAdminTransferred,
             "paused" => Self::ProtocolPaused,
             "unpaused" => Self::ProtocolUnpaused,
+            "reg_fee" => Self::RegistrationFeePaid,
+            "fee_coll" => Self::FeeCollected,
             _ => Self::Unknown,
         }
     }
@@ -56,10 +72,14 @@ impl EventKind {
             Self::ProjectVerified => "project_verified",
             Self::FundsReleased => "funds_released",
             Self::DonatorRefunded => "donator_refunded",
+            Self::ProjectExpired => "project_expired",
             Self::RoleSet => "role_set",
             Self::RoleDel => "role_del",
+            Self::SuperAdminTransferred => "super_admin_transferred",
             Self::ProtocolPaused => "protocol_paused",
             Self::ProtocolUnpaused => "protocol_unpaused",
+            Self::RegistrationFeePaid => "registration_fee_paid",
+            Self::FeeCollected => "fee_collected",
             Self::Unknown => "unknown",
         }
     }
@@ -72,6 +92,11 @@ pub struct PifpEvent {
     pub project_id: Option<String>,
     pub actor: Option<String>,
     pub amount: Option<String>,
+    /// Token address for token-denominated events (`funded`, `released`,
+    /// `refunded`, `fee_coll`).
+    pub token: Option<String>,
+    /// Donor memo of a `funded` event made through `deposit_with_memo`.
+    pub memo: Option<String>,
     pub ledger: i64,
     pub timestamp: i64,
     pub contract_id: String,
@@ -86,6 +111,11 @@ pub struct EventRecord {
     pub project_id: Option<String>,
     pub actor: Option<String>,
     pub amount: Option<String>,
+    /// Token address for token-denominated events (`funded`, `released`,
+    /// `refunded`, `fee_coll`).
+    pub token: Option<String>,
+    /// Donor memo of a `funded` event, if one was attached.
+    pub memo: Option<String>,
     pub ledger: i64,
     pub timestamp: i64,
     pub contract_id: String,
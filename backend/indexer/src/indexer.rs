@@ -6,38 +6,50 @@ use std::time::Duration;
 
 use reqwest::Client;
 use sqlx::SqlitePool;
+use tokio::sync::broadcast;
 use tracing::{error, info};
 
 use crate::config::Config;
 use crate::db;
+use crate::events::PifpEvent;
 use crate::rpc;
 
 pub struct IndexerState {
     pub pool: SqlitePool,
     pub config: Config,
     pub client: Client,
+    /// Newly stored events are published here for `GET /ws/events`.
+    pub events_tx: broadcast::Sender<PifpEvent>,
 }
 
 /// Spawn the indexer loop as a background [`tokio`] task.
 pub async fn run(state: Arc<IndexerState>) {
     info!("Indexer starting — contract: {}", state.config.contract_id);
 
-    // Load the cursor from the DB; fall back to config start_ledger.
+    // Resume after the last confirmed ledger; fall back to the pre-confirmation
+    // cursor, then to config start_ledger.
+    let safe_ledger = db::get_safe_ledger(&state.pool).await.unwrap_or(0);
     let last_ledger = db::get_last_ledger(&state.pool).await.unwrap_or(0);
     let cursor_str = db::get_cursor_string(&state.pool).await.unwrap_or(None);
 
-    let mut current_ledger = if last_ledger > 0 {
+    let mut current_ledger = if safe_ledger > 0 {
+        safe_ledger as u32 + 1
+    } else if last_ledger > 0 {
         last_ledger as u32
     } else {
         state.config.start_ledger
     };
     let mut cursor: Option<String> = cursor_str;
 
-    info!("Resuming from ledger {current_ledger}");
+    info!(
+        "Resuming from ledger {current_ledger} (confirmation depth {})",
+        state.config.confirmation_depth
+    );
 
     loop {
         match poll_once(
             &state.pool,
+            &state.events_tx,
             &state.client,
             &state.config,
             current_ledger,
@@ -63,6 +75,7 @@ pub async fn run(state: Arc<IndexerState>) {
 /// Returns `(next_start_ledger, next_cursor)`.
 async fn poll_once(
     pool: &SqlitePool,
+    events_tx: &broadcast::Sender<PifpEvent>,
     client: &Client,
     config: &Config,
     start_ledger: u32,
@@ -78,26 +91,167 @@ async fn poll_once(
     )
     .await?;
 
+    let decoded = rpc::decode_events(&raw_events, &config.contract_id);
+    let tip = latest_ledger.unwrap_or(0) as i64;
+    let ingest =
+        ingest_confirmed(pool, events_tx, &decoded, tip, config.confirmation_depth).await?;
     if !raw_events.is_empty() {
-        let decoded = rpc::decode_events(&raw_events, &config.contract_id);
-        let inserted = db::insert_events(pool, &decoded).await?;
         info!(
             "Polled {} raw events → {} new records stored",
             raw_events.len(),
-            inserted
+            ingest.stored
         );
     }
 
     // Advance the ledger cursor:
-    // - If there is a next_cursor string, keep the same start_ledger so the next
-    //   call paginates within the same ledger range.
-    // - Otherwise advance to the latest known ledger.
-    let next_ledger = latest_ledger
-        .map(|l| (l as u32).max(start_ledger))
-        .unwrap_or(start_ledger);
+    // - While every event read so far is confirmed and the page came back
+    //   full, keep paginating within the same range with next_cursor.
+    // - Otherwise drop the cursor and re-read from just past the confirmed
+    //   tip, so events near the tip are fetched again until they confirm
+    //   (or vanish in a reorg).
+    let page_full = raw_events.len() as u32 >= config.events_per_page;
+    let (next_ledger, next_cursor) = if !ingest.held_back && page_full && next_cursor.is_some() {
+        (start_ledger, next_cursor)
+    } else {
+        let safe = ingest.confirmed_tip.max(start_ledger as i64 - 1);
+        db::save_safe_ledger(pool, safe).await?;
+        (safe as u32 + 1, None)
+    };
 
     // Persist cursor so restarts are deterministic.
     db::save_cursor(pool, next_ledger as i64, next_cursor.as_deref()).await?;
 
     Ok((next_ledger, next_cursor))
 }
+
+/// What [`ingest_confirmed`] did with one page of events.
+#[derive(Debug, PartialEq)]
+pub struct Ingest {
+    /// How many confirmed events were new.
+    pub stored: usize,
+    /// Highest ledger at least `confirmation_depth` behind the tip.
+    pub confirmed_tip: i64,
+    /// Whether any event was too close to the tip to persist yet.
+    pub held_back: bool,
+}
+
+/// Persist the events of a page that are at least `confirmation_depth`
+/// ledgers behind the network `tip`, holding back the rest.
+///
+/// Held-back events are not remembered: the next poll reads their ledgers
+/// again and stores whatever is there once they are deep enough.
+pub async fn ingest_confirmed(
+    pool: &SqlitePool,
+    events_tx: &broadcast::Sender<PifpEvent>,
+    events: &[PifpEvent],
+    tip: i64,
+    confirmation_depth: u32,
+) -> crate::errors::Result<Ingest> {
+    let confirmed_tip = tip - confirmation_depth as i64;
+    let confirmed: Vec<PifpEvent> = events
+        .iter()
+        .filter(|event| event.ledger <= confirmed_tip)
+        .cloned()
+        .collect();
+    let stored = if confirmed.is_empty() {
+        0
+    } else {
+        store_events(pool, events_tx, &confirmed).await?
+    };
+    Ok(Ingest {
+        stored,
+        confirmed_tip,
+        held_back: confirmed.len() < events.len(),
+    })
+}
+
+/// Persist decoded events and publish the new ones to live subscribers.
+///
+/// Returns how many events were new. Having no subscribers is not an error.
+pub async fn store_events(
+    pool: &SqlitePool,
+    events_tx: &broadcast::Sender<PifpEvent>,
+    events: &[PifpEvent],
+) -> crate::errors::Result<usize> {
+    let inserted = db::insert_events(pool, events).await?;
+    for event in &inserted {
+        let _ = events_tx.send(event.clone());
+    }
+    Ok(inserted.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use super::*;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+        pool
+    }
+
+    fn funded_at(ledger: i64, tx_hash: &str) -> PifpEvent {
+        PifpEvent {
+            event_type: "project_funded".to_string(),
+            project_id: Some("42".to_string()),
+            actor: Some("GDONOR".to_string()),
+            amount: Some("100".to_string()),
+            token: Some("CTOKEN".to_string()),
+            memo: None,
+            ledger,
+            timestamp: 1_704_067_200,
+            contract_id: "CONTRACT1".to_string(),
+            tx_hash: Some(tx_hash.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn event_at_tip_is_held_back_until_confirmed() {
+        let pool = test_pool().await;
+        let (events_tx, _) = broadcast::channel(16);
+
+        // Ledger 100 is the tip: its event is not persisted yet.
+        let first = ingest_confirmed(&pool, &events_tx, &[funded_at(100, "tx_orphan")], 100, 1)
+            .await
+            .unwrap();
+        assert_eq!(
+            first,
+            Ingest {
+                stored: 0,
+                confirmed_tip: 99,
+                held_back: true,
+            }
+        );
+        assert!(db::get_all_events(&pool).await.unwrap().is_empty());
+
+        // The ledger is reorged; re-reading it a poll later finds a
+        // different event, now one ledger deep.
+        let second = ingest_confirmed(&pool, &events_tx, &[funded_at(100, "tx_canonical")], 101, 1)
+            .await
+            .unwrap();
+        assert_eq!(second.stored, 1);
+        assert!(!second.held_back);
+
+        let stored = db::get_all_events(&pool).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].tx_hash.as_deref(), Some("tx_canonical"));
+    }
+
+    #[tokio::test]
+    async fn safe_ledger_is_stored_apart_from_cursor() {
+        let pool = test_pool().await;
+        assert_eq!(db::get_safe_ledger(&pool).await.unwrap(), 0);
+
+        db::save_safe_ledger(&pool, 99).await.unwrap();
+        db::save_cursor(&pool, 100, Some("cursor")).await.unwrap();
+
+        assert_eq!(db::get_safe_ledger(&pool).await.unwrap(), 99);
+        assert_eq!(db::get_last_ledger(&pool).await.unwrap(), 100);
+    }
+}
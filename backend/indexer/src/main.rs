@@ -10,6 +10,7 @@ mod db;
 mod errors;
 mod events;
 mod indexer;
+mod rate_limit;
 mod rpc;
 
 use std::sync::Arc;
@@ -19,6 +20,7 @@ use axum::{
     Router,
 };
 use reqwest::Client;
+use tokio::sync::broadcast;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use tracing::info;
@@ -27,6 +29,9 @@ use tracing_subscriber::EnvFilter;
 use config::Config;
 use indexer::IndexerState;
 
+/// Events buffered per WebSocket subscriber before the slowest one lags.
+const EVENT_STREAM_CAPACITY: usize = 1024;
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Initialise structured logging (RUST_LOG controls verbosity).
@@ -48,24 +53,38 @@ async fn main() -> anyhow::Result<()> {
         .timeout(std::time::Duration::from_secs(30))
         .build()?;
 
+    // Newly indexed events fan out from the indexer to WebSocket clients.
+    let (events_tx, _) = broadcast::channel(EVENT_STREAM_CAPACITY);
+
     // ─── Background indexer ───────────────────────────────
     let indexer_state = Arc::new(IndexerState {
         pool: pool.clone(),
         config: config.clone(),
         client,
+        events_tx: events_tx.clone(),
     });
     tokio::spawn(indexer::run(indexer_state));
 
     // ─── REST API ─────────────────────────────────────────
-    let api_state = Arc::new(api::ApiState { pool });
+    let api_state = Arc::new(api::ApiState {
+        pool,
+        events_tx,
+        vote_limiter: rate_limit::RateLimiter::new(config.votes_per_minute),
+        oracles: config.oracle_addresses.iter().cloned().collect(),
+    });
 
     let app = Router::new()
         .route("/health", get(api::health))
         .route("/events", get(api::get_all_events))
+        .route("/ws/events", get(api::stream_events))
+        .route("/projects", get(api::list_projects))
+        .route("/projects/:id", get(api::get_project))
         .route("/projects/:id/events", get(api::get_project_events))
+        .route("/projects/:id/balances", get(api::get_project_balances))
         .route("/admin/quorum", post(api::set_quorum_threshold))
         .route("/projects/:id/vote", post(api::submit_vote))
         .route("/projects/:id/quorum", get(api::get_project_quorum))
+        .route("/projects/:id/consensus", get(api::get_project_consensus))
         .layer(CorsLayer::permissive())
         .layer(TraceLayer::new_for_http())
         .with_state(api_state);
@@ -198,12 +198,19 @@ fn decode_single(raw: &RawEvent, contract_id: &str) -> Option<PifpEvent> {
     let project_id = raw.topic.get(1).map(|t| extract_u64_or_raw(t));
 
     let (actor, amount) = decode_data(&raw.value, &kind);
+    let token = decode_token(raw, &kind);
+    let memo = match kind {
+        EventKind::ProjectFunded => extract_field(&raw.value, &["memo"]),
+        _ => None,
+    };
 
     Some(PifpEvent {
         event_type: kind.as_str().to_string(),
         project_id,
         actor,
         amount,
+        token,
+        memo,
         ledger,
         timestamp,
         contract_id: raw
@@ -233,7 +240,10 @@ fn decode_data(value: &Value, kind: &EventKind) -> (Option<String>, Option<Strin
             (actor, amount)
         }
         EventKind::ProjectFunded => {
-            let actor = extract_field(value, &["donator", "funder", "address"]);
+            // Anonymous deposits name the contract itself as the donator.
+            let anonymous = value.get("is_anonymous").and_then(Value::as_bool) == Some(true);
+            let actor =
+                extract_field(value, &["donator", "funder", "address"]).filter(|_| !anonymous);
             let amount = extract_field(value, &["amount"]);
             (actor, amount)
         }
@@ -241,7 +251,7 @@ fn decode_data(value: &Value, kind: &EventKind) -> (Option<String>, Option<Strin
             let actor = extract_field(value, &["oracle", "verifier", "address"]);
             (actor, None)
         }
-        EventKind::FundsReleased => {
+        EventKind::FundsReleased | EventKind::FeeCollected => {
             let amount = extract_field(value, &["amount"]);
             (None, amount)
         }
@@ -261,13 +271,14 @@ fn decode_data(value: &Value, kind: &EventKind) -> (Option<String>, Option<Strin
             (actor, amount)
         }
         EventKind::RoleSet | EventKind::RoleDel => {
-            // For role events the "data" is typically the caller address
+            // Older contracts published the bare caller address as data.
             let actor = value
                 .as_str()
                 .map(String::from)
-                .or_else(|| extract_field(value, &["address", "caller", "by"]));
+                .or_else(|| extract_field(value, &["granter", "revoker", "address", "caller"]));
             (actor, None)
         }
+        EventKind::SuperAdminTransferred => (extract_field(value, &["old"]), None),
         EventKind::ProtocolPaused | EventKind::ProtocolUnpaused => {
             let actor = value
                 .as_str()
@@ -275,7 +286,27 @@ fn decode_data(value: &Value, kind: &EventKind) -> (Option<String>, Option<Strin
                 .or_else(|| extract_field(value, &["address"]));
             (actor, None)
         }
-        EventKind::Unknown => (None, None),
+        EventKind::RegistrationFeePaid => {
+            let actor = extract_field(value, &["creator", "address"]);
+            let amount = extract_field(value, &["amount"]);
+            (actor, amount)
+        }
+        EventKind::ProjectExpired | EventKind::Unknown => (None, None),
+    }
+}
+
+/// Pull the token address out of token-denominated events.
+///
+/// All of them carry it as the third topic; `released` and `fee_coll` also
+/// repeat it in the data struct.
+fn decode_token(raw: &RawEvent, kind: &EventKind) -> Option<String> {
+    match kind {
+        EventKind::ProjectFunded
+        | EventKind::FundsReleased
+        | EventKind::DonatorRefunded
+        | EventKind::FeeCollected => extract_field(&raw.value, &["token"])
+            .or_else(|| raw.topic.get(2).map(|t| extract_u64_or_raw(t))),
+        _ => None,
     }
 }
 
@@ -376,13 +407,23 @@ mod tests {
             EventKind::from_topic("refunded"),
             EventKind::DonatorRefunded
         );
+        assert_eq!(EventKind::from_topic("expired"), EventKind::ProjectExpired);
         assert_eq!(EventKind::from_topic("role_set"), EventKind::RoleSet);
         assert_eq!(EventKind::from_topic("role_del"), EventKind::RoleDel);
+        assert_eq!(
+            EventKind::from_topic("sa_xfer"),
+            EventKind::SuperAdminTransferred
+        );
         assert_eq!(EventKind::from_topic("paused"), EventKind::ProtocolPaused);
         assert_eq!(
             EventKind::from_topic("unpaused"),
             EventKind::ProtocolUnpaused
         );
+        assert_eq!(
+            EventKind::from_topic("reg_fee"),
+            EventKind::RegistrationFeePaid
+        );
+        assert_eq!(EventKind::from_topic("fee_coll"), EventKind::FeeCollected);
         assert_eq!(EventKind::from_topic("something_else"), EventKind::Unknown);
     }
 
@@ -393,6 +434,7 @@ mod tests {
         assert_eq!(EventKind::ProjectVerified.as_str(), "project_verified");
         assert_eq!(EventKind::FundsReleased.as_str(), "funds_released");
         assert_eq!(EventKind::DonatorRefunded.as_str(), "donator_refunded");
+        assert_eq!(EventKind::ProjectExpired.as_str(), "project_expired");
         assert_eq!(EventKind::RoleSet.as_str(), "role_set");
         assert_eq!(EventKind::RoleDel.as_str(), "role_del");
     }
@@ -414,6 +456,7 @@ mod tests {
             topic: vec![
                 r#"{"type":"symbol","value":"funded"}"#.to_string(),
                 r#"{"type":"u64","value":"42"}"#.to_string(),
+                r#"{"type":"address","value":"CTOKEN"}"#.to_string(),
             ],
             value: serde_json::json!({ "donator": "GABC123", "amount": "5000" }),
             contract_id: Some("CONTRACT1".to_string()),
@@ -432,9 +475,68 @@ mod tests {
         assert_eq!(ev.project_id.as_deref(), Some("42"));
         assert_eq!(ev.actor.as_deref(), Some("GABC123"));
         assert_eq!(ev.amount.as_deref(), Some("5000"));
+        assert_eq!(ev.token.as_deref(), Some("CTOKEN"));
+        assert_eq!(ev.memo, None);
         assert_eq!(ev.ledger, 1000);
     }
 
+    #[test]
+    fn decode_funded_event_with_memo() {
+        let raw = RawEvent {
+            topic: vec![
+                r#"{"type":"symbol","value":"funded"}"#.to_string(),
+                r#"{"type":"u64","value":"42"}"#.to_string(),
+                r#"{"type":"address","value":"CTOKEN"}"#.to_string(),
+            ],
+            value: serde_json::json!({
+                "donator": "GABC123",
+                "amount": "5000",
+                "memo": "order #1234",
+            }),
+            contract_id: Some("CONTRACT1".to_string()),
+            tx_hash: Some("TX1".to_string()),
+            id: None,
+            ledger: Some(1000),
+            ledger_closed_at: Some("2024-01-01T00:00:00Z".to_string()),
+            in_successful_contract_call: Some(true),
+            paging_token: None,
+        };
+
+        let events = decode_events(&[raw], "CONTRACT1");
+        assert_eq!(events[0].event_type, "project_funded");
+        assert_eq!(events[0].amount.as_deref(), Some("5000"));
+        assert_eq!(events[0].memo.as_deref(), Some("order #1234"));
+    }
+
+    #[test]
+    fn decode_anonymous_funded_event() {
+        let raw = RawEvent {
+            topic: vec![
+                r#"{"type":"symbol","value":"funded"}"#.to_string(),
+                r#"{"type":"u64","value":"42"}"#.to_string(),
+                r#"{"type":"address","value":"CTOKEN"}"#.to_string(),
+            ],
+            value: serde_json::json!({
+                "donator": "CONTRACT1",
+                "amount": "5000",
+                "memo": null,
+                "is_anonymous": true,
+            }),
+            contract_id: Some("CONTRACT1".to_string()),
+            tx_hash: Some("TX1".to_string()),
+            id: None,
+            ledger: Some(1000),
+            ledger_closed_at: Some("2024-01-01T00:00:00Z".to_string()),
+            in_successful_contract_call: Some(true),
+            paging_token: None,
+        };
+
+        let events = decode_events(&[raw], "CONTRACT1");
+        assert_eq!(events[0].event_type, "project_funded");
+        assert_eq!(events[0].actor, None);
+        assert_eq!(events[0].amount.as_deref(), Some("5000"));
+    }
+
     #[test]
     fn decode_role_set_event() {
         let raw = RawEvent {
@@ -459,12 +561,57 @@ mod tests {
         assert_eq!(events[0].actor.as_deref(), Some("GCALLER"));
     }
 
+    #[test]
+    fn decode_role_events_struct_data() {
+        let granted = RawEvent {
+            topic: vec![
+                r#"{"type":"symbol","value":"role_set"}"#.to_string(),
+                r#"{"type":"address","value":"GORACLE"}"#.to_string(),
+                r#"{"type":"symbol","value":"oracle"}"#.to_string(),
+            ],
+            value: serde_json::json!({ "granter": "GADMIN", "target": "GORACLE", "role": ["Oracle"] }),
+            contract_id: Some("CONTRACT1".to_string()),
+            tx_hash: Some("TX4".to_string()),
+            id: None,
+            ledger: Some(1003),
+            ledger_closed_at: Some("2024-01-01T00:00:03Z".to_string()),
+            in_successful_contract_call: Some(true),
+            paging_token: None,
+        };
+        let revoked = RawEvent {
+            topic: vec![
+                r#"{"type":"symbol","value":"role_del"}"#.to_string(),
+                r#"{"type":"address","value":"GORACLE"}"#.to_string(),
+            ],
+            value: serde_json::json!({ "revoker": "GADMIN", "target": "GORACLE" }),
+            ..granted.clone()
+        };
+        let transferred = RawEvent {
+            topic: vec![
+                r#"{"type":"symbol","value":"sa_xfer"}"#.to_string(),
+                r#"{"type":"address","value":"GNEW"}"#.to_string(),
+            ],
+            value: serde_json::json!({ "old": "GOLD", "new": "GNEW" }),
+            ..granted.clone()
+        };
+
+        let events = decode_events(&[granted, revoked, transferred], "CONTRACT1");
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].event_type, "role_set");
+        assert_eq!(events[0].actor.as_deref(), Some("GADMIN"));
+        assert_eq!(events[1].event_type, "role_del");
+        assert_eq!(events[1].actor.as_deref(), Some("GADMIN"));
+        assert_eq!(events[2].event_type, "super_admin_transferred");
+        assert_eq!(events[2].actor.as_deref(), Some("GOLD"));
+    }
+
     #[test]
     fn decode_refunded_event_tuple_data() {
         let raw = RawEvent {
             topic: vec![
                 r#"{"type":"symbol","value":"refunded"}"#.to_string(),
                 r#"{"type":"u64","value":"42"}"#.to_string(),
+                r#"{"type":"address","value":"CTOKEN"}"#.to_string(),
             ],
             value: serde_json::json!(["GDONATOR", "750"]),
             contract_id: Some("CONTRACT1".to_string()),
@@ -483,6 +630,86 @@ mod tests {
         assert_eq!(ev.project_id.as_deref(), Some("42"));
         assert_eq!(ev.actor.as_deref(), Some("GDONATOR"));
         assert_eq!(ev.amount.as_deref(), Some("750"));
+        assert_eq!(ev.token.as_deref(), Some("CTOKEN"));
+    }
+
+    #[test]
+    fn decode_released_event() {
+        let raw = RawEvent {
+            topic: vec![
+                r#"{"type":"symbol","value":"released"}"#.to_string(),
+                r#"{"type":"u64","value":"42"}"#.to_string(),
+                r#"{"type":"address","value":"CTOKEN"}"#.to_string(),
+            ],
+            value: serde_json::json!({ "project_id": 42, "token": "CTOKEN", "amount": "1000" }),
+            contract_id: Some("CONTRACT1".to_string()),
+            tx_hash: Some("TX5".to_string()),
+            id: None,
+            ledger: Some(1004),
+            ledger_closed_at: Some("2024-01-01T00:00:04Z".to_string()),
+            in_successful_contract_call: Some(true),
+            paging_token: None,
+        };
+
+        let events = decode_events(&[raw], "CONTRACT1");
+        assert_eq!(events.len(), 1);
+        let ev = &events[0];
+        assert_eq!(ev.event_type, "funds_released");
+        assert_eq!(ev.project_id.as_deref(), Some("42"));
+        assert_eq!(ev.amount.as_deref(), Some("1000"));
+        assert_eq!(ev.token.as_deref(), Some("CTOKEN"));
+    }
+
+    #[test]
+    fn non_token_events_have_no_token() {
+        let raw = RawEvent {
+            topic: vec![
+                r#"{"type":"symbol","value":"verified"}"#.to_string(),
+                r#"{"type":"u64","value":"42"}"#.to_string(),
+            ],
+            value: serde_json::json!({ "oracle": "GORACLE" }),
+            contract_id: Some("CONTRACT1".to_string()),
+            tx_hash: Some("TX6".to_string()),
+            id: None,
+            ledger: Some(1005),
+            ledger_closed_at: None,
+            in_successful_contract_call: Some(true),
+            paging_token: None,
+        };
+
+        let events = decode_events(&[raw], "CONTRACT1");
+        assert_eq!(events[0].token, None);
+    }
+
+    #[test]
+    fn decode_registration_fee_event() {
+        let raw = RawEvent {
+            topic: vec![
+                r#"{"type":"symbol","value":"reg_fee"}"#.to_string(),
+                r#"{"type":"u64","value":"7"}"#.to_string(),
+            ],
+            value: serde_json::json!({
+                "project_id": 7,
+                "creator": "GCREATOR",
+                "token": "CTOKEN",
+                "amount": "100"
+            }),
+            contract_id: Some("CONTRACT1".to_string()),
+            tx_hash: Some("TX4".to_string()),
+            id: None,
+            ledger: Some(1003),
+            ledger_closed_at: Some("2024-01-01T00:00:03Z".to_string()),
+            in_successful_contract_call: Some(true),
+            paging_token: None,
+        };
+
+        let events = decode_events(&[raw], "CONTRACT1");
+        assert_eq!(events.len(), 1);
+        let ev = &events[0];
+        assert_eq!(ev.event_type, "registration_fee_paid");
+        assert_eq!(ev.project_id.as_deref(), Some("7"));
+        assert_eq!(ev.actor.as_deref(), Some("GCREATOR"));
+        assert_eq!(ev.amount.as_deref(), Some("100"));
     }
 
     #[test]
@@ -1,4 +1,7 @@
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String};
+
+use crate::rbac::{self, Role};
+use crate::types::{Pledge, ProjectBond};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -15,6 +18,15 @@ pub struct ProjectFunded {
     pub project_id: u64,
     pub donator: Address,
     pub amount: i128,
+    /// Donor's note from `deposit_with_memo`; never stored on-chain.
+    pub memo: Option<String>,
+    /// Set by `deposit_anonymous`; `donator` is then the contract itself.
+    pub is_anonymous: bool,
+    /// Lifetime amount raised in the deposited token, including this deposit
+    /// and any matched funds.
+    pub total_raised: i128,
+    /// Goal for the deposited token; 0 when the token has none.
+    pub goal: i128,
 }
 
 #[contracttype]
@@ -23,6 +35,16 @@ pub struct ProjectActive {
     pub project_id: u64,
 }
 
+/// Emitted once, alongside `ProjectActive`, when deposits first meet the goal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GoalReached {
+    pub project_id: u64,
+    /// Lifetime amount raised towards the goal: across every accepted token
+    /// for a single goal, otherwise in the token that met its goal.
+    pub total: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProjectVerified {
@@ -46,6 +68,265 @@ pub struct FundsReleased {
     pub amount: i128,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCollected {
+    pub project_id: u64,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesWithdrawn {
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundWaived {
+    pub project_id: u64,
+    pub token: Address,
+    pub donator: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WaivedClaimed {
+    pub project_id: u64,
+    pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnclaimedSwept {
+    pub project_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub destination: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UntrackedSwept {
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistrationFeePaid {
+    pub project_id: u64,
+    pub creator: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondReturned {
+    pub project_id: u64,
+    pub creator: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectSlashed {
+    pub project_id: u64,
+    pub slashed_by: Address,
+    pub reason_hash: BytesN<32>,
+    /// Token of the bond moved to the fee treasury; `None` if there was none.
+    pub bond_token: Option<Address>,
+    /// Amount moved to the fee treasury; 0 if there was no bond.
+    pub bond_amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlineExtended {
+    pub project_id: u64,
+    pub old_deadline: u64,
+    pub new_deadline: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofHashUpdated {
+    pub project_id: u64,
+    pub old_hash: BytesN<32>,
+    pub new_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleAssigned {
+    pub project_id: u64,
+    pub old_oracle: Option<Address>,
+    pub new_oracle: Option<Address>,
+}
+
+/// `granter` is `None` only for the SuperAdmin set at initialisation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGranted {
+    pub granter: Option<Address>,
+    pub target: Address,
+    pub role: Role,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevoked {
+    pub revoker: Address,
+    pub target: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SuperAdminTransferred {
+    pub old: Address,
+    pub new: Address,
+}
+
+/// Also kept as an on-chain history record; see `get_oracle_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleGranted {
+    pub granter: Address,
+    pub oracle: Address,
+    /// Ledger sequence of the grant.
+    pub ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptedTokenAdded {
+    pub project_id: u64,
+    pub token: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptedTokenRemoved {
+    pub project_id: u64,
+    pub token: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutAddressChanged {
+    pub project_id: u64,
+    pub old_address: Address,
+    pub new_address: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upgraded {
+    pub new_wasm_hash: BytesN<32>,
+    pub version: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteSubmitted {
+    pub project_id: u64,
+    pub oracle: Address,
+    pub proof_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectCancelled {
+    pub project_id: u64,
+    pub cancelled_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseApproved {
+    pub project_id: u64,
+    pub oracle: Address,
+    pub proof_hash: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseCoSigned {
+    pub project_id: u64,
+    pub oracle: Address,
+    pub admin: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseScheduled {
+    pub project_id: u64,
+    pub release_after: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseVetoed {
+    pub project_id: u64,
+    pub vetoed_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchingPoolFunded {
+    pub project_id: u64,
+    pub token: Address,
+    pub sponsor: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationMatched {
+    pub project_id: u64,
+    pub token: Address,
+    pub sponsor: Address,
+    pub matched_amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PledgeCreated {
+    pub pledge_id: u64,
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount_per_period: i128,
+    pub period_secs: u64,
+    pub periods: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PledgeExecuted {
+    pub pledge_id: u64,
+    pub project_id: u64,
+    pub amount: i128,
+    pub remaining_periods: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PledgeCancelled {
+    pub pledge_id: u64,
+    pub project_id: u64,
+    pub donator: Address,
+}
+
 pub fn emit_project_created(
     env: &Env,
     project_id: u64,
@@ -63,12 +344,29 @@ pub fn emit_project_created(
     env.events().publish(topics, data);
 }
 
-pub fn emit_project_funded(env: &Env, project_id: u64, donator: Address, amount: i128) {
-    let topics = (symbol_short!("funded"), project_id);
+/// Topics are `(funded, project_id, token)` so indexers can attribute the
+/// amount to a token without an RPC call.
+#[allow(clippy::too_many_arguments)]
+pub fn emit_project_funded(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    donator: Address,
+    amount: i128,
+    memo: Option<String>,
+    is_anonymous: bool,
+    total_raised: i128,
+    goal: i128,
+) {
+    let topics = (symbol_short!("funded"), project_id, token);
     let data = ProjectFunded {
         project_id,
         donator,
         amount,
+        memo,
+        is_anonymous,
+        total_raised,
+        goal,
     };
     env.events().publish(topics, data);
 }
@@ -79,6 +377,12 @@ pub fn emit_project_active(env: &Env, project_id: u64) {
     env.events().publish(topics, data);
 }
 
+pub fn emit_goal_reached(env: &Env, project_id: u64, total: i128) {
+    let topics = (symbol_short!("goal_rchd"), project_id);
+    let data = GoalReached { project_id, total };
+    env.events().publish(topics, data);
+}
+
 pub fn emit_project_verified(env: &Env, project_id: u64, oracle: Address, proof_hash: BytesN<32>) {
     let topics = (symbol_short!("verified"), project_id);
     let data = ProjectVerified {
@@ -98,6 +402,161 @@ pub fn emit_project_expired(env: &Env, project_id: u64, deadline: u64) {
     env.events().publish(topics, data);
 }
 
+pub fn emit_project_cancelled(env: &Env, project_id: u64, cancelled_by: Address) {
+    let topics = (symbol_short!("cancelled"), project_id);
+    let data = ProjectCancelled {
+        project_id,
+        cancelled_by,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_deadline_extended(env: &Env, project_id: u64, old_deadline: u64, new_deadline: u64) {
+    let topics = (symbol_short!("dl_ext"), project_id);
+    let data = DeadlineExtended {
+        project_id,
+        old_deadline,
+        new_deadline,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_proof_hash_updated(
+    env: &Env,
+    project_id: u64,
+    old_hash: BytesN<32>,
+    new_hash: BytesN<32>,
+) {
+    let topics = (symbol_short!("proof_upd"), project_id);
+    let data = ProofHashUpdated {
+        project_id,
+        old_hash,
+        new_hash,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_oracle_assigned(
+    env: &Env,
+    project_id: u64,
+    old_oracle: Option<Address>,
+    new_oracle: Option<Address>,
+) {
+    let topics = (symbol_short!("orc_asgn"), project_id);
+    let data = OracleAssigned {
+        project_id,
+        old_oracle,
+        new_oracle,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_role_granted(env: &Env, granter: Option<Address>, target: Address, role: Role) {
+    let topics = (
+        symbol_short!("role_set"),
+        target.clone(),
+        rbac::role_to_symbol(&role),
+    );
+    let data = RoleGranted {
+        granter,
+        target,
+        role,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_role_revoked(env: &Env, revoker: Address, target: Address) {
+    let topics = (symbol_short!("role_del"), target.clone());
+    let data = RoleRevoked { revoker, target };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_super_admin_transferred(env: &Env, old: Address, new: Address) {
+    let topics = (symbol_short!("sa_xfer"), new.clone());
+    let data = SuperAdminTransferred { old, new };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_oracle_granted(env: &Env, grant: &OracleGranted) {
+    let topics = (symbol_short!("orc_grant"), grant.oracle.clone());
+    env.events().publish(topics, grant.clone());
+}
+
+pub fn emit_accepted_token_added(env: &Env, project_id: u64, token: Address) {
+    let topics = (symbol_short!("tok_add"), project_id);
+    let data = AcceptedTokenAdded { project_id, token };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_accepted_token_removed(env: &Env, project_id: u64, token: Address) {
+    let topics = (symbol_short!("tok_rm"), project_id);
+    let data = AcceptedTokenRemoved { project_id, token };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_payout_address_changed(
+    env: &Env,
+    project_id: u64,
+    old_address: Address,
+    new_address: Address,
+) {
+    let topics = (symbol_short!("payout"), project_id);
+    let data = PayoutAddressChanged {
+        project_id,
+        old_address,
+        new_address,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_vote_submitted(env: &Env, project_id: u64, oracle: Address, proof_hash: BytesN<32>) {
+    let topics = (symbol_short!("vote"), project_id, oracle.clone());
+    let data = VoteSubmitted {
+        project_id,
+        oracle,
+        proof_hash,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_release_approved(env: &Env, project_id: u64, oracle: Address, proof_hash: BytesN<32>) {
+    let topics = (symbol_short!("rel_appr"), project_id);
+    let data = ReleaseApproved {
+        project_id,
+        oracle,
+        proof_hash,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_release_cosigned(env: &Env, project_id: u64, oracle: Address, admin: Address) {
+    let topics = (symbol_short!("rel_cosig"), project_id);
+    let data = ReleaseCoSigned {
+        project_id,
+        oracle,
+        admin,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_release_scheduled(env: &Env, project_id: u64, release_after: u64) {
+    let topics = (symbol_short!("rel_sched"), project_id);
+    let data = ReleaseScheduled {
+        project_id,
+        release_after,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_release_vetoed(env: &Env, project_id: u64, vetoed_by: Address) {
+    let topics = (symbol_short!("rel_veto"), project_id);
+    let data = ReleaseVetoed {
+        project_id,
+        vetoed_by,
+    };
+    env.events().publish(topics, data);
+}
+
 pub fn emit_funds_released(env: &Env, project_id: u64, token: Address, amount: i128) {
     let topics = (symbol_short!("released"), project_id, token.clone());
     let data = FundsReleased {
@@ -108,9 +567,234 @@ pub fn emit_funds_released(env: &Env, project_id: u64, token: Address, amount: i
     env.events().publish(topics, data);
 }
 
-pub fn emit_refunded(env: &Env, project_id: u64, donator: Address, amount: i128) {
-    let topics = (symbol_short!("refunded"), project_id);
-    let data = (donator, amount);
+pub fn emit_fee_collected(env: &Env, project_id: u64, token: Address, amount: i128) {
+    let topics = (symbol_short!("fee_coll"), project_id, token.clone());
+    let data = FeeCollected {
+        project_id,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_fees_withdrawn(env: &Env, token: Address, amount: i128, to: Address) {
+    let topics = (symbol_short!("fee_wdraw"), token.clone());
+    let data = FeesWithdrawn { token, amount, to };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_refund_waived(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    donator: Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("waived"), project_id, token.clone());
+    let data = RefundWaived {
+        project_id,
+        token,
+        donator,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_waived_claimed(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    recipient: Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("waiv_clmd"), project_id, token.clone());
+    let data = WaivedClaimed {
+        project_id,
+        token,
+        recipient,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_unclaimed_swept(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    amount: i128,
+    destination: Address,
+) {
+    let topics = (symbol_short!("unclaimed"), project_id, token.clone());
+    let data = UnclaimedSwept {
+        project_id,
+        token,
+        amount,
+        destination,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_untracked_swept(env: &Env, token: Address, amount: i128, recipient: Address) {
+    let topics = (symbol_short!("swept"), token.clone());
+    let data = UntrackedSwept {
+        token,
+        amount,
+        recipient,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_registration_fee_paid(
+    env: &Env,
+    project_id: u64,
+    creator: Address,
+    token: Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("reg_fee"), project_id);
+    let data = RegistrationFeePaid {
+        project_id,
+        creator,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_bond_returned(
+    env: &Env,
+    project_id: u64,
+    creator: Address,
+    token: Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("bond_ret"), project_id);
+    let data = BondReturned {
+        project_id,
+        creator,
+        token,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_project_slashed(
+    env: &Env,
+    project_id: u64,
+    slashed_by: Address,
+    reason_hash: BytesN<32>,
+    bond: Option<ProjectBond>,
+) {
+    let topics = (symbol_short!("slashed"), project_id);
+    let (bond_token, bond_amount) = match bond {
+        Some(bond) => (Some(bond.token), bond.amount),
+        None => (None, 0),
+    };
+    let data = ProjectSlashed {
+        project_id,
+        slashed_by,
+        reason_hash,
+        bond_token,
+        bond_amount,
+    };
+    env.events().publish(topics, data);
+}
+
+/// Topics are `(refunded, project_id, token)` and data is
+/// `(donator, amount, recipient)`; `token` and `recipient` are appended so
+/// consumers reading the leading positions keep working.
+pub fn emit_refunded(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    donator: Address,
+    amount: i128,
+    recipient: Address,
+) {
+    let topics = (symbol_short!("refunded"), project_id, token);
+    let data = (donator, amount, recipient);
+    env.events().publish(topics, data);
+}
+
+pub fn emit_matching_pool_funded(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    sponsor: Address,
+    amount: i128,
+) {
+    let topics = (symbol_short!("match_fnd"), project_id, token.clone());
+    let data = MatchingPoolFunded {
+        project_id,
+        token,
+        sponsor,
+        amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_donation_matched(
+    env: &Env,
+    project_id: u64,
+    token: Address,
+    sponsor: Address,
+    matched_amount: i128,
+) {
+    let topics = (symbol_short!("matched"), project_id, token.clone());
+    let data = DonationMatched {
+        project_id,
+        token,
+        sponsor,
+        matched_amount,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_pledge_created(env: &Env, pledge: &Pledge) {
+    let topics = (
+        symbol_short!("pledged"),
+        pledge.project_id,
+        pledge.donator.clone(),
+    );
+    let data = PledgeCreated {
+        pledge_id: pledge.id,
+        project_id: pledge.project_id,
+        donator: pledge.donator.clone(),
+        token: pledge.token.clone(),
+        amount_per_period: pledge.amount_per_period,
+        period_secs: pledge.period_secs,
+        periods: pledge.remaining_periods,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_pledge_executed(env: &Env, pledge: &Pledge) {
+    let topics = (symbol_short!("pledge_ex"), pledge.project_id, pledge.id);
+    let data = PledgeExecuted {
+        pledge_id: pledge.id,
+        project_id: pledge.project_id,
+        amount: pledge.amount_per_period,
+        remaining_periods: pledge.remaining_periods,
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_pledge_cancelled(env: &Env, pledge: &Pledge) {
+    let topics = (symbol_short!("pledge_cx"), pledge.project_id, pledge.id);
+    let data = PledgeCancelled {
+        pledge_id: pledge.id,
+        project_id: pledge.project_id,
+        donator: pledge.donator.clone(),
+    };
+    env.events().publish(topics, data);
+}
+
+pub fn emit_upgraded(env: &Env, new_wasm_hash: BytesN<32>, version: u32) {
+    let topics = (symbol_short!("upgraded"),);
+    let data = Upgraded {
+        new_wasm_hash,
+        version,
+    };
     env.events().publish(topics, data);
 }
 
@@ -121,3 +805,13 @@ pub fn emit_protocol_paused(env: &Env, admin: Address) {
 pub fn emit_protocol_unpaused(env: &Env, admin: Address) {
     env.events().publish((symbol_short!("unpaused"), admin), ());
 }
+
+pub fn emit_address_blocked(env: &Env, admin: Address, address: Address) {
+    env.events()
+        .publish((symbol_short!("blocked"), address), admin);
+}
+
+pub fn emit_address_unblocked(env: &Env, admin: Address, address: Address) {
+    env.events()
+        .publish((symbol_short!("unblocked"), address), admin);
+}
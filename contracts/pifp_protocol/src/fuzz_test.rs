@@ -2,12 +2,15 @@ extern crate std;
 use std::vec::Vec;
 
 use proptest::prelude::*;
-use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env, Vec as SorobanVec};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String, Vec as SorobanVec,
+};
 
 use crate::invariants::*;
 pub use crate::types::ProjectStatus;
 pub use crate::Role;
-use crate::{PifpProtocol, PifpProtocolClient};
+use crate::{PifpProtocol, PifpProtocolClient, ProjectOptions};
 
 // ── Helpers ─────────────────────────────────────────────────────────
 
@@ -26,6 +29,12 @@ fn create_token<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
     token::Client::new(env, &addr.address())
 }
 
+/// Assert INV-15 for `token`.
+fn check_total_held(client: &PifpProtocolClient, token: &Address) {
+    let balance = token::Client::new(&client.env, token).balance(&client.address);
+    assert_total_held_matches(balance, client.get_total_held(token));
+}
+
 // ── 1. Registration Fuzz Tests ──────────────────────────────────────
 
 proptest! {
@@ -51,6 +60,8 @@ proptest! {
             &goal,
             &proof_hash,
             &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
         );
 
         assert_all_project_invariants(&project);
@@ -78,6 +89,8 @@ proptest! {
             &100,
             &proof_hash,
             &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
         );
 
         assert_all_project_invariants(&project);
@@ -104,6 +117,8 @@ proptest! {
             &1000,
             &proof_hash,
             &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
         );
 
         assert_all_project_invariants(&project);
@@ -136,6 +151,8 @@ proptest! {
             &100_000,
             &proof_hash,
             &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
         );
 
         let donator = Address::generate(&env);
@@ -174,6 +191,8 @@ proptest! {
             &1_000_000,
             &proof_hash,
             &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
         );
 
         let sac = token::StellarAssetClient::new(&env, &token_client.address);
@@ -230,6 +249,8 @@ proptest! {
             &500,
             &proof_hash,
             &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
         );
 
         let oracle = Address::generate(&env);
@@ -262,6 +283,8 @@ proptest! {
             &500,
             &proof_hash,
             &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
         );
 
         let oracle = Address::generate(&env);
@@ -302,6 +325,8 @@ proptest! {
                 &1000,
                 &proof_hash,
                 &deadline,
+                &String::from_str(&env, "ipfs://metadata"),
+                &ProjectOptions::default(),
             );
             projects.push(p);
         }
@@ -335,6 +360,8 @@ proptest! {
             &100_000,
             &proof_hash,
             &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
         );
 
         let donator = Address::generate(&env);
@@ -368,6 +395,8 @@ proptest! {
             &500,
             &proof_hash,
             &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
         );
 
         let oracle = Address::generate(&env);
@@ -410,6 +439,8 @@ proptest! {
             &goal,
             &proof_hash,
             &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
         );
         assert_all_project_invariants(&project);
         assert_eq!(project.status, ProjectStatus::Funding);
@@ -437,6 +468,7 @@ proptest! {
 
         let final_balance = client.get_balance(&project.id, &token_client.address);
         assert_eq!(final_balance, total_deposited);
+        check_total_held(&client, &token_client.address);
 
         // Phase 3: Oracle verification.
         let oracle = Address::generate(&env);
@@ -457,9 +489,231 @@ proptest! {
         // Creator's actual token balance should equal what was deposited.
         let creator_actual_balance = token_client.balance(&creator);
         assert_eq!(creator_actual_balance, total_deposited);
+        check_total_held(&client, &token_client.address);
 
         // Phase 5: Double-verify should fail.
         let result = client.try_verify_and_release(&oracle, &project.id, &proof_hash);
         prop_assert!(result.is_err(), "double verification should fail");
     }
 }
+
+// ── 7. Overflow Fuzz Tests ──────────────────────────────────────────
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn fuzz_deposit_overflow_is_rejected(
+        first in (i128::MAX / 2)..=i128::MAX,
+        extra in 1i128..=1_000_000i128,
+        second_token in any::<bool>(),
+    ) {
+        let (env, client, admin) = setup_env();
+        let creator = Address::generate(&env);
+        client.grant_role(&admin, &creator, &Role::ProjectManager);
+
+        let token_admin = Address::generate(&env);
+        let token_a = create_token(&env, &token_admin);
+        let token_b = create_token(&env, &token_admin);
+        let proof_hash = BytesN::from_array(&env, &[9u8; 32]);
+        let deadline = env.ledger().timestamp() + 86_400;
+
+        let mut tokens = SorobanVec::new(&env);
+        tokens.push_back(token_a.address.clone());
+        tokens.push_back(token_b.address.clone());
+
+        let project = client.register_project(
+            &creator,
+            &tokens,
+            &1_000_000,
+            &proof_hash,
+            &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
+        );
+
+        let whale = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token_a.address).mint(&whale, &first);
+        client.deposit(&project.id, &whale, &token_a.address, &first);
+
+        // Pushes the running totals exactly `extra` past i128::MAX, whether
+        // it lands in the same token or in the project's second one.
+        let second = i128::MAX - first + extra;
+        let token_client = if second_token { &token_b } else { &token_a };
+        let donator = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token_client.address).mint(&donator, &second);
+
+        let result = client.try_deposit(&project.id, &donator, &token_client.address, &second);
+        prop_assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                crate::Error::Overflow as u32
+            )))
+        );
+
+        // Nothing moved and the first deposit is intact.
+        prop_assert_eq!(token_client.balance(&donator), second);
+        prop_assert_eq!(client.get_balance(&project.id, &token_a.address), first);
+        let after = client.get_project(&project.id);
+        prop_assert_eq!(after.total_deposited, first);
+        assert_all_project_invariants(&after);
+    }
+}
+
+// ── 8. Escrow Accounting Fuzz Tests ─────────────────────────────────
+
+/// Assert INV-12 for every token of `project_id`, given all donors.
+fn check_donor_balances(
+    client: &PifpProtocolClient,
+    project_id: u64,
+    tokens: &[token::Client],
+    donors: &[Address],
+) {
+    for token in tokens {
+        let balances: Vec<i128> = donors
+            .iter()
+            .map(|donor| client.get_contribution(&project_id, &token.address, donor))
+            .collect();
+        let held = client.get_balance(&project_id, &token.address);
+        assert_donor_balances_match(project_id, &balances, held);
+        check_total_held(client, &token.address);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn fuzz_deposits_and_refunds_keep_donor_balances(
+        deposits in prop::collection::vec((0usize..4, 0usize..2, 1i128..=10_000i128), 1..=12),
+        refund_order in prop::collection::vec((0usize..4, 0usize..2), 0..=8),
+    ) {
+        let (env, client, admin) = setup_env();
+        let creator = Address::generate(&env);
+        client.grant_role(&admin, &creator, &Role::ProjectManager);
+
+        let token_admin = Address::generate(&env);
+        let tokens = [create_token(&env, &token_admin), create_token(&env, &token_admin)];
+        let donors: Vec<Address> = (0..4).map(|_| Address::generate(&env)).collect();
+        let deadline = env.ledger().timestamp() + 86_400;
+
+        let mut accepted = SorobanVec::new(&env);
+        for token in &tokens {
+            accepted.push_back(token.address.clone());
+        }
+        // Out of reach of the deposits, so the project expires.
+        let project = client.register_project(
+            &creator,
+            &accepted,
+            &1_000_000_000,
+            &BytesN::from_array(&env, &[8u8; 32]),
+            &deadline,
+            &String::from_str(&env, "ipfs://metadata"),
+            &ProjectOptions::default(),
+        );
+
+        for (donor, token, amount) in &deposits {
+            let token = &tokens[*token];
+            token::StellarAssetClient::new(&env, &token.address).mint(&donors[*donor], amount);
+            client.deposit(&project.id, &donors[*donor], &token.address, amount);
+            check_donor_balances(&client, project.id, &tokens, &donors);
+        }
+
+        env.ledger().with_mut(|ledger| ledger.timestamp = deadline + 1);
+        for (donor, token) in &refund_order {
+            let token = &tokens[*token];
+            if client.get_contribution(&project.id, &token.address, &donors[*donor]) > 0 {
+                client.refund(&donors[*donor], &project.id, &token.address);
+            }
+            check_donor_balances(&client, project.id, &tokens, &donors);
+        }
+
+        for donor in &donors {
+            let _ = client.try_refund_all(donor, &project.id);
+        }
+        check_donor_balances(&client, project.id, &tokens, &donors);
+        for token in &tokens {
+            prop_assert_eq!(client.get_balance(&project.id, &token.address), 0);
+        }
+    }
+}
+
+// ── 9. Protocol Stats Fuzz Tests ────────────────────────────────────
+
+/// Assert INV-13, INV-14 and INV-15 over every project registered on `client`.
+fn check_protocol_stats(client: &PifpProtocolClient, token: &Address) {
+    let projects: Vec<_> = (0..client.get_project_count())
+        .map(|id| client.get_project(&id))
+        .collect();
+    assert_stats_match_projects(&client.get_protocol_stats(), &projects);
+    let raised: Vec<i128> = projects
+        .iter()
+        .map(|p| client.get_total_raised(&p.id, token))
+        .collect();
+    assert_volume_matches(client.get_total_volume(token), &raised);
+    check_total_held(client, token);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    /// Each op is `(kind, project, amount)`: 0 deposits, 1 verifies and
+    /// 2 cancels. Invalid ops fail and must leave the stats untouched.
+    #[test]
+    fn fuzz_protocol_stats_match_projects(
+        ops in prop::collection::vec((0u8..3, 0u64..3, 1i128..=600i128), 1..=15),
+    ) {
+        let (env, client, admin) = setup_env();
+        let creator = Address::generate(&env);
+        client.grant_role(&admin, &creator, &Role::ProjectManager);
+        let oracle = Address::generate(&env);
+        client.set_oracle(&admin, &oracle);
+        // Releases leave the fee behind in the contract.
+        client.set_fee_bps(&admin, &250);
+
+        let token_admin = Address::generate(&env);
+        let token_client = create_token(&env, &token_admin);
+        let sac = token::StellarAssetClient::new(&env, &token_client.address);
+        let proof_hash = BytesN::from_array(&env, &[5u8; 32]);
+        let deadline = env.ledger().timestamp() + 86_400;
+
+        let mut tokens = SorobanVec::new(&env);
+        tokens.push_back(token_client.address.clone());
+        for _ in 0..3 {
+            client.register_project(
+                &creator,
+                &tokens,
+                &1_000,
+                &proof_hash,
+                &deadline,
+                &String::from_str(&env, "ipfs://metadata"),
+                &ProjectOptions::default(),
+            );
+        }
+        check_protocol_stats(&client, &token_client.address);
+
+        for (kind, project_id, amount) in &ops {
+            match kind {
+                0 => {
+                    let donator = Address::generate(&env);
+                    sac.mint(&donator, amount);
+                    let _ = client.try_deposit(project_id, &donator, &token_client.address, amount);
+                }
+                1 => {
+                    let _ = client.try_verify_and_release(&oracle, project_id, &proof_hash);
+                }
+                _ => {
+                    let _ = client.try_cancel_project(&creator, project_id);
+                }
+            }
+            check_protocol_stats(&client, &token_client.address);
+        }
+
+        // Persist every remaining expiry so nothing is lapsed unpersisted.
+        env.ledger().with_mut(|ledger| ledger.timestamp = deadline + 1);
+        for id in 0..3u64 {
+            let _ = client.try_expire_project(&id);
+        }
+        check_protocol_stats(&client, &token_client.address);
+    }
+}
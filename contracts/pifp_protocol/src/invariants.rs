@@ -2,7 +2,7 @@
 
 extern crate std;
 
-use crate::types::{Project, ProjectStatus};
+use crate::types::{Project, ProjectStatus, ProtocolStats};
 
 /// INV-1: Project balance must never be negative.
 /// NOTE: With multi-token funding, individual balances are tracked in storage;
@@ -67,18 +67,27 @@ pub fn assert_sequential_ids(projects: &[Project]) {
 }
 
 /// INV-7: Status transition validity. Only forward transitions are allowed:
-///   Funding -> Active | Completed | Expired
-///   Active  -> Completed | Expired
+///   Funding -> Active | Verified | Completed | Expired | Cancelled
+///   Active  -> Verified | Completed | Expired | Cancelled
+///   Verified  -> Active | Completed | Cancelled
 ///   Completed -> (none)
 ///   Expired   -> (none)
+///   Cancelled -> (none)
 pub fn assert_valid_status_transition(from: &ProjectStatus, to: &ProjectStatus) {
     let valid = matches!(
         (from, to),
         (ProjectStatus::Funding, ProjectStatus::Active)
             | (ProjectStatus::Funding, ProjectStatus::Completed)
             | (ProjectStatus::Funding, ProjectStatus::Expired)
+            | (ProjectStatus::Funding, ProjectStatus::Cancelled)
+            | (ProjectStatus::Funding, ProjectStatus::Verified)
             | (ProjectStatus::Active, ProjectStatus::Completed)
             | (ProjectStatus::Active, ProjectStatus::Expired)
+            | (ProjectStatus::Active, ProjectStatus::Verified)
+            | (ProjectStatus::Active, ProjectStatus::Cancelled)
+            | (ProjectStatus::Verified, ProjectStatus::Active)
+            | (ProjectStatus::Verified, ProjectStatus::Completed)
+            | (ProjectStatus::Verified, ProjectStatus::Cancelled)
     );
 
     assert!(
@@ -141,6 +150,79 @@ pub fn assert_donation_count_non_negative(project: &Project) {
     );
 }
 
+/// INV-11: unique_donors can never exceed donation_count.
+/// Every new donator also contributes a new (donator, token) pair.
+pub fn assert_unique_donors_bounded(project: &Project) {
+    assert!(
+        project.unique_donors <= project.donation_count,
+        "INV-11 violated: unique_donors {} exceeds donation_count {}",
+        project.unique_donors,
+        project.donation_count
+    );
+}
+
+/// INV-12: Escrow accounting — while a project still holds its escrow (no
+/// release has paid any of it out), the per-donor balances of a token sum to
+/// the project's balance of that token.
+///
+/// `donor_balances` holds `get_contribution` for every address that has
+/// deposited the token, including matching-pool sponsors. Any drift means
+/// refunds would either strand funds or pay out more than the project holds.
+pub fn assert_donor_balances_match(project_id: u64, donor_balances: &[i128], token_balance: i128) {
+    let total: i128 = donor_balances.iter().sum();
+    assert_eq!(
+        total, token_balance,
+        "INV-12 violated: project {} donor balances sum to {} but it holds {}",
+        project_id, total, token_balance
+    );
+}
+
+/// INV-13: Protocol stats agree with the projects — `stats` counts every
+/// project in `projects` once, under its stored status.
+///
+/// `projects` must be every registered project, read while none has lapsed
+/// unpersisted (`get_project` would report those as `Expired`).
+pub fn assert_stats_match_projects(stats: &ProtocolStats, projects: &[Project]) {
+    let count =
+        |status: ProjectStatus| projects.iter().filter(|p| p.status == status).count() as u64;
+    let expected = ProtocolStats {
+        total_projects: projects.len() as u64,
+        total_completed: count(ProjectStatus::Completed),
+        total_expired: count(ProjectStatus::Expired),
+        total_refunds: stats.total_refunds,
+        funding_count: count(ProjectStatus::Funding),
+        active_count: count(ProjectStatus::Active),
+        verified_count: count(ProjectStatus::Verified),
+        total_cancelled: count(ProjectStatus::Cancelled),
+    };
+    assert_eq!(
+        stats, &expected,
+        "INV-13 violated: protocol stats drifted from the projects"
+    );
+}
+
+/// INV-14: A token's protocol-wide volume is the sum of every project's
+/// lifetime total raised in it.
+pub fn assert_volume_matches(total_volume: i128, totals_raised: &[i128]) {
+    let sum: i128 = totals_raised.iter().sum();
+    assert_eq!(
+        total_volume, sum,
+        "INV-14 violated: total volume {} but projects raised {}",
+        total_volume, sum
+    );
+}
+
+/// INV-15: Held tokens — the contract's actual balance of a token equals the
+/// running total it holds for escrow, pools, bonds, vesting, waived refunds
+/// and fees. Only tokens sent to the contract directly can make them differ.
+pub fn assert_total_held_matches(contract_balance: i128, total_held: i128) {
+    assert_eq!(
+        contract_balance, total_held,
+        "INV-15 violated: contract holds {} but tracks {}",
+        contract_balance, total_held
+    );
+}
+
 /// Run all stateless project invariants.
 pub fn assert_all_project_invariants(project: &Project) {
     assert_balance_non_negative(project);
@@ -148,4 +230,5 @@ pub fn assert_all_project_invariants(project: &Project) {
     assert_deadline_positive(project);
     assert_completed_has_valid_state(project);
     assert_donation_count_non_negative(project);
+    assert_unique_donors_bounded(project);
 }
@@ -7,18 +7,32 @@
 //! | Phase        | Entry Point(s)                              |
 //! |--------------|---------------------------------------------|
 //! | Bootstrap    | [`PifpProtocol::init`]                      |
-//! | Role admin   | `grant_role`, `revoke_role`, `transfer_super_admin`, `set_oracle` |
-//! | Registration | [`PifpProtocol::register_project`]          |
-//! | Funding      | [`PifpProtocol::deposit`]                   |
-//! | Donor safety | [`PifpProtocol::refund`]                    |
-//! | Verification | [`PifpProtocol::verify_and_release`]        |
-//! | Queries      | `get_project`, `get_project_balances`, `role_of`, `has_role` |
+//! | Upgrades     | `upgrade`, `version`, `migrate_project`, `get_schema_version` |
+//! | Role admin   | `grant_role`, `revoke_role`, `transfer_super_admin`, `set_oracle`, `revoke_oracle`, `set_project_oracle`, `set_requires_admin_cosign`, `register_oracle_key` |
+//! | Tokens       | `add_allowed_token`, `remove_allowed_token`, `get_allowed_tokens` |
+//! | Denylist     | `set_address_blocked`, `is_blocked`         |
+//! | Freeze       | `freeze_project`, `is_project_frozen`       |
+//! | Fees         | `set_fee_bps`, `set_registration_fee`, `get_registration_fee`, `set_creator_bond`, `get_creator_bond`, `withdraw_fees`, `get_fee_balance`, `get_total_held`, `sync_total_held`, `sweep_untracked` |
+//! | Unclaimed    | `set_refund_grace`, `get_refund_grace`, `sweep_unclaimed` |
+//! | Quorum       | `set_quorum_threshold`, `get_quorum_threshold` |
+//! | Timelock     | `set_release_delay`, `get_release_delay`, `veto_release`, `set_grace_period`, `get_grace_period` |
+//! | Registration | [`PifpProtocol::register_project`], `register_project_v2`, `set_protocol_params`, `get_protocol_params`, `set_max_accepted_tokens`, `get_max_accepted_tokens`, `set_max_active_projects`, `get_max_active_projects`, `get_active_project_count`, `extend_deadline`, `update_deadline`, `update_proof_hash`, `set_payout_address`, `lower_contribution_cap`, `add_accepted_token`, `remove_accepted_token`, `cancel_project`, `slash_project`, `expire_project`, `expire_projects`, `touch_expired` |
+//! | Funding      | [`PifpProtocol::deposit`], `deposit_with_memo`, `deposit_anonymous`, `deposit_many`, `deposit_batch` |
+//! | Pledges      | `create_pledge`, `execute_pledge`, `cancel_pledge`, `get_pledge` |
+//! | Matching     | `fund_matching_pool`, `withdraw_matching_pool`, `get_matching_pool` |
+//! | Donor safety | [`PifpProtocol::refund`], `refund_to`, `refund_all`, `force_refund`, `waive_refund` |
+//! | Payout       | `claim_release`, `claim_released_funds`, `claim_vested`, `claim_waived` |
+//! | Verification | [`PifpProtocol::verify_and_release`], `verify_and_release_partial`, `submit_verification_vote`, `verify_with_signature`, `approve_release`, `finalize_release` |
+//! | Audit        | `audit_project`                             |
+//! | Queries      | `get_project`, `get_projects`, `get_projects_by_status`, `get_project_count`, `get_project_status`, `get_project_config`, `get_project_state`, `time_until_deadline`, `get_project_balances`, `get_balances_by_tokens`, `get_protocol_stats`, `get_stats`, `get_total_volume`, `get_total_raised`, `get_funding_progress`, `rejects_overfunding`, `refunds_excess`, `get_contribution`, `get_donation`, `get_donations`, `get_receipt`, `get_receipts_for_donor`, `get_contribution_cap`, `get_min_deposit`, `get_project_bond`, `get_votes`, `get_vesting`, `role_of`, `has_role`, `list_role_holders`, `get_oracle_history` |
 //!
 //! ## Architecture
 //!
 //! Authorization is fully delegated to [`rbac`].  Storage access is fully
-//! delegated to [`storage`].  This file contains **only** the public entry
-//! points and event emissions — no business logic lives here directly.
+//! delegated to [`storage`], and event payloads to [`events`].  This file
+//! holds the public entry points together with the business logic they
+//! share — status transitions, quorum counting, fee and refund arithmetic —
+//! as private helpers on [`PifpProtocol`].
 //!
 //! See [`ARCHITECTURE.md`](../../../../ARCHITECTURE.md) for the full system
 //! architecture and threat model.
@@ -26,7 +40,8 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, panic_with_error, token, Address, BytesN, Env, Vec,
+    contract, contracterror, contractimpl, panic_with_error, token, xdr::ToXdr, Address, Bytes,
+    BytesN, Env, Map, String, Vec,
 };
 
 pub mod events;
@@ -43,23 +58,171 @@ mod rbac_test;
 #[cfg(test)]
 mod test;
 #[cfg(test)]
+mod test_add_token;
+#[cfg(test)]
+mod test_allowlist;
+#[cfg(test)]
+mod test_audit;
+#[cfg(test)]
+mod test_balances;
+#[cfg(test)]
+mod test_bond;
+#[cfg(test)]
+mod test_cancel;
+#[cfg(test)]
+mod test_contribution;
+#[cfg(test)]
+mod test_contribution_cap;
+#[cfg(test)]
+mod test_cosign;
+#[cfg(test)]
+mod test_creator_limit;
+#[cfg(test)]
+mod test_deadline;
+#[cfg(test)]
+mod test_denylist;
+#[cfg(test)]
+mod test_deposit_batch;
+#[cfg(test)]
+mod test_deposit_many;
+#[cfg(test)]
 mod test_donation_count;
 #[cfg(test)]
 mod test_events;
 #[cfg(test)]
 mod test_expire;
 #[cfg(test)]
+mod test_fee;
+#[cfg(test)]
+mod test_force_refund;
+#[cfg(test)]
+mod test_freeze;
+#[cfg(test)]
+mod test_funding_progress;
+#[cfg(test)]
+mod test_get_projects;
+#[cfg(test)]
+mod test_goal_reached;
+#[cfg(test)]
+mod test_grace_period;
+#[cfg(test)]
+mod test_matching_pool;
+#[cfg(test)]
+mod test_metadata;
+#[cfg(test)]
+mod test_migration;
+#[cfg(test)]
+mod test_min_deposit;
+#[cfg(test)]
+mod test_overfunding;
+#[cfg(test)]
+mod test_partial_release;
+#[cfg(test)]
+mod test_payout_address;
+#[cfg(test)]
+mod test_pledge;
+#[cfg(test)]
+mod test_project_oracle;
+#[cfg(test)]
+mod test_project_status;
+#[cfg(test)]
+mod test_proof_hash;
+#[cfg(test)]
+mod test_protocol_params;
+#[cfg(test)]
+mod test_quorum;
+#[cfg(test)]
+mod test_receipts;
+#[cfg(test)]
+mod test_reentrancy;
+#[cfg(test)]
 mod test_refund;
 #[cfg(test)]
+mod test_refund_all;
+#[cfg(test)]
+mod test_refund_excess;
+#[cfg(test)]
+mod test_registration_fee;
+#[cfg(test)]
+mod test_remove_token;
+#[cfg(test)]
+mod test_signature;
+#[cfg(test)]
+mod test_stats;
+#[cfg(test)]
+mod test_status;
+#[cfg(test)]
+mod test_status_index;
+#[cfg(test)]
+mod test_sweep;
+#[cfg(test)]
+mod test_sweep_unclaimed;
+#[cfg(test)]
+mod test_timelock;
+#[cfg(test)]
+mod test_token_goals;
+#[cfg(test)]
+mod test_token_validation;
+#[cfg(test)]
+mod test_total_held;
+#[cfg(test)]
+mod test_total_raised;
+#[cfg(test)]
+mod test_update_deadline;
+#[cfg(test)]
+mod test_upgrade;
+#[cfg(test)]
 mod test_utils;
+#[cfg(test)]
+mod test_vesting;
+#[cfg(test)]
+mod test_waive_refund;
 
-pub use events::emit_funds_released;
+pub use events::{emit_funds_released, OracleGranted};
 pub use rbac::Role;
 use storage::{
     drain_token_balance, get_all_balances, get_and_increment_project_id, load_project,
     load_project_pair, maybe_load_project, save_project, save_project_state,
 };
-pub use types::{Project, ProjectBalances, ProjectStatus};
+use types::ExcessRefund;
+pub use types::{
+    AuditReport, DonationReceipt, FundingProgress, MatchingPool, Pledge, Project, ProjectBalances,
+    ProjectBond, ProjectConfig, ProjectOptions, ProjectState, ProjectStatus, ProtocolParams,
+    ProtocolStats, ReleaseApproval, TokenProgress, VestingSchedule,
+};
+
+/// Upper bound for the protocol fee: 1000 bps = 10%.
+const MAX_FEE_BPS: u32 = 1_000;
+/// Basis-point denominator used for fee arithmetic.
+const BPS_DENOMINATOR: i128 = 10_000;
+/// Default furthest a deadline may lie in the future: 5 years (5 * 365 * 24 * 60 * 60).
+const DEFAULT_MAX_DEADLINE_OFFSET: u64 = 157_680_000;
+/// Longest accepted project metadata URI, in bytes.
+const MAX_METADATA_URI_LEN: u32 = 200;
+/// Maximum byte length of a deposit memo.
+const MAX_MEMO_LEN: u32 = 64;
+/// Default cap on the tokens a single project may accept.
+const DEFAULT_MAX_ACCEPTED_TOKENS: u32 = 10;
+/// Highest token cap a `SuperAdmin` may configure.
+const MAX_ACCEPTED_TOKENS_LIMIT: u32 = 20;
+/// Default cap on a project's funding goal: 10^30.
+const DEFAULT_MAX_GOAL: i128 = 1_000_000_000_000_000_000_000_000_000_000;
+/// Ledgers an oracle approval stays valid for an admin co-signature (~1 day).
+const RELEASE_APPROVAL_WINDOW: u32 = 17_280;
+/// Most donators a single `force_refund` call may process.
+const MAX_FORCE_REFUND_BATCH: u32 = 50;
+/// Most receipts a single `get_receipts_for_donor` call returns.
+const MAX_RECEIPT_PAGE: u32 = 50;
+/// Most entries a single `deposit_batch` call may carry.
+const MAX_DEPOSIT_BATCH: u32 = 10;
+/// Most projects a single `get_projects` call returns.
+const MAX_PROJECT_PAGE: u32 = 25;
+/// Domain tag opening the payload an oracle signs for `verify_with_signature`.
+const VERIFY_SIGNATURE_TAG: &[u8] = b"pifp:verify";
+/// Project ids a single `get_projects_by_status` call scans.
+const STATUS_SCAN_WINDOW: u64 = 256;
+/// Most project ids a single `expire_projects` call may carry.
+const MAX_EXPIRE_BATCH: u32 = 20;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -86,7 +249,33 @@ pub enum Error {
     ProtocolPaused = 19,
     GoalMismatch = 20,
     ProjectNotExpired = 21,
-    InvalidTransition = 22,
+    InvalidStateTransition = 22,
+    DeadlineOverflow = 23,
+    InvalidProofHash = 24,
+    QuorumNotReached = 25,
+    CosignRequired = 26,
+    ApprovalExpired = 27,
+    InvalidMetadata = 28,
+    ReleaseLocked = 29,
+    MustBePaused = 30,
+    ContributionCapExceeded = 31,
+    TokenNotAllowed = 32,
+    TokenNotAccepted = 33,
+    InvalidTokenContract = 34,
+    GoalExceeded = 35,
+    BatchTooLarge = 36,
+    GracePeriodActive = 37,
+    ReceiptNotFound = 38,
+    InvalidMemo = 39,
+    AddressBlocked = 40,
+    BelowMinimumDeposit = 41,
+    ProjectFrozen = 42,
+    PledgeNotFound = 43,
+    PledgeNotDue = 44,
+    CreatorLimitReached = 45,
+    PartialReleaseUnavailable = 46,
+    HoldingsNotSynced = 47,
+    AlreadyVoted = 48,
 }
 
 #[contract]
@@ -107,6 +296,7 @@ impl PifpProtocol {
     pub fn init(env: Env, super_admin: Address) {
         super_admin.require_auth();
         rbac::init_super_admin(&env, &super_admin);
+        storage::set_tracks_held(&env);
     }
 
     // ─────────────────────────────────────────────────────────
@@ -147,10 +337,49 @@ impl PifpProtocol {
         rbac::has_role(&env, address, role)
     }
 
+    /// Return the last `rbac::MAX_ORACLE_HISTORY` Oracle grants, oldest first.
+    ///
+    /// Lets auditors tell which oracle was authorised, and by whom, at the
+    /// ledger a project was verified.
+    pub fn get_oracle_history(env: Env) -> Vec<OracleGranted> {
+        rbac::get_oracle_history(&env)
+    }
+
+    /// Return the addresses holding `role`, in the order they were granted it.
+    ///
+    /// At most `rbac::MAX_LISTED_HOLDERS` entries are returned.
+    pub fn list_role_holders(env: Env, role: Role) -> Vec<Address> {
+        rbac::list_role_holders(&env, role)
+    }
+
     // ─────────────────────────────────────────────────────────
     // Emergency Control
     // ─────────────────────────────────────────────────────────
 
+    /// Quarantine a single project, or lift the quarantine when `frozen` is
+    /// false.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - While frozen, deposits, verification, releases and donor refunds
+    ///   on the project fail with `ProjectFrozen`; other projects are
+    ///   unaffected.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn freeze_project(env: Env, caller: Address, project_id: u64, frozen: bool) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if storage::maybe_load_project_config(&env, project_id).is_none() {
+            panic_with_error!(&env, Error::ProjectNotFound);
+        }
+        storage::set_frozen(&env, project_id, frozen);
+    }
+
+    /// Return whether `project_id` is frozen.
+    pub fn is_project_frozen(env: Env, project_id: u64) -> bool {
+        storage::is_frozen(&env, project_id)
+    }
+
     /// Pause the protocol, halting all registrations, deposits, and releases.
     ///
     /// - `caller` must hold `SuperAdmin` or `Admin`.
@@ -177,329 +406,3554 @@ impl PifpProtocol {
     }
 
     // ─────────────────────────────────────────────────────────
-    // Project lifecycle
+    // Upgrades
     // ─────────────────────────────────────────────────────────
 
-    /// Register a new funding project.
+    /// Replace the contract code with the already-uploaded `new_wasm_hash`.
     ///
-    /// `creator` must hold the `ProjectManager`, `Admin`, or `SuperAdmin` role.
-    pub fn register_project(
-        env: Env,
-        creator: Address,
-        accepted_tokens: Vec<Address>,
-        goal: i128,
-        proof_hash: BytesN<32>,
-        deadline: u64,
-    ) -> Project {
-        Self::require_not_paused(&env);
-        creator.require_auth();
-        // RBAC gate: only authorised roles may create projects.
-        rbac::require_can_register(&env, &creator);
-
-        if accepted_tokens.is_empty() {
-            panic_with_error!(&env, Error::EmptyAcceptedTokens);
-        }
-        if accepted_tokens.len() > 10 {
-            panic_with_error!(&env, Error::TooManyTokens);
+    /// - `caller` must hold `SuperAdmin`.
+    /// - The protocol must be paused first (`MustBePaused`), so no in-flight
+    ///   operation straddles the code change.
+    ///
+    /// Storage is kept as is. Bumps the stored version and emits an
+    /// `Upgraded` event.
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        if !storage::is_paused(&env) {
+            panic_with_error!(&env, Error::MustBePaused);
         }
 
-        // Check for duplicate tokens
-        for i in 0..accepted_tokens.len() {
-            let t_i = accepted_tokens.get(i).unwrap();
-            for j in (i + 1)..accepted_tokens.len() {
-                if t_i == accepted_tokens.get(j).unwrap() {
-                    panic_with_error!(&env, Error::DuplicateToken);
-                }
-            }
-        }
+        let version = storage::get_version(&env) + 1;
+        storage::set_version(&env, version);
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
 
-        if goal <= 0 || goal > 1_000_000_000_000_000_000_000_000_000_000i128 {
-            // 10^30
-            panic_with_error!(&env, Error::InvalidGoal);
-        }
+        events::emit_upgraded(&env, new_wasm_hash, version);
+    }
 
-        let now = env.ledger().timestamp();
-        // Max 5 years deadline (5 * 365 * 24 * 60 * 60)
-        let max_deadline = now + 157_680_000;
-        if deadline <= now || deadline > max_deadline {
-            panic_with_error!(&env, Error::InvalidDeadline);
+    /// Return the contract version: 1 for the initial deployment, bumped by
+    /// every `upgrade`.
+    pub fn version(env: Env) -> u32 {
+        storage::get_version(&env)
+    }
+
+    /// Rewrite a project stored by older code in the current storage layout.
+    ///
+    /// - `caller` must hold `SuperAdmin`.
+    /// - Unknown projects fail with `ProjectNotFound`.
+    ///
+    /// Projects already in the current layout are left untouched, so the call
+    /// is safe to repeat. New fields take their registration defaults; see
+    /// `storage::migrate_project`.
+    pub fn migrate_project(env: Env, caller: Address, project_id: u64) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        if !storage::project_exists(&env, project_id) {
+            panic_with_error!(&env, Error::ProjectNotFound);
         }
+        storage::migrate_project(&env, project_id);
+    }
 
-        let id = get_and_increment_project_id(&env);
-        let project = Project {
-            id,
-            creator: creator.clone(),
-            accepted_tokens: accepted_tokens.clone(),
-            goal,
-            proof_hash,
-            deadline,
-            status: ProjectStatus::Funding,
-            donation_count: 0,
-        };
+    /// Return the storage layout version a project was written with.
+    /// Projects that predate versioning report 1.
+    pub fn get_schema_version(env: Env, project_id: u64) -> u32 {
+        storage::get_schema_version(&env, project_id)
+    }
 
-        save_project(&env, &project);
+    // ─────────────────────────────────────────────────────────
+    // Protocol fees
+    // ─────────────────────────────────────────────────────────
 
-        // Standardized event emission
-        if let Some(token) = accepted_tokens.get(0) {
-            events::emit_project_created(&env, id, creator, token, goal);
+    /// Set the protocol fee taken from each token on release, in basis points.
+    ///
+    /// - `caller` must hold `SuperAdmin`.
+    /// - `bps` must not exceed 1000 (10%); panics with `Error::InvalidAmount` otherwise.
+    ///
+    /// Fees accumulate in the contract's fee treasury, withdrawable by a
+    /// `Treasurer` through `withdraw_fees`.
+    pub fn set_fee_bps(env: Env, caller: Address, bps: u32) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        if bps > MAX_FEE_BPS {
+            panic_with_error!(&env, Error::InvalidAmount);
         }
+        storage::set_fee_bps(&env, bps);
+    }
 
-        project
+    /// Return the protocol fee in basis points.
+    pub fn get_fee_bps(env: Env) -> u32 {
+        storage::get_fee_bps(&env)
     }
 
-    pub fn get_project(env: Env, id: u64) -> Project {
-        load_project(&env, id)
+    /// Charge `amount` of `token` for every project registration.
+    ///
+    /// - `caller` must hold `SuperAdmin`.
+    /// - `amount` must not be negative (`InvalidAmount`); 0 disables the fee.
+    ///
+    /// Collected fees join the fee treasury alongside release fees.
+    pub fn set_registration_fee(env: Env, caller: Address, token: Address, amount: i128) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        if amount < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        let fee = if amount == 0 {
+            None
+        } else {
+            Some((token, amount))
+        };
+        storage::set_registration_fee(&env, fee);
     }
 
-    /// Return the balance of `token` for `project_id`.
-    pub fn get_balance(env: Env, project_id: u64, token: Address) -> i128 {
-        storage::get_token_balance(&env, project_id, &token)
+    /// Return the `(token, amount)` charged per registration, or `None`
+    /// when registration is free.
+    pub fn get_registration_fee(env: Env) -> Option<(Address, i128)> {
+        storage::get_registration_fee(&env)
     }
 
-    /// Return the current per-token balances for a project.
+    /// Replace the registration limits.
     ///
-    /// Reconstructs the balance snapshot from persistent storage for every
-    /// token that was accepted at registration time.
+    /// - `caller` must hold `SuperAdmin`.
+    /// - Every limit must be positive: a `max_accepted_tokens` outside
+    ///   1–20 fails with `TooManyTokens`, a non-positive `max_goal` with
+    ///   `InvalidGoal` and a zero `max_project_duration_secs` with
+    ///   `InvalidDeadline`.
     ///
-    /// # Errors
-    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
-    pub fn get_project_balances(env: Env, project_id: u64) -> ProjectBalances {
-        let project = match maybe_load_project(&env, project_id) {
-            Some(p) => p,
-            None => panic_with_error!(&env, Error::ProjectNotFound),
-        };
-        get_all_balances(&env, &project)
+    /// New limits apply to later registrations, token additions and
+    /// deadline changes; existing projects are left as they are.
+    pub fn set_protocol_params(env: Env, caller: Address, params: ProtocolParams) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        Self::require_token_cap_in_range(&env, params.max_accepted_tokens);
+        if params.max_goal <= 0 {
+            panic_with_error!(&env, Error::InvalidGoal);
+        }
+        if params.max_project_duration_secs == 0 {
+            panic_with_error!(&env, Error::InvalidDeadline);
+        }
+        storage::set_protocol_params(&env, &params);
     }
 
-    /// Deposit funds into a project.
-    ///
-    /// The `token` must be one of the project's accepted tokens.
-    pub fn deposit(env: Env, project_id: u64, donator: Address, token: Address, amount: i128) {
-        Self::require_not_paused(&env);
-        donator.require_auth();
+    /// Return the registration limits currently in force.
+    pub fn get_protocol_params(env: Env) -> ProtocolParams {
+        Self::protocol_params(&env)
+    }
 
-        if amount <= 0 {
-            panic_with_error!(&env, Error::InvalidAmount);
-        }
+    /// Set how many tokens a single project may accept, leaving the other
+    /// registration limits as they are.
+    ///
+    /// - `caller` must hold `SuperAdmin`.
+    /// - `max` must be 1–20 (`TooManyTokens`).
+    pub fn set_max_accepted_tokens(env: Env, caller: Address, max: u32) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        Self::require_token_cap_in_range(&env, max);
+        let mut params = Self::protocol_params(&env);
+        params.max_accepted_tokens = max;
+        storage::set_protocol_params(&env, &params);
+    }
 
-        // Read both config and state with a single helper that bumps TTLs
-        // atomically. This is the optimized retrieval pattern; it also returns
-        // the state needed for the subsequent checks.
-        let (config, mut state) = load_project_pair(&env, project_id);
+    /// Return how many tokens a single project may accept; 10 until a
+    /// `SuperAdmin` changes it.
+    pub fn get_max_accepted_tokens(env: Env) -> u32 {
+        Self::protocol_params(&env).max_accepted_tokens
+    }
 
-        // Check expiration
-        if env.ledger().timestamp() >= config.deadline {
-            if matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
-                state.status = ProjectStatus::Expired;
-                save_project_state(&env, project_id, &state);
-            }
-            panic_with_error!(&env, Error::ProjectExpired);
+    /// Add `token` to the protocol allowlist of accepted tokens.
+    ///
+    /// - `admin` must hold `SuperAdmin` or `Admin`.
+    /// - Adding a token that is already listed is a no-op.
+    ///
+    /// While the allowlist is non-empty, `register_project` rejects any
+    /// accepted token not on it with `TokenNotAllowed`.
+    pub fn add_allowed_token(env: Env, admin: Address, token: Address) {
+        admin.require_auth();
+        rbac::require_admin_or_above(&env, &admin);
+        let mut tokens = storage::get_allowed_tokens(&env);
+        if !tokens.contains(&token) {
+            tokens.push_back(token);
+            storage::set_allowed_tokens(&env, &tokens);
         }
+    }
 
-        // Basic status check: must be Funding or Active.
-        match state.status {
-            ProjectStatus::Funding | ProjectStatus::Active => {}
-            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
-            _ => panic_with_error!(&env, Error::ProjectNotActive),
+    /// Remove `token` from the protocol allowlist.
+    ///
+    /// - `admin` must hold `SuperAdmin` or `Admin`.
+    /// - Removing an unlisted token is a no-op.
+    ///
+    /// Projects already registered with `token` keep accepting it. Removing
+    /// the last token makes registration permissionless again.
+    pub fn remove_allowed_token(env: Env, admin: Address, token: Address) {
+        admin.require_auth();
+        rbac::require_admin_or_above(&env, &admin);
+        let mut tokens = storage::get_allowed_tokens(&env);
+        if let Some(index) = tokens.first_index_of(&token) {
+            tokens.remove(index);
+            storage::set_allowed_tokens(&env, &tokens);
         }
+    }
 
-        // Verify token is accepted.
-        let mut found = false;
-        for t in config.accepted_tokens.iter() {
-            if t == token {
-                found = true;
-                break;
-            }
-        }
-        if !found {
-            panic_with_error!(&env, Error::NotAuthorized);
-        }
+    /// Return the protocol token allowlist; empty means any token is allowed.
+    pub fn get_allowed_tokens(env: Env) -> Vec<Address> {
+        storage::get_allowed_tokens(&env)
+    }
 
-        // Check if this is a new unique (donator, token) pair.
-        let is_new_donor = !storage::has_donator_seen(&env, project_id, &donator, &token);
-        if is_new_donor {
-            // Increment donation count and mark as seen.
-            state.donation_count += 1;
-            storage::mark_donator_seen(&env, project_id, &donator, &token);
-            // Save the updated state.
-            save_project_state(&env, project_id, &state);
+    /// Bar `address` from depositing and registering projects, or lift the
+    /// bar when `blocked` is false.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    ///
+    /// Blocked donors can still `refund` what they already contributed, so
+    /// blocking never freezes donor funds. A project whose creator or payout
+    /// address is blocked cannot be paid out: its escrow stays put until the
+    /// bar is lifted or the project expires and donors refund.
+    ///
+    /// Emits an `AddressBlocked` or `AddressUnblocked` event for audit.
+    pub fn set_address_blocked(env: Env, caller: Address, address: Address, blocked: bool) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_blocked(&env, &address, blocked);
+        if blocked {
+            events::emit_address_blocked(&env, caller, address);
+        } else {
+            events::emit_address_unblocked(&env, caller, address);
         }
+    }
 
-        // Transfer tokens from donator to contract.
-        let token_client = token::Client::new(&env, &token);
-        token_client.transfer(&donator, &env.current_contract_address(), &amount);
-
-        // Update the per-token balance.
-        let new_balance = storage::add_to_token_balance(&env, project_id, &token, amount);
+    /// Return whether `address` is on the denylist.
+    pub fn is_blocked(env: Env, address: Address) -> bool {
+        storage::is_blocked(&env, &address)
+    }
 
-        // If this is the primary token and goal is reached, transition from Funding to Active.
-        if state.status == ProjectStatus::Funding {
-            if let Some(first_token) = config.accepted_tokens.get(0) {
-                if token == first_token && new_balance >= config.goal {
-                    state.status = ProjectStatus::Active;
-                    save_project_state(&env, project_id, &state);
-                    events::emit_project_active(&env, project_id);
-                }
-            }
+    /// Require every creator to bond `amount` of `token` at registration.
+    ///
+    /// - `caller` must hold `SuperAdmin`.
+    /// - `amount` must not be negative (`InvalidAmount`); 0 disables bonds.
+    ///
+    /// Only projects registered after the change post a bond. The bond is
+    /// returned on release and moved to the fee treasury by `slash_project`.
+    pub fn set_creator_bond(env: Env, caller: Address, token: Address, amount: i128) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        if amount < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
         }
+        let bond = if amount == 0 {
+            None
+        } else {
+            Some((token, amount))
+        };
+        storage::set_creator_bond(&env, bond);
+    }
 
-        // Track per-donator refundable amount for this token.
-        storage::add_to_donator_balance(&env, project_id, &token, &donator, amount);
-
-        // Standardized event emission
-        events::emit_project_funded(&env, project_id, donator, amount);
+    /// Return the `(token, amount)` bond required per registration, or `None`
+    /// when no bond is required.
+    pub fn get_creator_bond(env: Env) -> Option<(Address, i128)> {
+        storage::get_creator_bond(&env)
     }
 
-    /// Refund a donator from an expired project that was not verified.
-    pub fn refund(env: Env, donator: Address, project_id: u64, token: Address) {
-        donator.require_auth();
+    /// Cap how many open projects a single creator may have at once.
+    ///
+    /// - `caller` must hold `SuperAdmin`.
+    /// - 0 (the default) means unlimited.
+    ///
+    /// A project stops counting once it is `Completed`, `Expired` or
+    /// `Cancelled`. Lapsed projects count until their expiry is persisted,
+    /// e.g. by `touch_expired`.
+    pub fn set_max_active_projects(env: Env, caller: Address, max: u32) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        storage::set_max_active_projects(&env, max);
+    }
 
-        let (config, mut state) = load_project_pair(&env, project_id);
+    /// Return the per-creator cap on open projects; 0 means unlimited.
+    pub fn get_max_active_projects(env: Env) -> u32 {
+        storage::get_max_active_projects(&env)
+    }
 
-        if env.ledger().timestamp() >= config.deadline
-            && matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
-        {
-            state.status = ProjectStatus::Expired;
-            save_project_state(&env, project_id, &state);
-        }
+    /// Return how many of `creator`'s projects are still open.
+    pub fn get_active_project_count(env: Env, creator: Address) -> u32 {
+        storage::get_active_project_count(&env, &creator)
+    }
 
-        if state.status != ProjectStatus::Expired {
-            panic_with_error!(&env, Error::ProjectNotExpired);
+    /// Transfer `amount` of accumulated `token` fees to `to`.
+    ///
+    /// - `treasurer` must hold `Treasurer` and must authorize.
+    /// - `amount` must be positive (`InvalidAmount`) and no larger than the
+    ///   fee balance (`InsufficientBalance`). Project escrow is never touched.
+    ///
+    /// Emits a `FeesWithdrawn` event.
+    pub fn withdraw_fees(env: Env, treasurer: Address, token: Address, amount: i128, to: Address) {
+        treasurer.require_auth();
+        rbac::require_role(&env, &treasurer, &Role::Treasurer);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
         }
 
-        let refund_amount = storage::get_donator_balance(&env, project_id, &token, &donator);
-        if refund_amount <= 0 {
+        let balance = storage::get_fee_balance(&env, &token);
+        if amount > balance {
             panic_with_error!(&env, Error::InsufficientBalance);
         }
+        storage::set_fee_balance(&env, &token, balance - amount);
 
-        // Zero-out first to prevent double-refund/reentrancy patterns.
-        storage::set_donator_balance(&env, project_id, &token, &donator, 0);
-        storage::add_to_token_balance(&env, project_id, &token, -refund_amount);
+        Self::send_tokens(&env, &token, &to, amount);
 
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &token);
-        token_client.transfer(&contract_address, &donator, &refund_amount);
+        events::emit_fees_withdrawn(&env, token, amount, to);
+    }
 
-        events::emit_refunded(&env, project_id, donator, refund_amount);
+    /// Return the protocol fees currently held for `token`.
+    pub fn get_fee_balance(env: Env, token: Address) -> i128 {
+        storage::get_fee_balance(&env, &token)
     }
 
-    /// Grant the Oracle role to `oracle`.
+    /// Return how much `token` the contract holds on others' behalf: project
+    /// escrow, matching pools, bonds, unclaimed vesting and waived refunds,
+    /// and protocol fees.
     ///
-    /// Replaces the original `set_oracle(admin, oracle)`.
-    /// - `caller` must hold `SuperAdmin` or `Admin`.
-    pub fn set_oracle(env: Env, caller: Address, oracle: Address) {
-        caller.require_auth();
-        rbac::require_admin_or_above(&env, &caller);
-        rbac::grant_role(&env, &caller, &oracle, Role::Oracle);
+    /// Kept as a running total updated on every transfer in and out, so it
+    /// equals the contract's token balance unless tokens were sent to it
+    /// directly (see `sweep_untracked`).
+    pub fn get_total_held(env: Env, token: Address) -> i128 {
+        storage::get_total_held(&env, &token)
     }
 
-    /// Verify proof of impact and release funds to the creator.
+    /// Seed the running total of `token` held after an upgrade, and return it.
     ///
-    /// The registered oracle submits a proof hash. If it matches the project's
-    /// stored `proof_hash`, the project status transitions to `Completed`.
+    /// - `caller` must hold `SuperAdmin`.
     ///
-    /// NOTE: This is a mocked verification (hash equality).
+    /// Part of migrating a contract deployed before the total was kept: run
+    /// once per token the contract holds. The total starts at the contract's
+    /// current `token` balance, a single read whatever the project count, so
+    /// tokens sent to the contract by mistake before the sync count as held;
+    /// only later strays can be swept. Until a token is synced its payouts
+    /// keep working and the total simply stops at zero.
+    ///
+    /// Tokens whose total is already exact, including every token of a
+    /// contract deployed with this version, are left untouched.
+    pub fn sync_total_held(env: Env, caller: Address, token: Address) -> i128 {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        if storage::is_held_exact(&env, &token) {
+            return storage::get_total_held(&env, &token);
+        }
+
+        let total = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        storage::set_total_held(&env, &token, total);
+        storage::mark_held_synced(&env, &token);
+        total
+    }
+
+    /// Transfer `token` the contract holds beyond what it tracks to `recipient`.
+    ///
+    /// A safety valve for tokens sent straight to the contract address. Only
+    /// the surplus over the running total from `get_total_held` (project
+    /// escrow, matching pools, bonds, unclaimed vesting, waived refunds and
+    /// protocol fees) can leave, so the cost does not grow with the project
+    /// count.
+    ///
+    /// - `caller` must hold `SuperAdmin`.
+    /// - On an upgraded contract, `token` must have been synced first
+    ///   (`HoldingsNotSynced`); see `sync_total_held`.
+    /// - Panics with `InsufficientBalance` if there is no surplus.
+    ///
+    /// Emits an `UntrackedSwept` event and returns the amount swept.
+    pub fn sweep_untracked(env: Env, caller: Address, token: Address, recipient: Address) -> i128 {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+
+        if !storage::is_held_exact(&env, &token) {
+            panic_with_error!(&env, Error::HoldingsNotSynced);
+        }
+
+        let token_client = token::Client::new(&env, &token);
+        let held = token_client.balance(&env.current_contract_address());
+        let surplus = held - storage::get_total_held(&env, &token);
+        if surplus <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+
+        token_client.transfer(&env.current_contract_address(), &recipient, &surplus);
+
+        events::emit_untracked_swept(&env, token, surplus, recipient);
+        surplus
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Oracle quorum
+    // ─────────────────────────────────────────────────────────
+
+    /// Set how many matching oracle votes are needed to release a project.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - `threshold` must be at least 1; panics with `Error::InvalidAmount` otherwise.
+    ///
+    /// A threshold of 1 (the default) keeps single-oracle `verify_and_release`.
+    /// Votes already cast are re-counted against the new threshold on the
+    /// next vote for the project. Projects with an assigned oracle ignore the
+    /// threshold: their oracle alone releases them.
+    pub fn set_quorum_threshold(env: Env, caller: Address, threshold: u32) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        if threshold == 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        storage::set_quorum_threshold(&env, threshold);
+    }
+
+    /// Return the number of matching oracle votes required to release.
+    pub fn get_quorum_threshold(env: Env) -> u32 {
+        storage::get_quorum_threshold(&env)
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Release timelock
+    // ─────────────────────────────────────────────────────────
+
+    /// Set the protocol-wide delay between verification and payout, in seconds.
+    ///
+    /// - `caller` must hold `SuperAdmin`.
+    /// - 0 (the default) disables the protocol-wide delay.
+    ///
+    /// Projects use the longer of this and their own `release_delay`. Only
+    /// verifications after the change are affected.
+    pub fn set_release_delay(env: Env, caller: Address, delay_secs: u64) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        storage::set_release_delay(&env, delay_secs);
+    }
+
+    /// Return the protocol-wide release delay, in seconds.
+    pub fn get_release_delay(env: Env) -> u64 {
+        storage::get_release_delay(&env)
+    }
+
+    /// Set how long after its deadline a project keeps accepting deposits,
+    /// in seconds.
+    ///
+    /// - `caller` must hold `SuperAdmin`.
+    /// - 0 (the default) closes deposits at the deadline.
+    ///
+    /// Verification stays strict at the deadline. Expiry, and with it
+    /// refunds, starts once the grace period is over.
+    pub fn set_grace_period(env: Env, caller: Address, grace_secs: u64) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        storage::set_grace_period(&env, grace_secs);
+    }
+
+    /// Return the deposit grace period, in seconds.
+    pub fn get_grace_period(env: Env) -> u64 {
+        storage::get_grace_period(&env)
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Unclaimed refunds
+    // ─────────────────────────────────────────────────────────
+
+    /// Set how long past its deadline an expired project stays reserved for
+    /// donor refunds before `sweep_unclaimed` may drain it, in seconds.
+    ///
+    /// - `caller` must hold `SuperAdmin`.
+    /// - Defaults to 30 days; applies to every expired project, including
+    ///   ones that expired before the change.
+    pub fn set_refund_grace(env: Env, caller: Address, grace_secs: u64) {
+        caller.require_auth();
+        rbac::require_role(&env, &caller, &Role::SuperAdmin);
+        storage::set_refund_grace(&env, grace_secs);
+    }
+
+    /// Return the refund grace period, in seconds.
+    pub fn get_refund_grace(env: Env) -> u64 {
+        storage::get_refund_grace(&env)
+    }
+
+    /// Drain what donors left unclaimed of `token` on an expired project to
+    /// `destination`.
+    ///
+    /// - `admin` must hold `SuperAdmin` or `Admin`.
+    /// - The project must be `Expired` (a lapsed deadline counts) and more
+    ///   than the refund grace period past the end of its deposit grace
+    ///   period, else `GracePeriodActive`.
+    /// - Sweeping to the contract's own address credits the fee treasury.
+    ///
+    /// Later refunds of `token` fail with `InsufficientBalance`. Emits an
+    /// `UnclaimedSwept` event and returns the amount swept.
+    pub fn sweep_unclaimed(
+        env: Env,
+        admin: Address,
+        project_id: u64,
+        token: Address,
+        destination: Address,
+    ) -> i128 {
+        admin.require_auth();
+        rbac::require_admin_or_above(&env, &admin);
+
+        let (config, state) = Self::load_refundable_pair(&env, project_id);
+        if state.status != ProjectStatus::Expired {
+            panic_with_error!(&env, Error::ProjectNotExpired);
+        }
+        let grace_ends = Self::funding_ends(&env, config.deadline)
+            .saturating_add(storage::get_refund_grace(&env));
+        if env.ledger().timestamp() <= grace_ends {
+            panic_with_error!(&env, Error::GracePeriodActive);
+        }
+
+        let amount = drain_token_balance(&env, project_id, &token);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+        let contract_address = env.current_contract_address();
+        if destination == contract_address {
+            storage::add_to_fee_balance(&env, &token, amount);
+        } else {
+            Self::send_tokens(&env, &token, &destination, amount);
+        }
+
+        events::emit_unclaimed_swept(&env, project_id, token, amount, destination);
+        amount
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Project lifecycle
+    // ─────────────────────────────────────────────────────────
+
+    /// Register a new funding project.
+    ///
+    /// `creator` must hold the `ProjectManager`, `Admin`, or `SuperAdmin` role.
+    /// `metadata_uri` points at the human-readable project description (e.g.
+    /// an IPFS CID) and must be 1–200 bytes (`InvalidMetadata`).
+    /// When the protocol token allowlist is non-empty, every accepted token
+    /// must be on it (`TokenNotAllowed`). Each token is probed with a
+    /// `decimals()` call and must answer it (`InvalidTokenContract`), which
+    /// adds one cross-contract call per accepted token.
+    /// Token count, goal and deadline are bounded by the current
+    /// [`ProtocolParams`] (`TooManyTokens`, `InvalidGoal`, `InvalidDeadline`).
+    /// A creator already at the per-creator cap on open projects cannot
+    /// register another (`CreatorLimitReached`).
+    /// Per-project settings are passed through `options`; use
+    /// `ProjectOptions::default()` for the protocol-wide behaviour.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_project(
+        env: Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        goal: i128,
+        proof_hash: BytesN<32>,
+        deadline: u64,
+        metadata_uri: String,
+        options: ProjectOptions,
+    ) -> Project {
+        let token_goals = Vec::new(&env);
+        Self::register(
+            env,
+            creator,
+            accepted_tokens,
+            goal,
+            token_goals,
+            proof_hash,
+            deadline,
+            metadata_uri,
+            options,
+        )
+    }
+
+    /// Register a project with a separate funding goal per accepted token.
+    ///
+    /// `token_goals` lists `(token, goal)` pairs in the order the tokens are
+    /// accepted; every goal is bounded like the single goal of
+    /// [`PifpProtocol::register_project`] (`InvalidGoal`), and the token
+    /// checks are the same. The project goes `Active` once any goal is met,
+    /// or once all are met when `options.require_all_goals` is set.
+    ///
+    /// The legacy `goal` field mirrors the first token's goal.
+    pub fn register_project_v2(
+        env: Env,
+        creator: Address,
+        token_goals: Vec<(Address, i128)>,
+        proof_hash: BytesN<32>,
+        deadline: u64,
+        metadata_uri: String,
+        options: ProjectOptions,
+    ) -> Project {
+        let mut accepted_tokens = Vec::new(&env);
+        for (token, _) in token_goals.iter() {
+            accepted_tokens.push_back(token);
+        }
+        let goal = match token_goals.get(0) {
+            Some((_, goal)) => goal,
+            None => panic_with_error!(&env, Error::EmptyAcceptedTokens),
+        };
+        Self::register(
+            env,
+            creator,
+            accepted_tokens,
+            goal,
+            token_goals,
+            proof_hash,
+            deadline,
+            metadata_uri,
+            options,
+        )
+    }
+
+    /// Shared body of `register_project` and `register_project_v2`.
+    /// `token_goals` is empty for single-goal projects.
+    #[allow(clippy::too_many_arguments)]
+    fn register(
+        env: Env,
+        creator: Address,
+        accepted_tokens: Vec<Address>,
+        goal: i128,
+        token_goals: Vec<(Address, i128)>,
+        proof_hash: BytesN<32>,
+        deadline: u64,
+        metadata_uri: String,
+        options: ProjectOptions,
+    ) -> Project {
+        Self::require_not_paused(&env);
+        creator.require_auth();
+        // RBAC gate: only authorised roles may create projects.
+        rbac::require_can_register(&env, &creator);
+        Self::require_not_blocked(&env, &creator);
+
+        let max_active = storage::get_max_active_projects(&env);
+        let active = storage::get_active_project_count(&env, &creator);
+        if max_active > 0 && active >= max_active {
+            panic_with_error!(&env, Error::CreatorLimitReached);
+        }
+
+        if accepted_tokens.is_empty() {
+            panic_with_error!(&env, Error::EmptyAcceptedTokens);
+        }
+        let params = Self::protocol_params(&env);
+        if accepted_tokens.len() > params.max_accepted_tokens {
+            panic_with_error!(&env, Error::TooManyTokens);
+        }
+
+        let allowed_tokens = storage::get_allowed_tokens(&env);
+        for token in accepted_tokens.iter() {
+            Self::require_allowed_token(&env, &allowed_tokens, &token);
+        }
+
+        // Check for duplicate tokens
+        for i in 0..accepted_tokens.len() {
+            let t_i = accepted_tokens.get(i).unwrap();
+            for j in (i + 1)..accepted_tokens.len() {
+                if t_i == accepted_tokens.get(j).unwrap() {
+                    panic_with_error!(&env, Error::DuplicateToken);
+                }
+            }
+        }
+
+        if goal <= 0 || goal > params.max_goal {
+            panic_with_error!(&env, Error::InvalidGoal);
+        }
+        for (_, token_goal) in token_goals.iter() {
+            if token_goal <= 0 || token_goal > params.max_goal {
+                panic_with_error!(&env, Error::InvalidGoal);
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let max_deadline = Self::max_deadline(&env, now);
+        if deadline <= now || deadline > max_deadline {
+            panic_with_error!(&env, Error::InvalidDeadline);
+        }
+
+        if metadata_uri.is_empty() || metadata_uri.len() > MAX_METADATA_URI_LEN {
+            panic_with_error!(&env, Error::InvalidMetadata);
+        }
+
+        if options.max_contribution < 0 || options.min_deposit < 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        // Probe the tokens last: it is the only validation that costs a
+        // cross-contract call.
+        for token in accepted_tokens.iter() {
+            Self::require_token_contract(&env, &token);
+        }
+
+        let id = get_and_increment_project_id(&env);
+
+        // Charge the registration fee before anything is persisted; a failed
+        // transfer aborts the whole registration.
+        let registration_fee = storage::get_registration_fee(&env);
+        if let Some((fee_token, fee_amount)) = registration_fee.clone() {
+            token::Client::new(&env, &fee_token).transfer(
+                &creator,
+                &env.current_contract_address(),
+                &fee_amount,
+            );
+            storage::add_to_total_held(&env, &fee_token, fee_amount);
+            storage::add_to_fee_balance(&env, &fee_token, fee_amount);
+        }
+        if let Some((bond_token, bond_amount)) = storage::get_creator_bond(&env) {
+            token::Client::new(&env, &bond_token).transfer(
+                &creator,
+                &env.current_contract_address(),
+                &bond_amount,
+            );
+            storage::add_to_total_held(&env, &bond_token, bond_amount);
+            storage::set_project_bond(
+                &env,
+                id,
+                &ProjectBond {
+                    token: bond_token,
+                    amount: bond_amount,
+                },
+            );
+        }
+
+        if options.reject_overfunding {
+            storage::set_rejects_overfunding(&env, id);
+        }
+        if options.refund_excess {
+            storage::set_refunds_excess(&env, id);
+        }
+        if options.min_deposit > 0 {
+            storage::set_min_deposit(&env, id, options.min_deposit);
+        }
+
+        let project = Project {
+            id,
+            creator: creator.clone(),
+            accepted_tokens: accepted_tokens.clone(),
+            goal,
+            proof_hash,
+            deadline,
+            metadata_uri,
+            assigned_oracle: options.assigned_oracle,
+            requires_admin_cosign: options.requires_admin_cosign,
+            vesting_duration_secs: options.vesting_duration_secs,
+            release_delay: options.release_delay,
+            payout_address: options.payout_address,
+            max_contribution: options.max_contribution,
+            token_goals,
+            require_all_goals: options.require_all_goals,
+            status: ProjectStatus::Funding,
+            donation_count: 0,
+            unique_donors: 0,
+            total_deposited: 0,
+        };
+
+        save_project(&env, &project);
+        storage::set_active_project_count(&env, &creator, active + 1);
+
+        // Standardized event emission
+        if let Some(token) = accepted_tokens.get(0) {
+            events::emit_project_created(&env, id, creator.clone(), token, goal);
+        }
+        if let Some((fee_token, fee_amount)) = registration_fee {
+            events::emit_registration_fee_paid(&env, id, creator, fee_token, fee_amount);
+        }
+
+        project
+    }
+
+    /// Push a project's deadline further into the future.
+    ///
+    /// - `caller` must be the project creator and must authorize.
+    /// - `admin` must hold `SuperAdmin` or `Admin` and must co-authorize.
+    /// - The project must be `Funding` or `Active` and its deadline must not
+    ///   have passed yet.
+    /// - `new_deadline` must be strictly greater than the current deadline and
+    ///   within `max_project_duration_secs` of now.
+    ///
+    /// Emits a `DeadlineExtended` event carrying the old and new deadlines.
+    pub fn extend_deadline(
+        env: Env,
+        caller: Address,
+        admin: Address,
+        project_id: u64,
+        new_deadline: u64,
+    ) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        admin.require_auth();
+        rbac::require_admin_or_above(&env, &admin);
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        let now = env.ledger().timestamp();
+        if now >= config.deadline {
+            panic_with_error!(&env, Error::ProjectExpired);
+        }
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+        if new_deadline <= config.deadline || new_deadline > Self::max_deadline(&env, now) {
+            panic_with_error!(&env, Error::InvalidDeadline);
+        }
+
+        let old_deadline = config.deadline;
+        config.deadline = new_deadline;
+        storage::save_project_config(&env, &config);
+
+        events::emit_deadline_extended(&env, project_id, old_deadline, new_deadline);
+    }
+
+    /// Tighten the most a single donor may contribute to a project in each
+    /// token.
+    ///
+    /// - `admin` must hold `SuperAdmin` or `Admin` and must authorize.
+    /// - The project must be `Funding` or `Active`.
+    /// - `new_cap` must be positive and, when a cap is already set, no higher
+    ///   than it; the cap can only come down, never be lifted. Otherwise
+    ///   `InvalidAmount`.
+    ///
+    /// Donors already above the new cap keep what they gave but cannot add
+    /// more of that token.
+    pub fn lower_contribution_cap(env: Env, admin: Address, project_id: u64, new_cap: i128) {
+        Self::require_not_paused(&env);
+        admin.require_auth();
+        rbac::require_admin_or_above(&env, &admin);
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+        if new_cap <= 0 || (config.max_contribution > 0 && new_cap > config.max_contribution) {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        config.max_contribution = new_cap;
+        storage::save_project_config(&env, &config);
+    }
+
+    /// Correct a misconfigured deadline before any money has landed.
+    ///
+    /// - `caller` must be the project creator or hold `SuperAdmin`/`Admin`.
+    /// - The project must still be `Funding` with no deposits recorded;
+    ///   otherwise `InvalidStateTransition`.
+    /// - `new_deadline` is checked against the same bounds as registration:
+    ///   strictly in the future and within `max_project_duration_secs` of now.
+    ///
+    /// Unlike [`PifpProtocol::extend_deadline`], the new deadline may be
+    /// earlier than the current one. Emits a `DeadlineExtended` event.
+    pub fn update_deadline(env: Env, caller: Address, project_id: u64, new_deadline: u64) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+
+        Self::require_untouched(&env, &state);
+
+        let now = env.ledger().timestamp();
+        let max_deadline = Self::max_deadline(&env, now);
+        if new_deadline <= now || new_deadline > max_deadline {
+            panic_with_error!(&env, Error::InvalidDeadline);
+        }
+
+        let old_deadline = config.deadline;
+        config.deadline = new_deadline;
+        storage::save_project_config(&env, &config);
+
+        events::emit_deadline_extended(&env, project_id, old_deadline, new_deadline);
+    }
+
+    /// Replace the proof artifact hash of a project nobody has funded yet.
+    ///
+    /// - `creator` must be the project creator and must authorize.
+    /// - The project must still be `Funding` with no deposits recorded;
+    ///   otherwise `InvalidStateTransition`, so donors always fund against
+    ///   the hash they saw.
+    /// - `new_hash` must not be all zeros (`InvalidProofHash`).
+    ///
+    /// Emits a `ProofHashUpdated` event carrying the old and new hashes.
+    pub fn update_proof_hash(env: Env, creator: Address, project_id: u64, new_hash: BytesN<32>) {
+        Self::require_not_paused(&env);
+        creator.require_auth();
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        Self::require_untouched(&env, &state);
+
+        if new_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic_with_error!(&env, Error::InvalidProofHash);
+        }
+
+        let old_hash = config.proof_hash.clone();
+        config.proof_hash = new_hash.clone();
+        storage::save_project_config(&env, &config);
+
+        events::emit_proof_hash_updated(&env, project_id, old_hash, new_hash);
+    }
+
+    /// Redirect a project's released funds to `new_address`.
+    ///
+    /// - `creator` must be the project creator and must authorize.
+    /// - The project must not be in a terminal state (`Completed`, `Expired`,
+    ///   `Cancelled`); otherwise `InvalidStateTransition`.
+    ///
+    /// Emits a `PayoutAddressChanged` event with the previous and new
+    /// recipient (the creator when none was set).
+    pub fn set_payout_address(env: Env, creator: Address, project_id: u64, new_address: Address) {
+        Self::require_not_paused(&env);
+        creator.require_auth();
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if !matches!(
+            state.status,
+            ProjectStatus::Funding | ProjectStatus::Active | ProjectStatus::Verified
+        ) {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+
+        let old_address = config.payout_recipient();
+        config.payout_address = Some(new_address.clone());
+        storage::save_project_config(&env, &config);
+
+        events::emit_payout_address_changed(&env, project_id, old_address, new_address);
+    }
+
+    /// Start accepting `token` on an existing project.
+    ///
+    /// - `caller` must be the project creator and must authorize.
+    /// - `admin` must hold `SuperAdmin` or `Admin` and must co-authorize.
+    /// - The project must be `Funding` or `Active`; otherwise
+    ///   `InvalidStateTransition`.
+    /// - `token` must not already be accepted (`DuplicateToken`), must pass
+    ///   the protocol allowlist (`TokenNotAllowed`), must answer `decimals()`
+    ///   (`InvalidTokenContract`), and the project may
+    ///   accept at most `max_accepted_tokens` (`TooManyTokens`).
+    ///
+    /// The new token's balance starts at zero. Emits `AcceptedTokenAdded`.
+    pub fn add_accepted_token(
+        env: Env,
+        caller: Address,
+        admin: Address,
+        project_id: u64,
+        token: Address,
+    ) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+        admin.require_auth();
+        rbac::require_admin_or_above(&env, &admin);
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+        if config.accepted_tokens.contains(&token) {
+            panic_with_error!(&env, Error::DuplicateToken);
+        }
+        if config.accepted_tokens.len() >= Self::protocol_params(&env).max_accepted_tokens {
+            panic_with_error!(&env, Error::TooManyTokens);
+        }
+        Self::require_allowed_token(&env, &storage::get_allowed_tokens(&env), &token);
+        Self::require_token_contract(&env, &token);
+
+        config.accepted_tokens.push_back(token.clone());
+        storage::save_project_config(&env, &config);
+        // Initialise the balance entry, as registration does for its tokens.
+        storage::set_token_balance(&env, project_id, &token, 0);
+
+        events::emit_accepted_token_added(&env, project_id, token);
+    }
+
+    /// Drop `token` from a project's accepted tokens.
+    ///
+    /// - `creator` must be the project creator and must authorize.
+    /// - The project must be `Funding` or `Active`; otherwise
+    ///   `InvalidStateTransition`.
+    /// - `token` must be accepted (`TokenNotAccepted`) and hold no escrowed
+    ///   balance (`InsufficientBalance`).
+    /// - The last accepted token cannot be removed (`EmptyAcceptedTokens`).
+    /// - A token with its own goal from `register_project_v2` cannot be
+    ///   removed (`GoalMismatch`).
+    ///
+    /// If the first token is removed, the next one becomes the unit of the
+    /// goal. Emits an `AcceptedTokenRemoved` event.
+    pub fn remove_accepted_token(env: Env, creator: Address, project_id: u64, token: Address) {
+        Self::require_not_paused(&env);
+        creator.require_auth();
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+        let index = match config.accepted_tokens.first_index_of(&token) {
+            Some(index) => index,
+            None => panic_with_error!(&env, Error::TokenNotAccepted),
+        };
+        if storage::get_token_balance(&env, project_id, &token) != 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+        if config.accepted_tokens.len() == 1 {
+            panic_with_error!(&env, Error::EmptyAcceptedTokens);
+        }
+        if config.token_goals.iter().any(|(t, _)| t == token) {
+            panic_with_error!(&env, Error::GoalMismatch);
+        }
+
+        config.accepted_tokens.remove(index);
+        storage::save_project_config(&env, &config);
+
+        events::emit_accepted_token_removed(&env, project_id, token);
+    }
+
+    /// Withdraw a project that nobody has funded yet.
+    ///
+    /// - `caller` must be the project creator or hold `SuperAdmin`/`Admin`.
+    /// - The project must still be `Funding` with no deposits recorded;
+    ///   otherwise `InvalidStateTransition` (donors refund after expiry instead).
+    ///
+    /// Moves the project to `Cancelled`, after which deposits and verification
+    /// are rejected. Emits a `ProjectCancelled` event.
+    pub fn cancel_project(env: Env, caller: Address, project_id: u64) {
+        Self::require_not_paused(&env);
+        caller.require_auth();
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        if caller != config.creator {
+            rbac::require_admin_or_above(&env, &caller);
+        }
+        Self::require_untouched(&env, &state);
+
+        Self::transition(&env, &config, &mut state, ProjectStatus::Cancelled);
+        save_project_state(&env, project_id, &state);
+
+        events::emit_project_cancelled(&env, project_id, caller);
+        Self::return_bond(&env, &config);
+    }
+
+    /// Penalise a project judged fraudulent.
+    ///
+    /// - `admin` must hold `SuperAdmin` or `Admin` and must authorize.
+    /// - `reason_hash` commits to the off-chain evidence.
+    /// - The project must not be `Completed` or already `Cancelled`;
+    ///   otherwise `InvalidStateTransition`.
+    ///
+    /// The creator's bond, if any, moves to the fee treasury. Open projects
+    /// become `Cancelled` so donors can `refund`; expired ones stay `Expired`.
+    /// Emits `ProjectCancelled` (when the status changes) and `ProjectSlashed`.
+    pub fn slash_project(env: Env, admin: Address, project_id: u64, reason_hash: BytesN<32>) {
+        Self::require_not_paused(&env);
+        admin.require_auth();
+        rbac::require_admin_or_above(&env, &admin);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        if state.status != ProjectStatus::Expired {
+            Self::transition(&env, &config, &mut state, ProjectStatus::Cancelled);
+            save_project_state(&env, project_id, &state);
+            storage::remove_release_after(&env, project_id);
+            events::emit_project_cancelled(&env, project_id, admin.clone());
+        }
+
+        let bond = storage::take_project_bond(&env, project_id);
+        if let Some(bond) = bond.clone() {
+            storage::add_to_fee_balance(&env, &bond.token, bond.amount);
+        }
+        events::emit_project_slashed(&env, project_id, admin, reason_hash, bond);
+    }
+
+    /// Return a project.
+    ///
+    /// A `Funding` or `Active` project past its deadline and the deposit
+    /// grace period is reported as `Expired` even if nobody has called
+    /// `expire_project` or `touch_expired` yet; the stored status is left
+    /// untouched.
+    pub fn get_project(env: Env, id: u64) -> Project {
+        let mut project = load_project(&env, id);
+        if Self::is_lapsed(&env, project.deadline, &project.status) {
+            project.status = ProjectStatus::Expired;
+        }
+        project
+    }
+
+    /// Return the projects with ids `start_id..start_id + limit`, with the
+    /// same lazy expiry as `get_project`.
+    ///
+    /// `limit` is capped at `MAX_PROJECT_PAGE`. Ids whose entries are
+    /// missing, e.g. archived, are skipped rather than failing the page, so
+    /// a page may hold fewer than `limit` projects; continue from
+    /// `start_id + limit`. Paging past `get_project_count` returns an empty
+    /// list.
+    pub fn get_projects(env: Env, start_id: u64, limit: u32) -> Vec<Project> {
+        let count = storage::get_project_count(&env);
+        let end = start_id
+            .saturating_add(limit.min(MAX_PROJECT_PAGE) as u64)
+            .min(count);
+        let mut projects = Vec::new(&env);
+        for id in start_id..end {
+            if let Some(mut project) = maybe_load_project(&env, id) {
+                if Self::is_lapsed(&env, project.deadline, &project.status) {
+                    project.status = ProjectStatus::Expired;
+                }
+                projects.push_back(project);
+            }
+        }
+        projects
+    }
+
+    /// Return the ids of projects stored with `status`, ascending, among
+    /// ids `start..start + 256`.
+    ///
+    /// The index keeps one 64-project bitmap per status, so a call reads at
+    /// most five entries however many projects exist. `limit` is capped at
+    /// `MAX_PROJECT_PAGE`. After a full page, continue from one past the last
+    /// id returned; a shorter page means the window is exhausted, so continue
+    /// from `start + 256` until `start` reaches `get_project_count`.
+    ///
+    /// Unlike `get_project`, this reads the stored status: a lapsed project
+    /// stays under `Funding` or `Active` until someone calls
+    /// `expire_project`, so keepers can find those past their deadline here.
+    pub fn get_projects_by_status(
+        env: Env,
+        status: ProjectStatus,
+        start: u64,
+        limit: u32,
+    ) -> Vec<u64> {
+        let end = start
+            .saturating_add(STATUS_SCAN_WINDOW)
+            .min(storage::get_project_count(&env));
+        storage::get_ids_with_status(&env, &status, start, end, limit.min(MAX_PROJECT_PAGE))
+    }
+
+    /// Return the seconds from the current ledger time until a project's
+    /// deadline; negative once it has passed.
+    ///
+    /// Deposits stay open for the protocol grace period beyond this, so a
+    /// negative value alone does not mean the project has expired.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn time_until_deadline(env: Env, project_id: u64) -> i64 {
+        let config = match storage::maybe_load_project_config(&env, project_id) {
+            Some(config) => config,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        let remaining = config.deadline as i128 - env.ledger().timestamp() as i128;
+        remaining.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    /// Return how many project ids have been issued; ids run from 0 to one
+    /// less than this.
+    pub fn get_project_count(env: Env) -> u64 {
+        storage::get_project_count(&env)
+    }
+
+    /// Return only the lifecycle status of a project, with the same lazy
+    /// expiry as `get_project`.
+    ///
+    /// Reads the small `ProjectState` entry alone, skipping the config that
+    /// `get_project` also deserializes; meant for clients polling many
+    /// projects. The deadline is only looked up for `Funding` and `Active`
+    /// projects.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_project_status(env: Env, project_id: u64) -> ProjectStatus {
+        let status = match storage::maybe_load_project_state(&env, project_id) {
+            Some(state) => state.status,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        if matches!(status, ProjectStatus::Funding | ProjectStatus::Active) {
+            let config = storage::load_project_config(&env, project_id);
+            if Self::is_lapsed(&env, config.deadline, &status) {
+                return ProjectStatus::Expired;
+            }
+        }
+        status
+    }
+
+    /// Return only the configuration of a project, as written at
+    /// registration and by later administrative updates.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_project_config(env: Env, project_id: u64) -> ProjectConfig {
+        match storage::maybe_load_project_config(&env, project_id) {
+            Some(config) => config,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        }
+    }
+
+    /// Return only the mutable state of a project, with the same lazy expiry
+    /// as `get_project`.
+    ///
+    /// Like `get_project_status`, it skips the config unless the project is
+    /// `Funding` or `Active` and its deadline must be checked; meant for
+    /// clients polling donation counts and totals.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_project_state(env: Env, project_id: u64) -> ProjectState {
+        let mut state = match storage::maybe_load_project_state(&env, project_id) {
+            Some(state) => state,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        if matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            let config = storage::load_project_config(&env, project_id);
+            if Self::is_lapsed(&env, config.deadline, &state.status) {
+                state.status = ProjectStatus::Expired;
+            }
+        }
+        state
+    }
+
+    /// Return the bond the creator posted for `project_id`, or `None` if it
+    /// was never required or has already been returned or slashed.
+    pub fn get_project_bond(env: Env, project_id: u64) -> Option<ProjectBond> {
+        storage::get_project_bond(&env, project_id)
+    }
+
+    /// Return the balance of `token` for `project_id`.
+    pub fn get_balance(env: Env, project_id: u64, token: Address) -> i128 {
+        storage::get_token_balance(&env, project_id, &token)
+    }
+
+    /// Return the lifetime deposits of `token` into a project.
+    ///
+    /// Unlike [`PifpProtocol::get_balance`], this is never reduced by
+    /// releases or refunds. Returns 0 for tokens that were never deposited.
+    pub fn get_total_raised(env: Env, project_id: u64, token: Address) -> i128 {
+        storage::get_total_raised(&env, project_id, &token)
+    }
+
+    /// Return how much of `token` `donator` has contributed to `project_id`.
+    ///
+    /// This is the amount the donor can reclaim via `refund` if the project
+    /// expires. Unknown projects and donors read as 0.
+    pub fn get_contribution(env: Env, project_id: u64, token: Address, donator: Address) -> i128 {
+        storage::get_donator_balance(&env, project_id, &token, &donator)
+    }
+
+    /// Return how much of `token` `donator` has on record for `project_id`.
+    ///
+    /// Same as [`PifpProtocol::get_contribution`], but rejects unknown
+    /// projects. Donors who never contributed read as 0.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_donation(env: Env, project_id: u64, token: Address, donator: Address) -> i128 {
+        if storage::maybe_load_project_config(&env, project_id).is_none() {
+            panic_with_error!(&env, Error::ProjectNotFound);
+        }
+        storage::get_donator_balance(&env, project_id, &token, &donator)
+    }
+
+    /// Return `donator`'s recorded balance in every accepted token of
+    /// `project_id`, in `accepted_tokens` order. Tokens the donor never
+    /// contributed are listed with 0.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_donations(env: Env, project_id: u64, donator: Address) -> Vec<(Address, i128)> {
+        let config = match storage::maybe_load_project_config(&env, project_id) {
+            Some(c) => c,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        let mut donations = Vec::new(&env);
+        for token in config.accepted_tokens.iter() {
+            let balance = storage::get_donator_balance(&env, project_id, &token, &donator);
+            donations.push_back((token, balance));
+        }
+        donations
+    }
+
+    /// Return the receipt issued for a deposit.
+    ///
+    /// # Errors
+    /// Panics with `Error::ReceiptNotFound` if no receipt has `receipt_id`.
+    pub fn get_receipt(env: Env, receipt_id: u64) -> DonationReceipt {
+        match storage::get_receipt(&env, receipt_id) {
+            Some(receipt) => receipt,
+            None => panic_with_error!(&env, Error::ReceiptNotFound),
+        }
+    }
+
+    /// Return `donator`'s receipts across all projects, oldest first,
+    /// skipping the first `start`.
+    ///
+    /// At most `limit` receipts are returned, capped at `MAX_RECEIPT_PAGE`.
+    /// Paging past the end returns an empty list.
+    pub fn get_receipts_for_donor(
+        env: Env,
+        donator: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<DonationReceipt> {
+        let count = storage::get_donor_receipt_count(&env, &donator);
+        let end = start.saturating_add(limit.min(MAX_RECEIPT_PAGE)).min(count);
+        let mut receipts = Vec::new(&env);
+        for index in start..end {
+            let receipt = storage::get_donor_receipt_id(&env, &donator, index)
+                .and_then(|id| storage::get_receipt(&env, id));
+            if let Some(receipt) = receipt {
+                receipts.push_back(receipt);
+            }
+        }
+        receipts
+    }
+
+    /// Return the most a single donor may contribute to `project_id` in each
+    /// token; 0 means unlimited.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_contribution_cap(env: Env, project_id: u64) -> i128 {
+        match storage::maybe_load_project_config(&env, project_id) {
+            Some(config) => config.max_contribution,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        }
+    }
+
+    /// Return the smallest amount a single deposit into `project_id` may
+    /// carry; 0 means no minimum.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_min_deposit(env: Env, project_id: u64) -> i128 {
+        if storage::maybe_load_project_config(&env, project_id).is_none() {
+            panic_with_error!(&env, Error::ProjectNotFound);
+        }
+        storage::get_min_deposit(&env, project_id)
+    }
+
+    /// Return whether `project_id` rejects deposits beyond its goal
+    /// (`ProjectOptions::reject_overfunding`). Unknown projects read as `false`.
+    pub fn rejects_overfunding(env: Env, project_id: u64) -> bool {
+        storage::rejects_overfunding(&env, project_id)
+    }
+
+    /// Return whether releasing `project_id` leaves the excess over its goal
+    /// to donors (`ProjectOptions::refund_excess`). Unknown projects read as
+    /// `false`.
+    pub fn refunds_excess(env: Env, project_id: u64) -> bool {
+        storage::refunds_excess(&env, project_id)
+    }
+
+    /// Return a project's progress towards its funding goal.
+    ///
+    /// `raised` counts lifetime deposits towards `goal`: in every accepted
+    /// token for a single goal, in the first token with per-token goals.
+    /// `tokens` breaks progress down per accepted token (single-goal projects
+    /// report 0 as the goal of every token but the first).
+    ///
+    /// `progress_bps` is capped at 10_000: an overfunded project reads as
+    /// 100%, and callers compare `raised` with `goal` to show the surplus.
+    /// With per-token goals it is the best token's progress, or the worst
+    /// one's when `require_all_goals` is set, so 10_000 always means the
+    /// project has reached `Active`'s threshold. `time_remaining` is 0 once
+    /// the deadline has passed, and `status` has the same lazy expiry as
+    /// `get_project`.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_funding_progress(env: Env, project_id: u64) -> FundingProgress {
+        let config = match storage::maybe_load_project_config(&env, project_id) {
+            Some(config) => config,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        let mut status = storage::load_project_state(&env, project_id).status;
+        if Self::is_lapsed(&env, config.deadline, &status) {
+            status = ProjectStatus::Expired;
+        }
+
+        let mut tokens = Vec::new(&env);
+        for token in config.accepted_tokens.iter() {
+            tokens.push_back(TokenProgress {
+                raised: storage::get_total_raised(&env, project_id, &token),
+                goal: Self::token_goal(&config, &token),
+                token,
+            });
+        }
+        let raised = if config.token_goals.is_empty() {
+            Self::raised_towards_goal(&env, &config)
+        } else {
+            tokens.get(0).map_or(0, |first| first.raised)
+        };
+
+        let progress_bps = if config.token_goals.is_empty() {
+            Self::progress_bps(raised, config.goal)
+        } else {
+            let per_token = tokens
+                .iter()
+                .filter(|t| t.goal > 0)
+                .map(|t| Self::progress_bps(t.raised, t.goal));
+            if config.require_all_goals {
+                per_token.min()
+            } else {
+                per_token.max()
+            }
+            .unwrap_or(0)
+        };
+
+        FundingProgress {
+            goal: config.goal,
+            raised,
+            progress_bps,
+            tokens,
+            time_remaining: config.deadline.saturating_sub(env.ledger().timestamp()),
+            status,
+        }
+    }
+
+    /// Return the current per-token balances for a project.
+    ///
+    /// Reconstructs the balance snapshot from persistent storage for every
+    /// accepted token, alongside each token's lifetime `total_raised`.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn get_project_balances(env: Env, project_id: u64) -> ProjectBalances {
+        let project = match maybe_load_project(&env, project_id) {
+            Some(p) => p,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        get_all_balances(&env, &project)
+    }
+
+    /// Return balances for a subset of a project's accepted tokens, in the
+    /// order requested.
+    ///
+    /// Reads only the project config and the requested balance entries, so
+    /// it is cheaper than [`PifpProtocol::get_project_balances`] for
+    /// frontends that track one or two tokens.
+    ///
+    /// # Errors
+    /// Panics with `Error::ProjectNotFound` if `project_id` does not exist,
+    /// `Error::BatchTooLarge` if `tokens` is longer than the accepted list,
+    /// and `Error::TokenNotAccepted` for any token the project does not
+    /// accept.
+    pub fn get_balances_by_tokens(
+        env: Env,
+        project_id: u64,
+        tokens: Vec<Address>,
+    ) -> ProjectBalances {
+        let config = match storage::maybe_load_project_config(&env, project_id) {
+            Some(config) => config,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        if tokens.len() > config.accepted_tokens.len() {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+        for token in tokens.iter() {
+            if !config.accepted_tokens.contains(&token) {
+                panic_with_error!(&env, Error::TokenNotAccepted);
+            }
+        }
+        storage::get_balances_for(&env, project_id, &tokens)
+    }
+
+    /// Return protocol-wide counters: projects registered, projects in each
+    /// status, and refund payouts made.
+    ///
+    /// Counters are updated alongside the writes they reflect, never
+    /// recomputed here. Projects that lapsed but were never touched count as
+    /// expired only once their expiry is persisted.
+    pub fn get_protocol_stats(env: Env) -> ProtocolStats {
+        storage::get_stats(&env)
+    }
+
+    /// Return the same counters as [`PifpProtocol::get_protocol_stats`].
+    ///
+    /// Kept for callers of the original entry point.
+    pub fn get_stats(env: Env) -> ProtocolStats {
+        storage::get_stats(&env)
+    }
+
+    /// Return the lifetime amount of `token` deposited across all projects,
+    /// including matched funds. Refunds and releases do not reduce it.
+    pub fn get_total_volume(env: Env, token: Address) -> i128 {
+        storage::get_total_volume(&env, &token)
+    }
+
+    /// Snapshot a project for an auditor in one authenticated call: stored
+    /// status, deadline, every token balance and donor counts.
+    ///
+    /// - `auditor` must hold `Auditor` and must authorize.
+    ///
+    /// # Errors
+    /// Panics with `Error::NotAuthorized` for non-auditors and
+    /// `Error::ProjectNotFound` if `project_id` does not exist.
+    pub fn audit_project(env: Env, auditor: Address, project_id: u64) -> AuditReport {
+        auditor.require_auth();
+        rbac::require_auditor(&env, &auditor);
+
+        let project = match maybe_load_project(&env, project_id) {
+            Some(p) => p,
+            None => panic_with_error!(&env, Error::ProjectNotFound),
+        };
+        AuditReport {
+            project_id,
+            creator: project.creator.clone(),
+            status: project.status.clone(),
+            deadline: project.deadline,
+            balances: get_all_balances(&env, &project).balances,
+            donation_count: project.donation_count,
+            unique_donors: project.unique_donors,
+            total_deposited: project.total_deposited,
+        }
+    }
+
+    /// Deposit funds into a project and return the id of its receipt.
+    ///
+    /// The `token` must be one of the project's accepted tokens. The
+    /// receipt can be read back with [`PifpProtocol::get_receipt`].
+    pub fn deposit(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        amount: i128,
+    ) -> u64 {
+        Self::deposit_with_memo(env, project_id, donator, token, amount, None)
+    }
+
+    /// Deposit funds into a project with a short note from the donor, and
+    /// return the id of its receipt.
+    ///
+    /// The memo (1–64 bytes, else `InvalidMemo`) is only published in the
+    /// `ProjectFunded` event; it is not kept in contract storage.
+    pub fn deposit_with_memo(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        amount: i128,
+        memo: Option<String>,
+    ) -> u64 {
+        if let Some(memo) = &memo {
+            if memo.is_empty() || memo.len() > MAX_MEMO_LEN {
+                panic_with_error!(&env, Error::InvalidMemo);
+            }
+        }
+        Self::deposit_single(env, project_id, donator, token, amount, memo, false)
+    }
+
+    /// Deposit funds into a project without naming the donator in the
+    /// `ProjectFunded` event, and return the id of its receipt.
+    ///
+    /// The event carries the contract's own address and `is_anonymous`
+    /// instead. The contribution is still recorded against `donator`, so
+    /// refunds, caps and donor counts work as for [`PifpProtocol::deposit`].
+    pub fn deposit_anonymous(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        token: Address,
+        amount: i128,
+    ) -> u64 {
+        Self::deposit_single(env, project_id, donator, token, amount, None, true)
+    }
+
+    /// Deposit several `(token, amount)` pairs into a project in one call,
+    /// and return the receipt id of each entry in order.
+    ///
+    /// All-or-nothing: every token must be accepted, listed once
+    /// (`DuplicateToken`) and every amount positive before any transfer
+    /// happens. At most `max_accepted_tokens` entries are allowed
+    /// (`TooManyTokens`). Donor
+    /// counts and `donation_count` end up exactly as after the same deposits
+    /// made one by one. Emits one `ProjectFunded` event per entry.
+    pub fn deposit_many(
+        env: Env,
+        project_id: u64,
+        donator: Address,
+        deposits: Vec<(Address, i128)>,
+    ) -> Vec<u64> {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        Self::require_not_blocked(&env, &donator);
+
+        if deposits.is_empty() {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if deposits.len() > Self::protocol_params(&env).max_accepted_tokens {
+            panic_with_error!(&env, Error::TooManyTokens);
+        }
+
+        let (config, mut state) = Self::load_for_deposit(&env, project_id);
+
+        // Validate the whole batch before moving any funds.
+        let mut seen: Vec<Address> = Vec::new(&env);
+        for (token, amount) in deposits.iter() {
+            if amount <= 0 {
+                panic_with_error!(&env, Error::InvalidAmount);
+            }
+            Self::require_accepted_token(&env, &config, &token);
+            if seen.contains(&token) {
+                panic_with_error!(&env, Error::DuplicateToken);
+            }
+            seen.push_back(token);
+        }
+
+        let mut receipts = Vec::new(&env);
+        for (token, amount) in deposits.iter() {
+            let receipt_id = Self::apply_deposit(
+                &env, &config, &mut state, &donator, &token, amount, None, false, false,
+            );
+            receipts.push_back(receipt_id);
+        }
+        save_project_state(&env, project_id, &state);
+        receipts
+    }
+
+    /// Deposit into several projects in one call, and return the receipt id
+    /// of each entry in order.
+    ///
+    /// All-or-nothing: every entry is checked first (project open and not
+    /// frozen, token accepted, amount positive) and the call fails with the
+    /// first entry's error before any transfer. At most 10 entries are
+    /// allowed (`BatchTooLarge`). Each entry emits the same `ProjectFunded`
+    /// event as a single `deposit`.
+    pub fn deposit_batch(
+        env: Env,
+        donator: Address,
+        deposits: Vec<(u64, Address, i128)>,
+    ) -> Vec<u64> {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        Self::require_not_blocked(&env, &donator);
+
+        if deposits.is_empty() {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        if deposits.len() > MAX_DEPOSIT_BATCH {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+
+        for (project_id, token, amount) in deposits.iter() {
+            if amount <= 0 {
+                panic_with_error!(&env, Error::InvalidAmount);
+            }
+            let (config, _) = Self::load_for_deposit(&env, project_id);
+            Self::require_accepted_token(&env, &config, &token);
+        }
+
+        // Reload per entry: several entries may target the same project.
+        let mut receipts = Vec::new(&env);
+        for (project_id, token, amount) in deposits.iter() {
+            let (config, mut state) = Self::load_for_deposit(&env, project_id);
+            let receipt_id = Self::apply_deposit(
+                &env, &config, &mut state, &donator, &token, amount, None, false, false,
+            );
+            save_project_state(&env, project_id, &state);
+            receipts.push_back(receipt_id);
+        }
+        receipts
+    }
+
+    /// Pledge `amount_per_period` of `token` to a project every `period_secs`
+    /// for `periods` periods, and return the pledge id.
+    ///
+    /// - `donator` must authorize and must separately approve the contract
+    ///   as spender of `token` for the total it expects to be pulled.
+    /// - The project must be open for deposits and accept `token`.
+    /// - `amount_per_period`, `period_secs` and `periods` must all be
+    ///   positive (`InvalidAmount`).
+    ///
+    /// No funds move here; the first period is due immediately. Emits a
+    /// `PledgeCreated` event.
+    pub fn create_pledge(
+        env: Env,
+        donator: Address,
+        project_id: u64,
+        token: Address,
+        amount_per_period: i128,
+        period_secs: u64,
+        periods: u32,
+    ) -> u64 {
+        Self::require_not_paused(&env);
+        donator.require_auth();
+        Self::require_not_blocked(&env, &donator);
+
+        if amount_per_period <= 0 || period_secs == 0 || periods == 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let (config, _) = Self::load_for_deposit(&env, project_id);
+        Self::require_accepted_token(&env, &config, &token);
+
+        let pledge = Pledge {
+            id: storage::get_and_increment_pledge_id(&env),
+            project_id,
+            donator,
+            token,
+            amount_per_period,
+            period_secs,
+            remaining_periods: periods,
+            next_due: env.ledger().timestamp(),
+        };
+        storage::save_pledge(&env, &pledge);
+
+        events::emit_pledge_created(&env, &pledge);
+        pledge.id
+    }
+
+    /// Pull one period of a pledge into its project, and return the receipt
+    /// id of the resulting deposit.
+    ///
+    /// Permissionless so keepers can run it, but funds only ever move from
+    /// the donator's allowance into the project's escrow. The deposit is
+    /// credited exactly like `deposit`, with the same checks and
+    /// `ProjectFunded` event. The pledge is removed after its last period.
+    ///
+    /// # Errors
+    /// Panics with `Error::PledgeNotFound` for unknown, cancelled or
+    /// exhausted pledges, `Error::PledgeNotDue` before `next_due`, and the
+    /// usual deposit errors (e.g. `ProjectExpired`) once the project no
+    /// longer takes deposits.
+    pub fn execute_pledge(env: Env, pledge_id: u64) -> u64 {
+        Self::require_not_paused(&env);
+
+        let mut pledge = match storage::get_pledge(&env, pledge_id) {
+            Some(pledge) => pledge,
+            None => panic_with_error!(&env, Error::PledgeNotFound),
+        };
+        if env.ledger().timestamp() < pledge.next_due {
+            panic_with_error!(&env, Error::PledgeNotDue);
+        }
+        Self::require_not_blocked(&env, &pledge.donator);
+
+        let (config, mut state) = Self::load_for_deposit(&env, pledge.project_id);
+        Self::require_accepted_token(&env, &config, &pledge.token);
+        let receipt_id = Self::apply_deposit(
+            &env,
+            &config,
+            &mut state,
+            &pledge.donator,
+            &pledge.token,
+            pledge.amount_per_period,
+            None,
+            false,
+            true,
+        );
+        save_project_state(&env, pledge.project_id, &state);
+
+        pledge.remaining_periods -= 1;
+        pledge.next_due = pledge.next_due.saturating_add(pledge.period_secs);
+        if pledge.remaining_periods == 0 {
+            storage::remove_pledge(&env, pledge_id);
+        } else {
+            storage::save_pledge(&env, &pledge);
+        }
+
+        events::emit_pledge_executed(&env, &pledge);
+        receipt_id
+    }
+
+    /// Stop all future pulls of a pledge.
+    ///
+    /// - `donator` must be the pledge's donator and must authorize
+    ///   (`NotAuthorized`).
+    ///
+    /// Periods already pulled stay deposited. Emits a `PledgeCancelled` event.
+    pub fn cancel_pledge(env: Env, donator: Address, pledge_id: u64) {
+        donator.require_auth();
+
+        let pledge = match storage::get_pledge(&env, pledge_id) {
+            Some(pledge) => pledge,
+            None => panic_with_error!(&env, Error::PledgeNotFound),
+        };
+        if donator != pledge.donator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        storage::remove_pledge(&env, pledge_id);
+        events::emit_pledge_cancelled(&env, &pledge);
+    }
+
+    /// Return an active pledge.
+    ///
+    /// # Errors
+    /// Panics with `Error::PledgeNotFound` if the pledge does not exist,
+    /// was cancelled or has run all its periods.
+    pub fn get_pledge(env: Env, pledge_id: u64) -> Pledge {
+        match storage::get_pledge(&env, pledge_id) {
+            Some(pledge) => pledge,
+            None => panic_with_error!(&env, Error::PledgeNotFound),
+        }
+    }
+
+    /// Escrow `amount` of `token` from `sponsor` to match donations to a
+    /// project 1:1.
+    ///
+    /// - `sponsor` must authorize.
+    /// - The project must be open for deposits and accept `token`.
+    /// - `amount` must be positive (`InvalidAmount`).
+    /// - A pool already funded by another sponsor cannot be topped up
+    ///   (`NotAuthorized`); the same sponsor may add to it.
+    ///
+    /// Pool funds stay out of the project's balance until a deposit of
+    /// `token` draws an equal amount from it, credited to the sponsor as a
+    /// contribution. Whatever is left refunds to the sponsor with their
+    /// contributions once refunds open, or through `withdraw_matching_pool`
+    /// after completion. Emits a `MatchingPoolFunded` event.
+    pub fn fund_matching_pool(
+        env: Env,
+        sponsor: Address,
+        project_id: u64,
+        token: Address,
+        amount: i128,
+    ) {
+        Self::require_not_paused(&env);
+        sponsor.require_auth();
+        Self::require_not_blocked(&env, &sponsor);
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let (config, _) = Self::load_for_deposit(&env, project_id);
+        Self::require_accepted_token(&env, &config, &token);
+
+        let mut pool = match storage::get_matching_pool(&env, project_id, &token) {
+            Some(pool) if pool.sponsor != sponsor => {
+                panic_with_error!(&env, Error::NotAuthorized)
+            }
+            Some(pool) => pool,
+            None => MatchingPool {
+                sponsor: sponsor.clone(),
+                remaining: 0,
+            },
+        };
+        pool.remaining = match pool.remaining.checked_add(amount) {
+            Some(total) => total,
+            None => panic_with_error!(&env, Error::Overflow),
+        };
+
+        token::Client::new(&env, &token).transfer(
+            &sponsor,
+            &env.current_contract_address(),
+            &amount,
+        );
+        storage::add_to_total_held(&env, &token, amount);
+        storage::set_matching_pool(&env, project_id, &token, &pool);
+
+        events::emit_matching_pool_funded(&env, project_id, token, sponsor, amount);
+    }
+
+    /// Return the unspent matching pool of a project for `token`, if any.
+    pub fn get_matching_pool(env: Env, project_id: u64, token: Address) -> Option<MatchingPool> {
+        storage::get_matching_pool(&env, project_id, &token)
+    }
+
+    /// Return the unspent part of a matching pool to its sponsor once the
+    /// project has completed, and return the amount paid.
+    ///
+    /// Expired and cancelled projects return it through `refund` instead.
+    ///
+    /// # Errors
+    /// Panics with `Error::InvalidStateTransition` unless the project is
+    /// `Completed`, and `Error::InsufficientBalance` if `sponsor` has no
+    /// unspent pool in `token`.
+    pub fn withdraw_matching_pool(
+        env: Env,
+        sponsor: Address,
+        project_id: u64,
+        token: Address,
+    ) -> i128 {
+        sponsor.require_auth();
+
+        let (_, state) = Self::load_refundable_pair(&env, project_id);
+        if state.status != ProjectStatus::Completed {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+
+        let amount = Self::take_matching_pool(&env, project_id, &token, &sponsor);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+        Self::send_tokens(&env, &token, &sponsor, amount);
+        amount
+    }
+
+    /// Refund a donator from an expired or slashed project, or pay out their
+    /// share of the excess a `refund_excess` project left in escrow at release.
+    pub fn refund(env: Env, donator: Address, project_id: u64, token: Address) {
+        Self::refund_to(env, donator.clone(), project_id, token, donator);
+    }
+
+    /// Refund a donator from an expired or slashed project, sending the
+    /// funds to `recipient` instead of the donator.
+    ///
+    /// The donator must still authorize, and it is their tracked balance
+    /// that is zeroed. Useful when the donating wallet is compromised or
+    /// funds should be consolidated elsewhere.
+    pub fn refund_to(
+        env: Env,
+        donator: Address,
+        project_id: u64,
+        token: Address,
+        recipient: Address,
+    ) {
+        donator.require_auth();
+
+        let (_, state) = Self::load_refundable_pair(&env, project_id);
+
+        let excess = if state.status == ProjectStatus::Completed {
+            storage::get_excess_refund(&env, project_id, &token)
+        } else {
+            None
+        };
+        if excess.is_none() {
+            Self::require_refunds_open(&env, &state);
+        }
+
+        if Self::refund_token(&env, project_id, &token, &donator, &recipient, excess).is_none() {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+    }
+
+    /// Refund a donator every accepted token of an expired or slashed
+    /// project in one call, emitting one `Refunded` event per token paid.
+    ///
+    /// Tokens the donator holds nothing in are skipped; fails with
+    /// `InsufficientBalance` only if there is nothing to refund in any of
+    /// them, so calling it twice fails cleanly. On a `Completed`
+    /// `refund_excess` project it pays the donator's share of every token
+    /// with excess left in escrow.
+    ///
+    /// # Errors
+    /// Same preconditions as `refund`: `ProjectNotExpired` unless the
+    /// project is `Expired`, `Cancelled` or past its deadline, or is
+    /// `Completed` with excess to share.
+    pub fn refund_all(env: Env, donator: Address, project_id: u64) {
+        donator.require_auth();
+
+        let (config, state) = Self::load_refundable_pair(&env, project_id);
+        let completed = state.status == ProjectStatus::Completed;
+        if !completed {
+            Self::require_refunds_open(&env, &state);
+        }
+
+        let mut has_excess = false;
+        let mut refunded_any = false;
+        for token in config.accepted_tokens.iter() {
+            let excess = if completed {
+                match storage::get_excess_refund(&env, project_id, &token) {
+                    Some(excess) => Some(excess),
+                    None => continue,
+                }
+            } else {
+                None
+            };
+            has_excess |= excess.is_some();
+            refunded_any |=
+                Self::refund_token(&env, project_id, &token, &donator, &donator, excess).is_some();
+        }
+
+        if completed && !has_excess {
+            panic_with_error!(&env, Error::ProjectNotExpired);
+        }
+        if !refunded_any {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+    }
+
+    /// Push refunds to donators of an expired or slashed project who have not
+    /// claimed them, so small balances are not stranded.
+    ///
+    /// - `admin` must hold `SuperAdmin` or `Admin`.
+    /// - At most 50 `donators` per call (`BatchTooLarge`).
+    /// - Same status preconditions as `refund`, except that excess left by a
+    ///   `refund_excess` release is not pushed.
+    ///
+    /// Every accepted token the donator still holds a balance in is returned
+    /// to them, with the same `Refunded` event as a self-service refund;
+    /// entries with nothing left are skipped, so re-running a list is a
+    /// no-op. Returns how many donators received a refund.
+    pub fn force_refund(env: Env, admin: Address, project_id: u64, donators: Vec<Address>) -> u32 {
+        admin.require_auth();
+        rbac::require_admin_or_above(&env, &admin);
+        if donators.len() > MAX_FORCE_REFUND_BATCH {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+
+        let (config, state) = Self::load_refundable_pair(&env, project_id);
+        Self::require_refunds_open(&env, &state);
+
+        let mut refunded = 0;
+        for donator in donators.iter() {
+            let mut paid = false;
+            for token in config.accepted_tokens.iter() {
+                paid |= Self::refund_token(&env, project_id, &token, &donator, &donator, None)
+                    .is_some();
+            }
+            if paid {
+                refunded += 1;
+            }
+        }
+        refunded
+    }
+
+    /// Give up the refund of `token` on an expired project in favour of its
+    /// creator, who can then collect it with `claim_waived`.
+    ///
+    /// The donator's refundable share (as computed by `refund`) is zeroed and leaves the refundable escrow, so
+    /// neither a later refund nor `sweep_unclaimed` can take it.
+    ///
+    /// - Panics with `ProjectNotExpired` unless the project is `Expired` (a
+    ///   lapsed deadline counts), and `InsufficientBalance` if the donator
+    ///   has nothing left to refund.
+    ///
+    /// Emits a `RefundWaived` event and returns the amount waived.
+    pub fn waive_refund(env: Env, donator: Address, project_id: u64, token: Address) -> i128 {
+        donator.require_auth();
+
+        let (_, state) = Self::load_refundable_pair(&env, project_id);
+        if state.status != ProjectStatus::Expired {
+            panic_with_error!(&env, Error::ProjectNotExpired);
+        }
+
+        let contributed = storage::get_donator_balance(&env, project_id, &token, &donator);
+        let amount = Self::refund_share(&env, project_id, &token, contributed);
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+
+        storage::set_donator_balance(&env, project_id, &token, &donator, 0);
+        storage::add_to_token_balance(&env, project_id, &token, -amount);
+        let waived = storage::get_waived(&env, project_id, &token)
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow));
+        storage::set_waived(&env, project_id, &token, waived);
+
+        events::emit_refund_waived(&env, project_id, token, donator, amount);
+        amount
+    }
+
+    /// Grant the Oracle role to `oracle`.
+    ///
+    /// Replaces the original `set_oracle(admin, oracle)`.
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    pub fn set_oracle(env: Env, caller: Address, oracle: Address) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        rbac::grant_role(&env, &caller, &oracle, Role::Oracle);
+    }
+
+    /// Revoke the Oracle role from `oracle`.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Fails with `RoleNotFound` unless `oracle` currently holds `Oracle`.
+    ///
+    /// Addresses hold a single role, so `oracle` is left with no role.
+    pub fn revoke_oracle(env: Env, caller: Address, oracle: Address) {
+        caller.require_auth();
+        rbac::revoke_oracle(&env, &caller, &oracle);
+    }
+
+    /// Assign (or clear) the oracle exclusively allowed to verify a project.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - With `Some(oracle)`, only that address may call `verify_and_release`
+    ///   for the project, whether or not it holds the global `Oracle` role.
+    /// - With `None`, verification falls back to any global `Oracle`.
+    /// - The project must still be verifiable (`Funding` or `Active`).
+    ///
+    /// Emits an `OracleAssigned` event with the previous and new assignment.
+    pub fn set_project_oracle(env: Env, caller: Address, project_id: u64, oracle: Option<Address>) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+
+        let old_oracle = config.assigned_oracle.clone();
+        config.assigned_oracle = oracle.clone();
+        storage::save_project_config(&env, &config);
+
+        events::emit_oracle_assigned(&env, project_id, old_oracle, oracle);
+    }
+
+    /// Require (or stop requiring) an admin co-signature to release a project.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - The project must still be verifiable (`Funding` or `Active`).
+    pub fn set_requires_admin_cosign(env: Env, caller: Address, project_id: u64, required: bool) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+
+        let (mut config, state) = load_project_pair(&env, project_id);
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+
+        config.requires_admin_cosign = required;
+        storage::save_project_config(&env, &config);
+    }
+
+    /// Verify proof of impact and release funds to the creator.
+    ///
+    /// The registered oracle submits a proof hash. If it matches the project's
+    /// stored `proof_hash`, the project status transitions to `Completed`.
+    /// When the project has an assigned oracle, only that address may call
+    /// this; otherwise any holder of the global `Oracle` role may.
+    ///
+    /// Only available while the quorum threshold is 1 or the project has an
+    /// assigned oracle; otherwise oracles must use `submit_verification_vote`
+    /// instead (`QuorumNotReached`). Projects flagged `requires_admin_cosign` must go
+    /// through `approve_release` + `finalize_release` (`CosignRequired`).
+    ///
+    /// For projects registered with a `vesting_duration_secs`, the net
+    /// amounts are recorded as vesting schedules and claimed by the creator
+    /// through `claim_vested` instead of being transferred immediately.
+    ///
+    /// When the project's `release_delay` or the protocol-wide delay is set,
+    /// the project moves to `Verified` and funds stay escrowed until
+    /// `claim_release` is called after the longer of the two. Emits
+    /// `ReleaseScheduled` in that case.
+    ///
+    /// NOTE: This is a mocked verification (hash equality).
     /// The structure is prepared for future ZK-STARK verification.
     ///
     /// Reads the immutable config (for proof_hash) and mutable state (for status),
     /// then writes back only the small state entry.
     pub fn verify_and_release(
         env: Env,
-        oracle: Address,
+        oracle: Address,
+        project_id: u64,
+        submitted_proof_hash: BytesN<32>,
+    ) {
+        Self::require_not_paused(&env);
+        oracle.require_auth();
+
+        let (config, mut state) = Self::load_for_verification(&env, project_id, &oracle);
+        Self::require_direct_release(&env, &config);
+
+        Self::release(&env, &config, &mut state, &oracle, submitted_proof_hash);
+    }
+
+    /// Record `oracle`'s vote that `proof_hash` is the project's proof.
+    ///
+    /// Votes are final: each oracle holds at most one vote per project,
+    /// repeating it is a no-op and voting for a different hash fails with
+    /// `AlreadyVoted`, so a single oracle can never count twice towards the
+    /// quorum. Only `veto_release` clears the votes. Votes on
+    /// completed, expired or otherwise unverifiable projects are rejected. Once
+    /// the number of votes for `proof_hash` reaches the quorum threshold the
+    /// release runs exactly as in `verify_and_release`, including the hash
+    /// check against the stored `proof_hash`.
+    ///
+    /// Only votes from addresses that still pass the oracle gate are counted,
+    /// so revoking an oracle's role also withdraws its votes from the tally.
+    /// A project with an assigned oracle needs only that oracle's vote.
+    ///
+    /// The same oracle gate as `verify_and_release` applies. Emits a
+    /// `VoteSubmitted` event.
+    pub fn submit_verification_vote(
+        env: Env,
+        oracle: Address,
+        project_id: u64,
+        proof_hash: BytesN<32>,
+    ) {
+        Self::require_not_paused(&env);
+        oracle.require_auth();
+
+        let (config, mut state) = Self::load_for_verification(&env, project_id, &oracle);
+        if config.requires_admin_cosign {
+            panic_with_error!(&env, Error::CosignRequired);
+        }
+
+        let votes = Self::record_vote(&env, project_id, &oracle, &proof_hash);
+        events::emit_vote_submitted(&env, project_id, oracle.clone(), proof_hash.clone());
+
+        if Self::count_votes(&env, &config, &votes, &proof_hash)
+            >= Self::required_votes(&env, &config)
+        {
+            Self::release(&env, &config, &mut state, &oracle, proof_hash);
+        }
+    }
+
+    /// Map an ed25519 public key to the oracle address it signs for.
+    ///
+    /// - `caller` must hold `SuperAdmin` or `Admin`.
+    /// - Registering a key again re-points it to the new `oracle`.
+    ///
+    /// The mapped address must still pass the oracle gate when a signature is
+    /// used, so revoking its Oracle role disables the key as well.
+    pub fn register_oracle_key(env: Env, caller: Address, oracle: Address, pubkey: BytesN<32>) {
+        caller.require_auth();
+        rbac::require_admin_or_above(&env, &caller);
+        storage::set_oracle_key(&env, &pubkey, &oracle);
+    }
+
+    /// Release a project using an oracle's ed25519 signature instead of its auth.
+    ///
+    /// Lets a relayer submit the release and pay the fees. The signature must
+    /// cover the canonical payload
+    /// `"pifp:verify" || contract address (XDR) || project_id (u64, big-endian) || proof_hash`.
+    /// The domain tag keeps these signatures apart from anything else the key
+    /// signs, and the contract address, which already differs per network,
+    /// stops a signature from being replayed on another deployment.
+    /// `oracle_pubkey` must be registered via `register_oracle_key`, and the
+    /// address it maps to is then checked exactly as in `verify_and_release`.
+    ///
+    /// An invalid signature traps inside the host's `ed25519_verify`, which
+    /// the contract cannot intercept, so callers see a host error rather than
+    /// `VerificationFailed`. The whole invocation aborts and no funds move.
+    pub fn verify_with_signature(
+        env: Env,
+        project_id: u64,
+        proof_hash: BytesN<32>,
+        oracle_pubkey: BytesN<32>,
+        signature: BytesN<64>,
+    ) {
+        Self::require_not_paused(&env);
+
+        let oracle = match storage::get_oracle_key(&env, &oracle_pubkey) {
+            Some(oracle) => oracle,
+            None => panic_with_error!(&env, Error::NotAuthorized),
+        };
+
+        let mut payload = Bytes::from_slice(&env, VERIFY_SIGNATURE_TAG);
+        payload.append(&env.current_contract_address().to_xdr(&env));
+        payload.append(&Bytes::from_array(&env, &project_id.to_be_bytes()));
+        payload.append(&Bytes::from_array(&env, &proof_hash.to_array()));
+        env.crypto()
+            .ed25519_verify(&oracle_pubkey, &payload, &signature);
+
+        let (config, mut state) = Self::load_for_verification(&env, project_id, &oracle);
+        Self::require_direct_release(&env, &config);
+
+        Self::release(&env, &config, &mut state, &oracle, proof_hash);
+    }
+
+    /// Verify proof of impact and release only part of the escrow.
+    ///
+    /// `amounts` lists `(token, amount)` pairs to pay out to the creator (minus
+    /// the protocol fee). Each token must be accepted (`TokenNotAccepted`), each
+    /// amount positive (`InvalidAmount`) and no larger than what is still
+    /// escrowed (`InsufficientBalance`). Balances are reduced, not drained.
+    ///
+    /// The same oracle gate, hash check and release guards as
+    /// `verify_and_release` apply. The project moves to (or stays) `Active`;
+    /// only a full `verify_and_release` completes it. Emits one
+    /// `FundsReleased` event per entry.
+    ///
+    /// Tranches are paid out immediately, so they are refused with
+    /// `PartialReleaseUnavailable` wherever a full release would be held
+    /// back: when a release delay applies (the veto window) or the project
+    /// vests its payouts.
+    pub fn verify_and_release_partial(
+        env: Env,
+        oracle: Address,
+        project_id: u64,
+        proof_hash: BytesN<32>,
+        amounts: Vec<(Address, i128)>,
+    ) {
+        Self::require_not_paused(&env);
+        oracle.require_auth();
+
+        let (config, mut state) = Self::load_for_verification(&env, project_id, &oracle);
+        Self::require_direct_release(&env, &config);
+        Self::require_payable(&env, &config);
+        if Self::release_delay(&env, &config) > 0 || config.vesting_duration_secs > 0 {
+            panic_with_error!(&env, Error::PartialReleaseUnavailable);
+        }
+
+        // Mocked ZK verification: compare submitted hash to stored hash.
+        if proof_hash != config.proof_hash {
+            panic_with_error!(&env, Error::VerificationFailed);
+        }
+        if amounts.is_empty() {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        for (token, amount) in amounts.iter() {
+            if amount <= 0 {
+                panic_with_error!(&env, Error::InvalidAmount);
+            }
+            Self::require_accepted_token(&env, &config, &token);
+
+            let balance = storage::get_token_balance(&env, project_id, &token);
+            if amount > balance {
+                panic_with_error!(&env, Error::InsufficientBalance);
+            }
+            storage::set_token_balance(&env, project_id, &token, balance - amount);
+            storage::mark_partially_released(&env, project_id, &token);
+
+            Self::pay_out(&env, &config, &token, amount);
+        }
+
+        if state.status == ProjectStatus::Funding {
+            Self::transition(&env, &config, &mut state, ProjectStatus::Active);
+            save_project_state(&env, project_id, &state);
+            events::emit_project_active(&env, project_id);
+        }
+    }
+
+    /// First half of a two-person release: an oracle approves the proof.
+    ///
+    /// The same oracle gate and status checks as `verify_and_release` apply,
+    /// and `proof_hash` must already match the stored hash. The approval is
+    /// recorded for `finalize_release` and replaces any earlier one. Usable on
+    /// any project; mandatory for projects flagged `requires_admin_cosign`.
+    ///
+    /// The approval also counts as the oracle's verification vote, under the
+    /// same rules as `submit_verification_vote` (`AlreadyVoted` if it voted
+    /// for another hash). Under a quorum threshold above 1, every oracle of
+    /// the quorum approves before an admin finalizes.
+    ///
+    /// Emits a `ReleaseApproved` event.
+    pub fn approve_release(env: Env, oracle: Address, project_id: u64, proof_hash: BytesN<32>) {
+        Self::require_not_paused(&env);
+        oracle.require_auth();
+
+        let (config, _) = Self::load_for_verification(&env, project_id, &oracle);
+        if proof_hash != config.proof_hash {
+            panic_with_error!(&env, Error::VerificationFailed);
+        }
+
+        Self::record_vote(&env, project_id, &oracle, &proof_hash);
+
+        let approval = ReleaseApproval {
+            oracle: oracle.clone(),
+            proof_hash: proof_hash.clone(),
+            approved_at: env.ledger().sequence(),
+        };
+        storage::set_release_approval(&env, project_id, &approval);
+
+        events::emit_release_approved(&env, project_id, oracle, proof_hash);
+    }
+
+    /// Second half of a two-person release: an admin co-signs and funds move.
+    ///
+    /// - `admin` must hold `SuperAdmin` or `Admin` and must not be the
+    ///   approving oracle (`NotAuthorized`).
+    /// - An oracle approval must exist (`NotAuthorized`) and be at most
+    ///   `RELEASE_APPROVAL_WINDOW` ledgers old (`ApprovalExpired`).
+    /// - The approving oracle must still pass the oracle gate.
+    /// - The approved hash must have the same quorum of current oracles as
+    ///   `submit_verification_vote` requires (`QuorumNotReached`).
+    ///
+    /// Emits a `ReleaseCoSigned` event naming both signers.
+    pub fn finalize_release(env: Env, admin: Address, project_id: u64) {
+        Self::require_not_paused(&env);
+        admin.require_auth();
+        rbac::require_admin_or_above(&env, &admin);
+
+        let approval = match storage::get_release_approval(&env, project_id) {
+            Some(approval) => approval,
+            None => panic_with_error!(&env, Error::NotAuthorized),
+        };
+        if admin == approval.oracle {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        if env.ledger().sequence() > approval.approved_at.saturating_add(RELEASE_APPROVAL_WINDOW) {
+            panic_with_error!(&env, Error::ApprovalExpired);
+        }
+
+        let (config, mut state) = Self::load_for_verification(&env, project_id, &approval.oracle);
+        let votes = storage::get_votes(&env, project_id);
+        if Self::count_votes(&env, &config, &votes, &approval.proof_hash)
+            < Self::required_votes(&env, &config)
+        {
+            panic_with_error!(&env, Error::QuorumNotReached);
+        }
+        storage::remove_release_approval(&env, project_id);
+
+        events::emit_release_cosigned(&env, project_id, approval.oracle.clone(), admin);
+        Self::release(
+            &env,
+            &config,
+            &mut state,
+            &approval.oracle,
+            approval.proof_hash,
+        );
+    }
+
+    /// Return the pending oracle approval for a project, if any.
+    pub fn get_release_approval(env: Env, project_id: u64) -> Option<ReleaseApproval> {
+        storage::get_release_approval(&env, project_id)
+    }
+
+    /// Return every recorded verification vote for a project, keyed by oracle.
+    pub fn get_votes(env: Env, project_id: u64) -> Map<Address, BytesN<32>> {
+        storage::get_votes(&env, project_id)
+    }
+
+    /// Pay out a `Verified` project once its release delay has elapsed.
+    ///
+    /// Permissionless: anyone may trigger the transfer to the creator. Panics
+    /// with `InvalidStateTransition` if the project is not `Verified` and
+    /// `ReleaseLocked` if the delay has not yet passed. Marks the project
+    /// `Completed`.
+    pub fn claim_release(env: Env, project_id: u64) {
+        Self::require_not_paused(&env);
+        Self::complete_release(&env, project_id);
+    }
+
+    /// Creator-initiated variant of `claim_release`.
+    ///
+    /// `creator` must authorize and own the project (`NotAuthorized`); the
+    /// same status and timelock checks apply.
+    pub fn claim_released_funds(env: Env, creator: Address, project_id: u64) {
+        Self::require_not_paused(&env);
+        creator.require_auth();
+
+        let config = storage::load_project_config(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+        Self::complete_release(&env, project_id);
+    }
+
+    /// Veto a timelocked release while its delay is still running.
+    ///
+    /// - `admin` must hold `SuperAdmin` or `Admin`.
+    /// - The project must be `Verified` (`InvalidStateTransition`) and its
+    ///   release must not have unlocked yet (`InvalidStateTransition`).
+    ///
+    /// Returns the project to `Active`, where it can be verified again, and
+    /// emits a `ReleaseVetoed` event. Recorded votes and any pending oracle
+    /// approval are discarded, so a new release needs a fresh quorum or
+    /// co-signature.
+    pub fn veto_release(env: Env, admin: Address, project_id: u64) {
+        admin.require_auth();
+        rbac::require_admin_or_above(&env, &admin);
+
+        let (config, mut state) = load_project_pair(&env, project_id);
+        if state.status != ProjectStatus::Verified {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+        let release_after = storage::get_release_after(&env, project_id).unwrap_or(0);
+        if env.ledger().timestamp() >= release_after {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+
+        Self::transition(&env, &config, &mut state, ProjectStatus::Active);
+        save_project_state(&env, project_id, &state);
+        storage::remove_release_after(&env, project_id);
+        storage::clear_votes(&env, project_id);
+        storage::remove_release_approval(&env, project_id);
+
+        events::emit_release_vetoed(&env, project_id, admin);
+    }
+
+    /// Transfer the refunds donors waived in `token` to the project's payout
+    /// recipient, minus the protocol fee, and return the amount sent.
+    ///
+    /// Panics with `NotAuthorized` if `creator` does not own the project and
+    /// `InsufficientBalance` when nothing has been waived since the last
+    /// claim.
+    ///
+    /// Emits a `WaivedClaimed` event for the transferred amount.
+    pub fn claim_waived(env: Env, creator: Address, project_id: u64, token: Address) -> i128 {
+        creator.require_auth();
+
+        let config = storage::load_project_config(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        Self::require_payable(&env, &config);
+
+        let waived = storage::get_waived(&env, project_id, &token);
+        if waived <= 0 {
+            panic_with_error!(&env, Error::InsufficientBalance);
+        }
+        storage::set_waived(&env, project_id, &token, 0);
+
+        let payout = Self::collect_fee(&env, &config, &token, waived);
+        let recipient = config.payout_recipient();
+        Self::send_tokens(&env, &token, &recipient, payout);
+
+        events::emit_waived_claimed(&env, project_id, token, recipient, payout);
+        payout
+    }
+
+    /// Return the waived refunds of `token` the creator of `project_id` has
+    /// yet to claim.
+    pub fn get_waived(env: Env, project_id: u64, token: Address) -> i128 {
+        storage::get_waived(&env, project_id, &token)
+    }
+
+    /// Transfer the vested-but-unclaimed part of a released `token` balance
+    /// to the project's payout recipient and return the amount sent.
+    ///
+    /// Only applies to projects registered with a `vesting_duration_secs`.
+    /// Funds vest linearly from the release; once the full duration has
+    /// elapsed the remainder is claimable. Panics with `InvalidAmount` when
+    /// nothing is claimable and `NotAuthorized` if `creator` does not own the
+    /// project.
+    ///
+    /// Emits a `FundsReleased` event for the transferred amount.
+    pub fn claim_vested(env: Env, creator: Address, project_id: u64, token: Address) -> i128 {
+        creator.require_auth();
+
+        let config = storage::load_project_config(&env, project_id);
+        if creator != config.creator {
+            panic_with_error!(&env, Error::NotAuthorized);
+        }
+
+        Self::require_payable(&env, &config);
+
+        let mut schedule = match storage::get_vesting(&env, project_id, &token) {
+            Some(schedule) => schedule,
+            None => panic_with_error!(&env, Error::InvalidAmount),
+        };
+
+        let elapsed = env.ledger().timestamp() - schedule.released_at;
+        let vested = if elapsed >= config.vesting_duration_secs {
+            schedule.total
+        } else {
+            schedule
+                .total
+                .checked_mul(elapsed as i128)
+                .unwrap_or_else(|| panic_with_error!(&env, Error::Overflow))
+                / config.vesting_duration_secs as i128
+        };
+
+        let claimable = vested - schedule.claimed_so_far;
+        if claimable <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        schedule.claimed_so_far = vested;
+        storage::set_vesting(&env, project_id, &token, &schedule);
+
+        Self::send_tokens(&env, &token, &config.payout_recipient(), claimable);
+
+        events::emit_funds_released(&env, project_id, token, claimable);
+        claimable
+    }
+
+    /// Return the vesting schedule of a released `token` balance, if any.
+    pub fn get_vesting(env: Env, project_id: u64, token: Address) -> Option<VestingSchedule> {
+        storage::get_vesting(&env, project_id, &token)
+    }
+
+    /// Mark a project as expired if its deadline has passed.
+    ///
+    /// Permissionless: anyone can trigger expiration once the deadline is met.
+    /// - Panics if project is not in Funding status.
+    /// - Panics if deadline has not passed, including the deposit grace period.
+    pub fn expire_project(env: Env, project_id: u64) {
+        let (config, mut state) = load_project_pair(&env, project_id);
+
+        // State transition check: only Funding or Active projects can expire.
+        if !state.status.can_transition_to(&ProjectStatus::Expired) {
+            panic_with_error!(&env, Error::InvalidStateTransition);
+        }
+
+        // Deadline check, grace period included.
+        if env.ledger().timestamp() < Self::funding_ends(&env, config.deadline) {
+            panic_with_error!(&env, Error::ProjectNotExpired);
+        }
+
+        // Update status and save.
+        Self::transition(&env, &config, &mut state, ProjectStatus::Expired);
+        save_project_state(&env, project_id, &state);
+
+        // Standardized event emission.
+        events::emit_project_expired(&env, project_id, config.deadline);
+    }
+
+    /// Persist the expiry of a project whose deadline has passed.
+    ///
+    /// Permissionless, like `expire_project`, but a no-op instead of a panic
+    /// when the project is not due: returns `true` only if it moved a
+    /// `Funding` or `Active` project to `Expired`, emitting `ProjectExpired`.
+    pub fn touch_expired(env: Env, project_id: u64) -> bool {
+        let (config, mut state) = load_project_pair(&env, project_id);
+        Self::persist_expiry(&env, &config, &mut state)
+    }
+
+    /// Persist the expiry of every lapsed project in `project_ids`, for
+    /// keepers sweeping many projects in one transaction.
+    ///
+    /// Permissionless, like `expire_project`. At most 20 ids per call
+    /// (`BatchTooLarge`). Ids that are not due, already final, or do not
+    /// exist are skipped rather than failing the batch, so one stale id
+    /// cannot block the rest. Each expired project emits `ProjectExpired`;
+    /// returns the ids that were expired, in call order.
+    pub fn expire_projects(env: Env, project_ids: Vec<u64>) -> Vec<u64> {
+        if project_ids.len() > MAX_EXPIRE_BATCH {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+        let mut expired = Vec::new(&env);
+        for project_id in project_ids.iter() {
+            let config = storage::maybe_load_project_config(&env, project_id);
+            let state = storage::maybe_load_project_state(&env, project_id);
+            if let (Some(config), Some(mut state)) = (config, state) {
+                if Self::persist_expiry(&env, &config, &mut state) {
+                    expired.push_back(project_id);
+                }
+            }
+        }
+        expired
+    }
+
+    // ─────────────────────────────────────────────────────────
+    // Internal Helpers
+    // ─────────────────────────────────────────────────────────
+
+    /// Move a lapsed project to `Expired`, save it and emit
+    /// `ProjectExpired`; `false` if it was not due.
+    fn persist_expiry(env: &Env, config: &ProjectConfig, state: &mut ProjectState) -> bool {
+        if !Self::is_lapsed(env, config.deadline, &state.status) {
+            return false;
+        }
+
+        Self::transition(env, config, state, ProjectStatus::Expired);
+        save_project_state(env, config.id, state);
+        events::emit_project_expired(env, config.id, config.deadline);
+        true
+    }
+
+    fn require_not_paused(env: &Env) {
+        if storage::is_paused(env) {
+            panic_with_error!(env, Error::ProtocolPaused);
+        }
+    }
+
+    fn require_not_frozen(env: &Env, project_id: u64) {
+        if storage::is_frozen(env, project_id) {
+            panic_with_error!(env, Error::ProjectFrozen);
+        }
+    }
+
+    fn require_not_blocked(env: &Env, address: &Address) {
+        if storage::is_blocked(env, address) {
+            panic_with_error!(env, Error::AddressBlocked);
+        }
+    }
+
+    /// Panic with `AddressBlocked` if the project's creator or payout
+    /// address is on the denylist.
+    fn require_payable(env: &Env, config: &ProjectConfig) {
+        Self::require_not_blocked(env, &config.creator);
+        Self::require_not_blocked(env, &config.payout_recipient());
+    }
+
+    /// Shared body of the single-token deposit entry points.
+    fn deposit_single(
+        env: Env,
         project_id: u64,
-        submitted_proof_hash: BytesN<32>,
-    ) {
+        donator: Address,
+        token: Address,
+        amount: i128,
+        memo: Option<String>,
+        anonymous: bool,
+    ) -> u64 {
         Self::require_not_paused(&env);
-        oracle.require_auth();
-        // RBAC gate: caller must hold the Oracle role.
-        rbac::require_oracle(&env, &oracle);
+        donator.require_auth();
+        Self::require_not_blocked(&env, &donator);
+
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+
+        let (config, mut state) = Self::load_for_deposit(&env, project_id);
+        Self::require_accepted_token(&env, &config, &token);
+
+        let receipt_id = Self::apply_deposit(
+            &env, &config, &mut state, &donator, &token, amount, memo, anonymous, false,
+        );
+        save_project_state(&env, project_id, &state);
+        receipt_id
+    }
+
+    /// Load a project that is about to receive a deposit.
+    ///
+    /// Lazily expires the project if its deadline has passed (`ProjectExpired`)
+    /// and rejects projects that can no longer complete.
+    fn load_for_deposit(env: &Env, project_id: u64) -> (ProjectConfig, ProjectState) {
+        Self::require_not_frozen(env, project_id);
+
+        // Read both config and state with a single helper that bumps TTLs
+        // atomically. This is the optimized retrieval pattern; it also returns
+        // the state needed for the subsequent checks.
+        let (config, mut state) = load_project_pair(env, project_id);
+
+        // Check expiration; deposits stay open through the grace period.
+        if env.ledger().timestamp() >= Self::funding_ends(env, config.deadline) {
+            if state.status.can_transition_to(&ProjectStatus::Expired) {
+                Self::transition(env, &config, &mut state, ProjectStatus::Expired);
+                save_project_state(env, project_id, &state);
+            }
+            panic_with_error!(env, Error::ProjectExpired);
+        }
+
+        // Deposits are only accepted while the project is funding or active.
+        if !matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active) {
+            panic_with_error!(env, Error::InvalidStateTransition);
+        }
+
+        (config, state)
+    }
+
+    /// Panic with `TokenNotAllowed` if the protocol allowlist is non-empty
+    /// and does not contain `token`.
+    fn require_allowed_token(env: &Env, allowed_tokens: &Vec<Address>, token: &Address) {
+        if !allowed_tokens.is_empty() && !allowed_tokens.contains(token) {
+            panic_with_error!(env, Error::TokenNotAllowed);
+        }
+    }
+
+    /// Panic with `InvalidTokenContract` unless `token` answers `decimals()`.
+    ///
+    /// Catches accounts and non-token contracts at registration rather than
+    /// on the first deposit. Costs one cross-contract call per token.
+    fn require_token_contract(env: &Env, token: &Address) {
+        if !matches!(token::Client::new(env, token).try_decimals(), Ok(Ok(_))) {
+            panic_with_error!(env, Error::InvalidTokenContract);
+        }
+    }
+
+    /// Panic with `TokenNotAccepted` unless `token` is accepted by the project.
+    fn require_accepted_token(env: &Env, config: &ProjectConfig, token: &Address) {
+        if !config.accepted_tokens.contains(token) {
+            panic_with_error!(env, Error::TokenNotAccepted);
+        }
+    }
+
+    /// Move `amount` of `token` from `donator` into escrow and update the
+    /// in-memory `state`, returning the id of the receipt issued. The caller
+    /// is responsible for persisting `state`.
+    ///
+    /// With `anonymous`, the `ProjectFunded` event names the contract
+    /// instead of `donator`; everything stored still uses `donator`.
+    #[allow(clippy::too_many_arguments)]
+    fn apply_deposit(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        donator: &Address,
+        token: &Address,
+        amount: i128,
+        memo: Option<String>,
+        anonymous: bool,
+        pull: bool,
+    ) -> u64 {
+        let project_id = config.id;
+
+        if amount < storage::get_min_deposit(env, project_id) {
+            panic_with_error!(env, Error::BelowMinimumDeposit);
+        }
+
+        // Enforce the per-donor cap before any state changes or transfers.
+        if config.max_contribution > 0 {
+            let contributed = storage::get_donator_balance(env, project_id, token, donator);
+            match contributed.checked_add(amount) {
+                Some(total) if total <= config.max_contribution => {}
+                _ => panic_with_error!(env, Error::ContributionCapExceeded),
+            }
+        }
+
+        // Refuse to overshoot the goal before any funds move.
+        if storage::rejects_overfunding(env, project_id) {
+            if let Some(headroom) = Self::goal_headroom(env, config, token) {
+                if amount > headroom {
+                    panic_with_error!(env, Error::GoalExceeded);
+                }
+            }
+        }
+
+        // Check if this is a new unique (donator, token) pair.
+        if !storage::has_donator_seen(env, project_id, donator, token) {
+            state.donation_count += 1;
+            storage::mark_donator_seen(env, project_id, donator, token);
+        }
+        // Check if this donator is new to the project in any token.
+        if !storage::is_project_donor(env, project_id, donator) {
+            state.unique_donors += 1;
+            storage::mark_project_donor(env, project_id, donator);
+        }
+        state.total_deposited = match state.total_deposited.checked_add(amount) {
+            Some(total) => total,
+            None => panic_with_error!(env, Error::Overflow),
+        };
+
+        // Transfer tokens from donator to contract. Pledges spend the donor's
+        // allowance instead, with the contract as both spender and recipient.
+        let token_client = token::Client::new(env, token);
+        let contract = env.current_contract_address();
+        if pull {
+            token_client.transfer_from(&contract, donator, &contract, &amount);
+        } else {
+            token_client.transfer(donator, &contract, &amount);
+        }
+        storage::add_to_total_held(env, token, amount);
+
+        // Update the per-token balance and lifetime total.
+        storage::add_to_token_balance(env, project_id, token, amount);
+        let total_raised =
+            match storage::get_total_raised(env, project_id, token).checked_add(amount) {
+                Some(total) => total,
+                None => panic_with_error!(env, Error::Overflow),
+            };
+        storage::set_total_raised(env, project_id, token, total_raised);
+        storage::add_to_total_volume(env, token, amount);
+
+        let total_raised = Self::apply_match(env, config, state, token, amount, total_raised);
+
+        // Transition from Funding to Active once the goal is reached.
+        if state.status == ProjectStatus::Funding {
+            if let Some(total) = Self::goal_reached(env, config, token, total_raised) {
+                Self::transition(env, config, state, ProjectStatus::Active);
+                events::emit_project_active(env, project_id);
+                events::emit_goal_reached(env, project_id, total);
+            }
+        }
+
+        // Track per-donator refundable amount for this token.
+        storage::add_to_donator_balance(env, project_id, token, donator, amount);
+
+        // Standardized event emission
+        let published_donator = if anonymous {
+            env.current_contract_address()
+        } else {
+            donator.clone()
+        };
+        events::emit_project_funded(
+            env,
+            project_id,
+            token.clone(),
+            published_donator,
+            amount,
+            memo,
+            anonymous,
+            total_raised,
+            Self::token_goal(config, token),
+        );
+
+        let receipt = DonationReceipt {
+            id: storage::get_and_increment_receipt_id(env),
+            project_id,
+            donator: donator.clone(),
+            token: token.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        storage::add_receipt(env, &receipt);
+        receipt.id
+    }
+
+    /// Match a deposit of `amount` from the project's matching pool for
+    /// `token`, if any, and return the new lifetime total raised.
+    ///
+    /// The match is capped by the pool's remaining funds and, when the
+    /// project rejects overfunding, by what is left of the goal. Matched
+    /// funds move into escrow and are credited to the sponsor as if they
+    /// had deposited them, so they refund through the normal machinery.
+    fn apply_match(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        token: &Address,
+        amount: i128,
+        total_raised: i128,
+    ) -> i128 {
+        let project_id = config.id;
+        let mut pool = match storage::get_matching_pool(env, project_id, token) {
+            Some(pool) => pool,
+            None => return total_raised,
+        };
+
+        let mut matched = amount.min(pool.remaining);
+        if storage::rejects_overfunding(env, project_id) {
+            if let Some(headroom) = Self::goal_headroom(env, config, token) {
+                matched = matched.min(headroom);
+            }
+        }
+        if matched <= 0 {
+            return total_raised;
+        }
+
+        pool.remaining -= matched;
+        if pool.remaining == 0 {
+            storage::remove_matching_pool(env, project_id, token);
+        } else {
+            storage::set_matching_pool(env, project_id, token, &pool);
+        }
+
+        let sponsor = pool.sponsor;
+        if !storage::has_donator_seen(env, project_id, &sponsor, token) {
+            state.donation_count += 1;
+            storage::mark_donator_seen(env, project_id, &sponsor, token);
+        }
+        if !storage::is_project_donor(env, project_id, &sponsor) {
+            state.unique_donors += 1;
+            storage::mark_project_donor(env, project_id, &sponsor);
+        }
+        state.total_deposited = match state.total_deposited.checked_add(matched) {
+            Some(total) => total,
+            None => panic_with_error!(env, Error::Overflow),
+        };
+
+        storage::add_to_token_balance(env, project_id, token, matched);
+        storage::add_to_donator_balance(env, project_id, token, &sponsor, matched);
+        let total_raised = match total_raised.checked_add(matched) {
+            Some(total) => total,
+            None => panic_with_error!(env, Error::Overflow),
+        };
+        storage::set_total_raised(env, project_id, token, total_raised);
+        storage::add_to_total_volume(env, token, matched);
+
+        events::emit_donation_matched(env, project_id, token.clone(), sponsor, matched);
+        total_raised
+    }
+
+    /// Whether a deposit that raised `token`'s lifetime total to
+    /// `total_raised` meets the goal, and if so the total that met it.
+    ///
+    /// Single-goal projects count every accepted token towards the goal. With
+    /// per-token goals, the deposited token's own goal suffices unless
+    /// `require_all_goals` is set, in which case every goal must be met.
+    fn goal_reached(
+        env: &Env,
+        config: &ProjectConfig,
+        token: &Address,
+        total_raised: i128,
+    ) -> Option<i128> {
+        if config.token_goals.is_empty() {
+            let raised = Self::raised_towards_goal(env, config);
+            return (raised >= config.goal).then_some(raised);
+        }
+        let reached = if config.require_all_goals {
+            config
+                .token_goals
+                .iter()
+                .all(|(t, goal)| storage::get_total_raised(env, config.id, &t) >= goal)
+        } else {
+            config
+                .token_goals
+                .iter()
+                .any(|(t, goal)| t == *token && total_raised >= goal)
+        };
+        reached.then_some(total_raised)
+    }
+
+    /// Lifetime deposits of a single-goal project across all its accepted
+    /// tokens.
+    fn raised_towards_goal(env: &Env, config: &ProjectConfig) -> i128 {
+        config.accepted_tokens.iter().fold(0i128, |sum, token| {
+            sum.saturating_add(storage::get_total_raised(env, config.id, &token))
+        })
+    }
+
+    /// How much more `token` may raise before the goal it counts towards is
+    /// exceeded, or `None` if no goal caps it.
+    ///
+    /// A single goal caps the combined total of every accepted token; with
+    /// per-token goals each token is capped by its own goal.
+    fn goal_headroom(env: &Env, config: &ProjectConfig, token: &Address) -> Option<i128> {
+        if config.token_goals.is_empty() {
+            return Some(config.goal - Self::raised_towards_goal(env, config));
+        }
+        let goal = Self::token_goal(config, token);
+        (goal > 0).then(|| goal - storage::get_total_raised(env, config.id, token))
+    }
+
+    /// `raised` as basis points of `goal`, capped at 10_000.
+    fn progress_bps(raised: i128, goal: i128) -> u32 {
+        if raised >= goal {
+            return 10_000;
+        }
+        // raised < goal here, so the result fits below 10_000. Very large
+        // goals fall back to dividing the goal first to avoid overflow.
+        let bps = match raised.checked_mul(10_000) {
+            Some(scaled) => scaled / goal,
+            None => raised / (goal / 10_000),
+        };
+        bps as u32
+    }
+
+    /// Move `state` to `next`, panicking with `InvalidStateTransition` if
+    /// `types::can_transition` forbids it. The caller persists the state;
+    /// the `get_protocol_stats` counters are updated here, in the same invocation.
+    fn transition(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        next: ProjectStatus,
+    ) {
+        if !state.status.can_transition_to(&next) {
+            panic_with_error!(env, Error::InvalidStateTransition);
+        }
+        match next {
+            ProjectStatus::Completed => storage::record_completion(env),
+            ProjectStatus::Expired => storage::record_expiry(env),
+            _ => {}
+        }
+        // Final statuses have no way out, so each project is released from
+        // its creator's active count exactly once.
+        if matches!(
+            next,
+            ProjectStatus::Completed | ProjectStatus::Expired | ProjectStatus::Cancelled
+        ) {
+            let active = storage::get_active_project_count(env, &config.creator);
+            storage::set_active_project_count(env, &config.creator, active.saturating_sub(1));
+        }
+        storage::set_status(env, config.id, state, next);
+    }
+
+    /// Whether a project with `deadline` and `status` should be treated as
+    /// expired now.
+    fn is_lapsed(env: &Env, deadline: u64, status: &ProjectStatus) -> bool {
+        env.ledger().timestamp() >= Self::funding_ends(env, deadline)
+            && status.can_transition_to(&ProjectStatus::Expired)
+    }
+
+    /// When a project with `deadline` stops taking deposits and expires: the
+    /// deadline plus the protocol-wide deposit grace period.
+    fn funding_ends(env: &Env, deadline: u64) -> u64 {
+        deadline.saturating_add(storage::get_grace_period(env))
+    }
+
+    /// Goal of `token` within a project; 0 when the token carries none.
+    fn token_goal(config: &ProjectConfig, token: &Address) -> i128 {
+        if config.token_goals.is_empty() {
+            return if config.accepted_tokens.get(0).as_ref() == Some(token) {
+                config.goal
+            } else {
+                0
+            };
+        }
+        config
+            .token_goals
+            .iter()
+            .find(|(t, _)| t == token)
+            .map_or(0, |(_, goal)| goal)
+    }
+
+    /// Record `oracle`'s vote for `proof_hash` and return all votes.
+    ///
+    /// Votes are final: repeating one is a no-op and a vote for a different
+    /// hash panics with `AlreadyVoted`.
+    fn record_vote(
+        env: &Env,
+        project_id: u64,
+        oracle: &Address,
+        proof_hash: &BytesN<32>,
+    ) -> Map<Address, BytesN<32>> {
+        let mut votes = storage::get_votes(env, project_id);
+        match votes.get(oracle.clone()) {
+            Some(voted) if voted == *proof_hash => {}
+            Some(_) => panic_with_error!(env, Error::AlreadyVoted),
+            None => {
+                votes.set(oracle.clone(), proof_hash.clone());
+                storage::set_votes(env, project_id, &votes);
+            }
+        }
+        votes
+    }
+
+    /// Whether `oracle` may verify the project: the assigned oracle if there
+    /// is one, otherwise any holder of the global Oracle role.
+    fn may_verify(env: &Env, config: &ProjectConfig, oracle: &Address) -> bool {
+        match &config.assigned_oracle {
+            Some(assigned) => oracle == assigned,
+            None => rbac::has_role(env, oracle.clone(), Role::Oracle),
+        }
+    }
+
+    /// Number of `votes` for `proof_hash` cast by addresses that may still
+    /// verify the project.
+    fn count_votes(
+        env: &Env,
+        config: &ProjectConfig,
+        votes: &Map<Address, BytesN<32>>,
+        proof_hash: &BytesN<32>,
+    ) -> u32 {
+        let mut matching: u32 = 0;
+        for (voter, hash) in votes.iter() {
+            if hash == *proof_hash && Self::may_verify(env, config, &voter) {
+                matching += 1;
+            }
+        }
+        matching
+    }
+
+    /// Panic with `NotAuthorized` unless `oracle` may verify the project.
+    fn require_project_oracle(env: &Env, config: &ProjectConfig, oracle: &Address) {
+        if !Self::may_verify(env, config, oracle) {
+            panic_with_error!(env, Error::NotAuthorized);
+        }
+    }
+
+    /// Matching votes needed to release the project.
+    ///
+    /// An assigned oracle is the only address that may verify its project,
+    /// so its single vote is the quorum; other projects need the protocol's
+    /// quorum threshold.
+    fn required_votes(env: &Env, config: &ProjectConfig) -> u32 {
+        if config.assigned_oracle.is_some() {
+            1
+        } else {
+            storage::get_quorum_threshold(env)
+        }
+    }
+
+    /// Load a project that `oracle` is about to verify.
+    ///
+    /// Checks the oracle gate, lazily expires the project if its deadline has
+    /// passed (`ProjectExpired`) and rejects projects that cannot complete.
+    fn load_for_verification(
+        env: &Env,
+        project_id: u64,
+        oracle: &Address,
+    ) -> (ProjectConfig, ProjectState) {
+        Self::require_not_frozen(env, project_id);
 
         // Optimised dual-read helper
-        let (config, mut state) = load_project_pair(&env, project_id);
+        let (config, state) = load_project_pair(env, project_id);
+        Self::require_project_oracle(env, &config, oracle);
 
+        // Verification is strict at the deadline, even while the deposit
+        // grace period keeps the project open to deposits.
         if env.ledger().timestamp() >= config.deadline
-            && matches!(state.status, ProjectStatus::Funding | ProjectStatus::Active)
+            && state.status.can_transition_to(&ProjectStatus::Expired)
         {
-            state.status = ProjectStatus::Expired;
-            save_project_state(&env, project_id, &state);
-            panic_with_error!(&env, Error::ProjectExpired);
+            panic_with_error!(env, Error::ProjectExpired);
+        }
+
+        // Ensure the project is in a verifiable state. A second release keeps
+        // its dedicated error so callers can tell it apart from other misuse.
+        if state.status == ProjectStatus::Completed {
+            panic_with_error!(env, Error::MilestoneAlreadyReleased);
         }
+        if !state.status.can_transition_to(&ProjectStatus::Verified) {
+            panic_with_error!(env, Error::InvalidStateTransition);
+        }
+
+        (config, state)
+    }
 
-        // Ensure the project is in a verifiable state.
-        match state.status {
-            ProjectStatus::Funding | ProjectStatus::Active => {}
-            ProjectStatus::Completed => panic_with_error!(&env, Error::MilestoneAlreadyReleased),
-            ProjectStatus::Expired => panic_with_error!(&env, Error::ProjectExpired),
+    /// Panic unless a single oracle may release the project on its own.
+    ///
+    /// Rejects projects that need an admin co-signature (`CosignRequired`) and
+    /// projects that need more than one vote (`QuorumNotReached`).
+    fn require_direct_release(env: &Env, config: &ProjectConfig) {
+        if config.requires_admin_cosign {
+            panic_with_error!(env, Error::CosignRequired);
         }
+        if Self::required_votes(env, config) > 1 {
+            panic_with_error!(env, Error::QuorumNotReached);
+        }
+    }
+
+    /// Check `proof_hash`, then pay out every token balance and mark the
+    /// project `Completed`, or mark it `Verified` and schedule the payout
+    /// when the project has a release delay.
+    fn release(
+        env: &Env,
+        config: &ProjectConfig,
+        state: &mut ProjectState,
+        oracle: &Address,
+        proof_hash: BytesN<32>,
+    ) {
+        let project_id = config.id;
 
         // Mocked ZK verification: compare submitted hash to stored hash.
-        if submitted_proof_hash != config.proof_hash {
-            panic_with_error!(&env, Error::VerificationFailed);
+        if proof_hash != config.proof_hash {
+            panic_with_error!(env, Error::VerificationFailed);
         }
 
-        // Transition to Completed — only write the state entry.
-        state.status = ProjectStatus::Completed;
+        let delay = Self::release_delay(env, config);
+        if delay > 0 {
+            let release_after = env
+                .ledger()
+                .timestamp()
+                .checked_add(delay)
+                .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+            Self::transition(env, config, state, ProjectStatus::Verified);
+            storage::set_release_after(env, project_id, release_after);
+            save_project_state(env, project_id, state);
+
+            events::emit_project_verified(env, project_id, oracle.clone(), proof_hash);
+            events::emit_release_scheduled(env, project_id, release_after);
+            return;
+        }
 
-        // Transfer all deposited tokens to the creator.
-        // If any transfer fails, panic to revert the entire transaction.
-        let contract_address = env.current_contract_address();
-        for token in config.accepted_tokens.iter() {
-            // Drain the token balance (gets balance and zeros it).
-            let balance = drain_token_balance(&env, project_id, &token);
+        // Checks-effects-interactions: persist `Completed` before any token
+        // call, so a re-entrant release sees `MilestoneAlreadyReleased`.
+        Self::transition(env, config, state, ProjectStatus::Completed);
+        save_project_state(env, project_id, state);
+
+        Self::disburse(env, config);
+
+        // Standardized event emission
+        events::emit_project_verified(env, project_id, oracle.clone(), proof_hash);
+    }
+
+    /// Delay between verification and payout: the stricter of the project's
+    /// own delay and the protocol-wide one.
+    fn release_delay(env: &Env, config: &ProjectConfig) -> u64 {
+        config.release_delay.max(storage::get_release_delay(env))
+    }
 
+    /// Pay out a `Verified` project whose release delay has elapsed and mark
+    /// it `Completed`.
+    fn complete_release(env: &Env, project_id: u64) {
+        Self::require_not_frozen(env, project_id);
+        let (config, mut state) = load_project_pair(env, project_id);
+        if state.status != ProjectStatus::Verified {
+            panic_with_error!(env, Error::InvalidStateTransition);
+        }
+        let release_after = storage::get_release_after(env, project_id).unwrap_or(0);
+        if env.ledger().timestamp() < release_after {
+            panic_with_error!(env, Error::ReleaseLocked);
+        }
+
+        // Effects before interactions, as in `release`.
+        Self::transition(env, &config, &mut state, ProjectStatus::Completed);
+        save_project_state(env, project_id, &state);
+        storage::remove_release_after(env, project_id);
+
+        Self::disburse(env, &config);
+    }
+
+    /// Drain every token balance of a project to its creator, minus the
+    /// protocol fee, or into vesting schedules for vesting projects, and
+    /// return the creator's bond.
+    ///
+    /// For `refund_excess` projects, whatever was raised above a token's goal
+    /// stays in escrow for donors to reclaim through `refund`.
+    ///
+    /// All balances are zeroed before the first token is called.
+    fn disburse(env: &Env, config: &ProjectConfig) {
+        Self::require_payable(env, config);
+        let project_id = config.id;
+        let refund_excess = storage::refunds_excess(env, project_id);
+
+        // Drain every token balance (gets balance and zeros it) up front.
+        let mut drained: Vec<(Address, i128)> = Vec::new(env);
+        for token in config.accepted_tokens.iter() {
+            let mut balance = drain_token_balance(env, project_id, &token);
+            if refund_excess {
+                balance -= Self::set_aside_excess(env, config, &token, balance);
+            }
             // Only transfer if there's a non-zero balance.
             if balance > 0 {
-                // Create token client and transfer to creator.
-                let token_client = token::Client::new(&env, &token);
-                token_client.transfer(&contract_address, &config.creator, &balance);
+                drained.push_back((token, balance));
+            }
+        }
 
-                // Emit funds_released event for this token.
-                events::emit_funds_released(&env, project_id, token, balance);
+        // If any transfer fails, panic to revert the entire transaction.
+        for (token, balance) in drained.iter() {
+            if config.vesting_duration_secs > 0 {
+                Self::start_vesting(env, config, &token, balance);
+            } else {
+                Self::pay_out(env, config, &token, balance);
             }
         }
 
-        // Save the updated state (now marked as Completed).
-        save_project_state(&env, project_id, &state);
+        Self::return_bond(env, config);
+    }
 
-        // Standardized event emission
-        events::emit_project_verified(&env, project_id, oracle.clone(), submitted_proof_hash);
+    /// Load a project about to be refunded, persisting its expiry first if
+    /// the deadline has passed.
+    fn load_refundable_pair(env: &Env, project_id: u64) -> (ProjectConfig, ProjectState) {
+        Self::require_not_frozen(env, project_id);
+        let (config, mut state) = load_project_pair(env, project_id);
+        if Self::is_lapsed(env, config.deadline, &state.status) {
+            Self::transition(env, &config, &mut state, ProjectStatus::Expired);
+            save_project_state(env, project_id, &state);
+        }
+        (config, state)
     }
 
-    /// Mark a project as expired if its deadline has passed.
+    /// Assert that donors may reclaim their contributions.
+    fn require_refunds_open(env: &Env, state: &ProjectState) {
+        if !matches!(
+            state.status,
+            ProjectStatus::Expired | ProjectStatus::Cancelled
+        ) {
+            panic_with_error!(env, Error::ProjectNotExpired);
+        }
+    }
+
+    /// Refund `donator`'s recorded balance of `token` to `recipient`, or
+    /// their share of `excess` when given.
     ///
-    /// Permissionless: anyone can trigger expiration once the deadline is met.
-    /// - Panics if project is not in Funding status.
-    /// - Panics if deadline has not passed.
-    pub fn expire_project(env: Env, project_id: u64) {
-        let (config, mut state) = load_project_pair(&env, project_id);
+    /// A matching pool sponsor also gets back whatever is left of their
+    /// pool for `token`, in the same transfer.
+    ///
+    /// Returns the amount paid, or `None` if the donator has nothing to
+    /// refund in `token`.
+    fn refund_token(
+        env: &Env,
+        project_id: u64,
+        token: &Address,
+        donator: &Address,
+        recipient: &Address,
+        excess: Option<ExcessRefund>,
+    ) -> Option<i128> {
+        if let Some(excess) = excess {
+            return Self::refund_excess_share(env, project_id, token, donator, recipient, excess);
+        }
 
-        // State transition check: only Funding or Active projects can expire.
-        // Completed projects cannot be expired.
-        match state.status {
-            ProjectStatus::Funding | ProjectStatus::Active => {}
-            _ => panic_with_error!(&env, Error::InvalidTransition),
+        let contributed = storage::get_donator_balance(env, project_id, token, donator);
+        let escrow_refund = Self::refund_share(env, project_id, token, contributed);
+        let unspent_pool = Self::take_matching_pool(env, project_id, token, donator);
+        let refund_amount = escrow_refund + unspent_pool;
+        if refund_amount <= 0 {
+            return None;
         }
 
-        // Deadline check.
-        if env.ledger().timestamp() < config.deadline {
-            panic_with_error!(&env, Error::ProjectNotExpired);
+        // Zero-out first to prevent double-refund/reentrancy patterns.
+        storage::set_donator_balance(env, project_id, token, donator, 0);
+        storage::add_to_token_balance(env, project_id, token, -escrow_refund);
+
+        Self::send_tokens(env, token, recipient, refund_amount);
+
+        events::emit_refunded(
+            env,
+            project_id,
+            token.clone(),
+            donator.clone(),
+            refund_amount,
+            recipient.clone(),
+        );
+        storage::record_refund(env);
+        Some(refund_amount)
+    }
+
+    /// Part of a donor's `contributed` balance of `token` that an expired or
+    /// cancelled project still holds for them.
+    ///
+    /// After a partial release the escrow no longer covers every
+    /// contribution. The first refund snapshots what is left against all
+    /// contributions, and each donor then gets back
+    /// `contributed * remaining / total contributed` whatever order they
+    /// claim in, floored against the running total as in
+    /// `refund_excess_share`.
+    fn refund_share(env: &Env, project_id: u64, token: &Address, contributed: i128) -> i128 {
+        let escrowed = storage::get_token_balance(env, project_id, token);
+        if contributed <= 0 || !storage::is_partially_released(env, project_id, token) {
+            return contributed.min(escrowed).max(0);
         }
 
-        // Update status and save.
-        state.status = ProjectStatus::Expired;
-        save_project_state(&env, project_id, &state);
+        let mut shortfall =
+            storage::get_shortfall_refund(env, project_id, token).unwrap_or(ExcessRefund {
+                excess: escrowed,
+                basis: storage::get_total_raised(env, project_id, token),
+                claimed_basis: 0,
+            });
+        let claimed_before = Self::excess_share_of(env, &shortfall, shortfall.claimed_basis);
+        shortfall.claimed_basis = shortfall
+            .claimed_basis
+            .checked_add(contributed)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+        let claimed_after = Self::excess_share_of(env, &shortfall, shortfall.claimed_basis);
+        let share = (claimed_after - claimed_before).min(escrowed);
+        if share > 0 {
+            storage::set_shortfall_refund(env, project_id, token, &shortfall);
+        }
+        share
+    }
 
-        // Standardized event emission.
-        events::emit_project_expired(&env, project_id, config.deadline);
+    /// Remove and return the unspent matching pool for `token` if `sponsor`
+    /// funded it, or 0.
+    fn take_matching_pool(env: &Env, project_id: u64, token: &Address, sponsor: &Address) -> i128 {
+        match storage::get_matching_pool(env, project_id, token) {
+            Some(pool) if pool.sponsor == *sponsor => {
+                storage::remove_matching_pool(env, project_id, token);
+                pool.remaining
+            }
+            _ => 0,
+        }
     }
 
-    // ─────────────────────────────────────────────────────────
-    // Internal Helpers
-    // ─────────────────────────────────────────────────────────
+    /// Pay `donator` their pro-rata share of the excess left in escrow by a
+    /// `refund_excess` release; `None` if they contributed nothing.
+    ///
+    /// Shares are floored against the running total of claimed
+    /// contributions, so the shares of all donors add up to exactly the
+    /// excess whatever order they claim in.
+    fn refund_excess_share(
+        env: &Env,
+        project_id: u64,
+        token: &Address,
+        donator: &Address,
+        recipient: &Address,
+        mut excess: ExcessRefund,
+    ) -> Option<i128> {
+        let contributed = storage::get_donator_balance(env, project_id, token, donator);
+        if contributed <= 0 {
+            return None;
+        }
 
-    fn require_not_paused(env: &Env) {
-        if storage::is_paused(env) {
-            panic_with_error!(env, Error::ProtocolPaused);
+        let claimed_before = Self::excess_share_of(env, &excess, excess.claimed_basis);
+        excess.claimed_basis = excess
+            .claimed_basis
+            .checked_add(contributed)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+        let claimed_after = Self::excess_share_of(env, &excess, excess.claimed_basis);
+        let escrowed = storage::get_token_balance(env, project_id, token);
+        let refund_amount = (claimed_after - claimed_before).min(escrowed);
+
+        // Zero-out first, as in `refund_to`.
+        storage::set_donator_balance(env, project_id, token, donator, 0);
+        storage::set_excess_refund(env, project_id, token, &excess);
+        if refund_amount <= 0 {
+            return Some(0);
+        }
+        storage::add_to_token_balance(env, project_id, token, -refund_amount);
+
+        Self::send_tokens(env, token, recipient, refund_amount);
+
+        events::emit_refunded(
+            env,
+            project_id,
+            token.clone(),
+            donator.clone(),
+            refund_amount,
+            recipient.clone(),
+        );
+        storage::record_refund(env);
+        Some(refund_amount)
+    }
+
+    /// Part of the excess owed to donors whose contributions add up to `basis`.
+    fn excess_share_of(env: &Env, excess: &ExcessRefund, basis: i128) -> i128 {
+        let basis = basis.min(excess.basis);
+        excess
+            .excess
+            .checked_mul(basis)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+            / excess.basis
+    }
+
+    /// Keep what was raised in `token` above its goal, up to the drained
+    /// `balance`, in escrow for donors and return the amount kept.
+    ///
+    /// Tokens without a goal are paid out in full.
+    fn set_aside_excess(env: &Env, config: &ProjectConfig, token: &Address, balance: i128) -> i128 {
+        let goal = Self::token_goal(config, token);
+        let total_raised = storage::get_total_raised(env, config.id, token);
+        if goal <= 0 || total_raised <= goal || balance <= 0 {
+            return 0;
+        }
+
+        let excess = (total_raised - goal).min(balance);
+        storage::set_token_balance(env, config.id, token, excess);
+        storage::set_excess_refund(
+            env,
+            config.id,
+            token,
+            &ExcessRefund {
+                excess,
+                basis: total_raised,
+                claimed_basis: 0,
+            },
+        );
+        excess
+    }
+
+    /// Hand the creator's bond back, if one is still held.
+    fn return_bond(env: &Env, config: &ProjectConfig) {
+        if let Some(bond) = storage::take_project_bond(env, config.id) {
+            Self::send_tokens(env, &bond.token, &config.creator, bond.amount);
+            events::emit_bond_returned(
+                env,
+                config.id,
+                config.creator.clone(),
+                bond.token,
+                bond.amount,
+            );
+        }
+    }
+
+    /// Transfer `amount` of `token` out of the contract to `to`, no longer
+    /// counting it as held.
+    ///
+    /// Every payout of tracked funds goes through here; only
+    /// `sweep_untracked`, whose surplus was never counted, transfers directly.
+    fn send_tokens(env: &Env, token: &Address, to: &Address, amount: i128) {
+        storage::sub_from_total_held(env, token, amount);
+        token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+    }
+
+    /// Transfer `amount` of already-debited escrow to the payout recipient,
+    /// minus the protocol fee.
+    fn pay_out(env: &Env, config: &ProjectConfig, token: &Address, amount: i128) {
+        let payout = Self::collect_fee(env, config, token, amount);
+
+        // Transfer the remainder to the payout recipient.
+        Self::send_tokens(env, token, &config.payout_recipient(), payout);
+
+        // Emit funds_released event for this token.
+        events::emit_funds_released(env, config.id, token.clone(), payout);
+    }
+
+    /// Take the protocol fee from `amount` of already-debited escrow and
+    /// start vesting the remainder to the creator from now.
+    fn start_vesting(env: &Env, config: &ProjectConfig, token: &Address, amount: i128) {
+        let total = Self::collect_fee(env, config, token, amount);
+        let schedule = VestingSchedule {
+            total,
+            claimed_so_far: 0,
+            released_at: env.ledger().timestamp(),
+        };
+        storage::set_vesting(env, config.id, token, &schedule);
+    }
+
+    /// Move the protocol fee on `amount` into the fee treasury and return
+    /// what is left for the creator. The tokens stay in the contract.
+    fn collect_fee(env: &Env, config: &ProjectConfig, token: &Address, amount: i128) -> i128 {
+        // Floor division keeps rounding in the creator's favour.
+        let fee = amount
+            .checked_mul(storage::get_fee_bps(env) as i128)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow))
+            / BPS_DENOMINATOR;
+        if fee > 0 {
+            storage::add_to_fee_balance(env, token, fee);
+            events::emit_fee_collected(env, config.id, token.clone(), fee);
+        }
+        amount - fee
+    }
+
+    /// Assert that a project is still `Funding` and has never received a deposit.
+    ///
+    /// `donation_count` is bumped on the first deposit of every
+    /// (donator, token) pair, so zero means nothing has been deposited.
+    fn require_untouched(env: &Env, state: &ProjectState) {
+        if state.status != ProjectStatus::Funding || state.donation_count > 0 {
+            panic_with_error!(env, Error::InvalidStateTransition);
+        }
+    }
+
+    /// Panic with `TooManyTokens` unless `max` is a valid token cap.
+    fn require_token_cap_in_range(env: &Env, max: u32) {
+        if max == 0 || max > MAX_ACCEPTED_TOKENS_LIMIT {
+            panic_with_error!(env, Error::TooManyTokens);
+        }
+    }
+
+    /// Stored registration limits, or the built-in defaults when unset.
+    fn protocol_params(env: &Env) -> ProtocolParams {
+        storage::get_protocol_params(env).unwrap_or(ProtocolParams {
+            max_accepted_tokens: DEFAULT_MAX_ACCEPTED_TOKENS,
+            max_goal: DEFAULT_MAX_GOAL,
+            max_project_duration_secs: DEFAULT_MAX_DEADLINE_OFFSET,
+        })
+    }
+
+    /// Latest deadline a project may have when measured from `now`.
+    fn max_deadline(env: &Env, now: u64) -> u64 {
+        let max_offset = Self::protocol_params(env).max_project_duration_secs;
+        match now.checked_add(max_offset) {
+            Some(max) => max,
+            None => panic_with_error!(env, Error::DeadlineOverflow),
         }
     }
 }
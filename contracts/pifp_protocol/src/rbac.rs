@@ -7,27 +7,35 @@
 //!     ├── Admin
 //!     ├── Oracle
 //!     ├── Auditor
-//!     └── ProjectManager
+//!     ├── ProjectManager
+//!     └── Treasurer
 //! ```
 //!
 //! ## Storage layout
 //!
 //! - `RbacKey::SuperAdmin` → `Address`  — the one and only super-admin.
 //! - `RbacKey::Role(addr)` → `Role`     — the role held by `addr`, if any.
+//! - `RbacKey::Holders(role)` → `Vec<Address>` — holders of `role`, in
+//!   insertion order; kept in sync with `RbacKey::Role` on every mutation.
+//! - `RbacKey::OracleHistory` → `Vec<OracleGranted>` — the last
+//!   `MAX_ORACLE_HISTORY` Oracle grants, oldest first.
 //!
 //! ## Event emissions
 //!
 //! Every mutation emits an on-chain event so that off-chain indexers can
-//! reconstruct a complete audit trail without storing membership lists on-chain:
+//! reconstruct a complete audit trail:
 //!
 //! | Event topic prefix | Trigger |
 //! |--------------------|---------|
-//! | `role_set`         | Role granted or replaced |
-//! | `role_del`         | Role revoked |
+//! | `role_set`         | Role granted or replaced (`RoleGranted`) |
+//! | `role_del`         | Role revoked (`RoleRevoked`) |
+//! | `sa_xfer`          | SuperAdmin transferred (`SuperAdminTransferred`) |
+//! | `orc_grant`        | Oracle role granted (with granter and ledger) |
 //!
 //! ## Threat model notes
 //!
 //! - `Admin` cannot escalate to `SuperAdmin` — only `SuperAdmin` may grant that role.
+//! - Only `SuperAdmin` may grant `Treasurer`, which controls protocol fee withdrawals.
 //! - `SuperAdmin` cannot be removed via `revoke_role`; use `transfer_super_admin`.
 //! - An address holds **at most one role** at a time; granting a new role replaces the old one.
 
@@ -35,6 +43,7 @@
 
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
 
+use crate::events::{self, OracleGranted};
 use crate::Error;
 
 // ─────────────────────────────────────────────────────────
@@ -54,10 +63,12 @@ pub enum Role {
     Admin,
     /// Can call `verify_and_release`; replaces the single oracle address.
     Oracle,
-    /// Read-only observer; confirmed by off-chain checks rather than on-chain gates.
+    /// Read-only observer; may call the authenticated `audit_*` queries.
     Auditor,
     /// Can call `register_project`; restricted to managing their own projects.
     ProjectManager,
+    /// Can withdraw accumulated protocol fees; granted by SuperAdmin only.
+    Treasurer,
 }
 
 // ─────────────────────────────────────────────────────────
@@ -71,26 +82,74 @@ pub enum RbacKey {
     Role(Address),
     /// The one and only SuperAdmin address.
     SuperAdmin,
+    /// Maps a role → every address currently holding it, in insertion order.
+    Holders(Role),
+    /// The most recent Oracle grants, oldest first.
+    OracleHistory,
 }
 
+/// Number of Oracle grants kept in `RbacKey::OracleHistory`.
+pub const MAX_ORACLE_HISTORY: u32 = 10;
+
+/// Maximum number of addresses returned by `list_role_holders`.
+pub const MAX_LISTED_HOLDERS: u32 = 100;
+
 // ─────────────────────────────────────────────────────────
 // Storage helpers (private)
 // ─────────────────────────────────────────────────────────
 
-/// Persist a role assignment. Overwrites any existing role.
+/// Persist a role assignment. Overwrites any existing role and moves
+/// `address` from the previous role's holder index to the new one.
 fn store_role(env: &Env, address: &Address, role: &Role) {
+    match get_role(env, address) {
+        Some(ref prev) if prev == role => return,
+        Some(prev) => remove_holder(env, &prev, address),
+        None => {}
+    }
     env.storage()
         .persistent()
         .set(&RbacKey::Role(address.clone()), role);
+    add_holder(env, role, address);
 }
 
-/// Remove any role stored for `address`.
+/// Remove any role stored for `address`, dropping it from the holder index.
 fn clear_role(env: &Env, address: &Address) {
+    if let Some(prev) = get_role(env, address) {
+        remove_holder(env, &prev, address);
+    }
     env.storage()
         .persistent()
         .remove(&RbacKey::Role(address.clone()));
 }
 
+/// Read every holder of `role`, in insertion order.
+fn get_holders(env: &Env, role: &Role) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&RbacKey::Holders(role.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append `address` to the holder index of `role`.
+fn add_holder(env: &Env, role: &Role, address: &Address) {
+    let mut holders = get_holders(env, role);
+    holders.push_back(address.clone());
+    env.storage()
+        .persistent()
+        .set(&RbacKey::Holders(role.clone()), &holders);
+}
+
+/// Remove `address` from the holder index of `role`, preserving order.
+fn remove_holder(env: &Env, role: &Role, address: &Address) {
+    let mut holders = get_holders(env, role);
+    if let Some(index) = holders.first_index_of(address) {
+        holders.remove(index);
+        env.storage()
+            .persistent()
+            .set(&RbacKey::Holders(role.clone()), &holders);
+    }
+}
+
 /// Read the role for `address`, returning `None` if unassigned.
 pub fn get_role(env: &Env, address: &Address) -> Option<Role> {
     env.storage()
@@ -118,13 +177,7 @@ pub fn init_super_admin(env: &Env, super_admin: &Address) {
         .set(&RbacKey::SuperAdmin, super_admin);
     store_role(env, super_admin, &Role::SuperAdmin);
 
-    emit(
-        env,
-        symbol_short!("role_set"),
-        super_admin,
-        &Role::SuperAdmin,
-        None::<Address>,
-    );
+    events::emit_role_granted(env, None, super_admin.clone(), Role::SuperAdmin);
 }
 
 // ─────────────────────────────────────────────────────────
@@ -134,16 +187,17 @@ pub fn init_super_admin(env: &Env, super_admin: &Address) {
 /// Grant `role` to `target`.
 ///
 /// - `caller` must hold `SuperAdmin` or `Admin`.
-/// - `Admin` callers cannot grant `SuperAdmin` — only SuperAdmin can elevate.
+/// - `Admin` callers cannot grant `SuperAdmin` or `Treasurer` — only SuperAdmin can.
 /// - Assigning a role to an address that already has one replaces it.
 ///
-/// Emits a `role_set` event.
+/// Emits a `role_set` event, plus an `orc_grant` event recorded in the
+/// oracle history when `role` is `Oracle`.
 pub fn grant_role(env: &Env, caller: &Address, target: &Address, role: Role) {
     let caller_role = get_role(env, caller);
 
     match &role {
-        // Only SuperAdmin can grant SuperAdmin
-        Role::SuperAdmin => {
+        // Only SuperAdmin can grant SuperAdmin or Treasurer
+        Role::SuperAdmin | Role::Treasurer => {
             require_role(env, caller, &Role::SuperAdmin);
         }
         // Admin or SuperAdmin can grant everything else
@@ -160,13 +214,12 @@ pub fn grant_role(env: &Env, caller: &Address, target: &Address, role: Role) {
     }
 
     store_role(env, target, &role);
-    emit(
-        env,
-        symbol_short!("role_set"),
-        target,
-        &role,
-        Some(caller.clone()),
-    );
+    let is_oracle = role == Role::Oracle;
+    events::emit_role_granted(env, Some(caller.clone()), target.clone(), role);
+
+    if is_oracle {
+        record_oracle_grant(env, caller, target);
+    }
 }
 
 /// Revoke any role from `target`.
@@ -187,8 +240,27 @@ pub fn revoke_role(env: &Env, caller: &Address, target: &Address) {
 
     if get_role(env, target).is_some() {
         clear_role(env, target);
-        emit_revoke(env, target, Some(caller.clone()));
+        events::emit_role_revoked(env, caller.clone(), target.clone());
+    }
+}
+
+/// Revoke the Oracle role from `oracle`.
+///
+/// - `caller` must hold `SuperAdmin` or `Admin`.
+/// - `oracle` must currently hold `Oracle`; any other role (or none) panics
+///   with `RoleNotFound`, so this path can never clear a different role.
+///
+/// Because an address holds at most one role, `oracle` is left roleless.
+/// Emits a `role_del` event.
+pub fn revoke_oracle(env: &Env, caller: &Address, oracle: &Address) {
+    require_admin_or_above(env, caller);
+
+    if get_role(env, oracle) != Some(Role::Oracle) {
+        panic_with_error_rbac(env, Error::RoleNotFound);
     }
+
+    clear_role(env, oracle);
+    events::emit_role_revoked(env, caller.clone(), oracle.clone());
 }
 
 /// Transfer the SuperAdmin role to a new address.
@@ -197,24 +269,20 @@ pub fn revoke_role(env: &Env, caller: &Address, target: &Address) {
 /// - `new_super_admin` is granted the `SuperAdmin` role.
 /// - The old SuperAdmin loses the `SuperAdmin` role automatically.
 ///
-/// This is the only way to remove a SuperAdmin.
+/// This is the only way to remove a SuperAdmin. Emits `role_del` for the
+/// old address, `role_set` for the new one, then `sa_xfer`.
 pub fn transfer_super_admin(env: &Env, current: &Address, new: &Address) {
     require_role(env, current, &Role::SuperAdmin);
 
     // Clear old SuperAdmin
     clear_role(env, current);
-    emit_revoke(env, current, Some(current.clone()));
+    events::emit_role_revoked(env, current.clone(), current.clone());
 
     // Set new SuperAdmin
     env.storage().persistent().set(&RbacKey::SuperAdmin, new);
     store_role(env, new, &Role::SuperAdmin);
-    emit(
-        env,
-        symbol_short!("role_set"),
-        new,
-        &Role::SuperAdmin,
-        Some(current.clone()),
-    );
+    events::emit_role_granted(env, Some(current.clone()), new.clone(), Role::SuperAdmin);
+    events::emit_super_admin_transferred(env, current.clone(), new.clone());
 }
 
 // ─────────────────────────────────────────────────────────
@@ -255,6 +323,13 @@ pub fn require_oracle(env: &Env, address: &Address) {
     require_role(env, address, &Role::Oracle);
 }
 
+/// Assert that `address` holds the Auditor role.
+/// Used to gate the `audit_*` queries.
+#[inline]
+pub fn require_auditor(env: &Env, address: &Address) {
+    require_role(env, address, &Role::Auditor);
+}
+
 /// Assert that `address` may register and manage projects.
 /// ProjectManager, Admin, and SuperAdmin may all register projects.
 #[inline]
@@ -280,32 +355,58 @@ pub fn has_role(env: &Env, address: Address, role: Role) -> bool {
     get_role(env, &address).map(|r| r == role).unwrap_or(false)
 }
 
+/// Returns the last `MAX_ORACLE_HISTORY` Oracle grants, oldest first.
+pub fn get_oracle_history(env: &Env) -> Vec<OracleGranted> {
+    env.storage()
+        .persistent()
+        .get(&RbacKey::OracleHistory)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Returns up to `MAX_LISTED_HOLDERS` holders of `role`, in the order they
+/// were granted it.
+pub fn list_role_holders(env: &Env, role: Role) -> Vec<Address> {
+    let holders = get_holders(env, &role);
+    if holders.len() > MAX_LISTED_HOLDERS {
+        holders.slice(0..MAX_LISTED_HOLDERS)
+    } else {
+        holders
+    }
+}
+
 // ─────────────────────────────────────────────────────────
 // Internal helpers
 // ─────────────────────────────────────────────────────────
 
-/// Emit a role assignment event.
-/// Topic: `(role_set, target_address, role_name_symbol)`
-/// Data:  `Option<caller_address>`
-fn emit(env: &Env, event: soroban_sdk::Symbol, target: &Address, role: &Role, by: Option<Address>) {
-    let role_sym = role_to_symbol(env, role);
-    env.events().publish((event, target.clone(), role_sym), by);
-}
+/// Append an Oracle grant to the bounded history and emit `orc_grant`.
+fn record_oracle_grant(env: &Env, granter: &Address, oracle: &Address) {
+    let grant = OracleGranted {
+        granter: granter.clone(),
+        oracle: oracle.clone(),
+        ledger: env.ledger().sequence(),
+    };
+
+    let mut history = get_oracle_history(env);
+    if history.len() >= MAX_ORACLE_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(grant.clone());
+    env.storage()
+        .persistent()
+        .set(&RbacKey::OracleHistory, &history);
 
-/// Emit a role revocation event.
-fn emit_revoke(env: &Env, target: &Address, by: Option<Address>) {
-    env.events()
-        .publish((symbol_short!("role_del"), target.clone()), by);
+    events::emit_oracle_granted(env, &grant);
 }
 
 /// Convert a Role to a short Symbol for event topics.
-fn role_to_symbol(env: &Env, role: &Role) -> soroban_sdk::Symbol {
+pub(crate) fn role_to_symbol(role: &Role) -> soroban_sdk::Symbol {
     match role {
         Role::SuperAdmin => symbol_short!("supadmin"),
         Role::Admin => symbol_short!("admin"),
         Role::Oracle => symbol_short!("oracle"),
         Role::Auditor => symbol_short!("auditor"),
         Role::ProjectManager => symbol_short!("proj_mgr"),
+        Role::Treasurer => symbol_short!("treasurer"),
     }
 }
 
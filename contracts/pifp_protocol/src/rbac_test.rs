@@ -1,7 +1,12 @@
 extern crate std;
 
-use crate::{test_utils::TestContext, Role};
-use soroban_sdk::vec;
+use crate::events::RoleRevoked;
+use crate::{test_utils::TestContext, OracleGranted, ProjectOptions, Role};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    vec, IntoVal, TryIntoVal,
+};
 
 #[test]
 fn test_init_sets_super_admin() {
@@ -72,7 +77,7 @@ fn test_transfer_super_admin() {
 #[test]
 fn test_project_manager_can_register() {
     let ctx = TestContext::new();
-    let tokens = vec![&ctx.env, ctx.generate_address()];
+    let tokens = vec![&ctx.env, ctx.create_token().0.address];
 
     let project = ctx.client.register_project(
         &ctx.manager,
@@ -80,6 +85,8 @@ fn test_project_manager_can_register() {
         &1000i128,
         &ctx.dummy_proof(),
         &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
     );
     assert_eq!(project.creator, ctx.manager);
 }
@@ -95,3 +102,235 @@ fn test_oracle_can_verify() {
     let completed = ctx.client.get_project(&project.id);
     assert_eq!(completed.status, crate::ProjectStatus::Completed);
 }
+
+#[test]
+fn test_list_role_holders_in_insertion_order() {
+    let ctx = TestContext::new();
+    let second = ctx.generate_address();
+    let third = ctx.generate_address();
+
+    ctx.client.grant_role(&ctx.admin, &second, &Role::Oracle);
+    ctx.client.grant_role(&ctx.admin, &third, &Role::Oracle);
+
+    assert_eq!(
+        ctx.client.list_role_holders(&Role::Oracle),
+        vec![&ctx.env, ctx.oracle.clone(), second, third]
+    );
+}
+
+#[test]
+fn test_list_role_holders_drops_revoked_and_replaced() {
+    let ctx = TestContext::new();
+    let revoked = ctx.generate_address();
+    let promoted = ctx.generate_address();
+
+    ctx.client.grant_role(&ctx.admin, &revoked, &Role::Oracle);
+    ctx.client.grant_role(&ctx.admin, &promoted, &Role::Oracle);
+    ctx.client.revoke_role(&ctx.admin, &revoked);
+    ctx.client.grant_role(&ctx.admin, &promoted, &Role::Admin);
+
+    assert_eq!(
+        ctx.client.list_role_holders(&Role::Oracle),
+        vec![&ctx.env, ctx.oracle.clone()]
+    );
+    assert_eq!(
+        ctx.client.list_role_holders(&Role::Admin),
+        vec![&ctx.env, promoted]
+    );
+}
+
+#[test]
+fn test_list_role_holders_regrant_does_not_duplicate() {
+    let ctx = TestContext::new();
+
+    ctx.client
+        .grant_role(&ctx.admin, &ctx.oracle, &Role::Oracle);
+
+    assert_eq!(
+        ctx.client.list_role_holders(&Role::Oracle),
+        vec![&ctx.env, ctx.oracle.clone()]
+    );
+}
+
+#[test]
+fn test_list_role_holders_follows_super_admin_transfer() {
+    let ctx = TestContext::new();
+    let new_super = ctx.generate_address();
+
+    ctx.client.transfer_super_admin(&ctx.admin, &new_super);
+
+    assert_eq!(
+        ctx.client.list_role_holders(&Role::SuperAdmin),
+        vec![&ctx.env, new_super]
+    );
+}
+
+#[test]
+fn test_list_role_holders_is_capped() {
+    let ctx = TestContext::new();
+    for _ in 0..crate::rbac::MAX_LISTED_HOLDERS + 5 {
+        let auditor = ctx.generate_address();
+        ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+    }
+
+    assert_eq!(
+        ctx.client.list_role_holders(&Role::Auditor).len(),
+        crate::rbac::MAX_LISTED_HOLDERS
+    );
+}
+
+#[test]
+fn test_oracle_grants_are_recorded_in_history() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    let oracle = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.env.ledger().with_mut(|l| l.sequence_number += 5);
+
+    ctx.client.set_oracle(&admin, &oracle);
+
+    let history = ctx.client.get_oracle_history();
+    assert_eq!(history.len(), 2);
+    let setup_grant = history.get(0).unwrap();
+    assert_eq!(setup_grant.granter, ctx.admin);
+    assert_eq!(setup_grant.oracle, ctx.oracle);
+    assert_eq!(
+        history.get(1).unwrap(),
+        OracleGranted {
+            granter: admin,
+            oracle,
+            ledger: ctx.env.ledger().sequence(),
+        }
+    );
+}
+
+#[test]
+fn test_oracle_grant_emits_event() {
+    let ctx = TestContext::new();
+    let oracle = ctx.generate_address();
+
+    ctx.client.grant_role(&ctx.admin, &oracle, &Role::Oracle);
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("orc_grant").into_val(&ctx.env),
+        oracle.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    let event_data: OracleGranted = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(event_data.granter, ctx.admin);
+    assert_eq!(event_data.oracle, oracle);
+}
+
+#[test]
+fn test_non_oracle_grants_are_not_recorded() {
+    let ctx = TestContext::new();
+    let auditor = ctx.generate_address();
+
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+
+    assert_eq!(ctx.client.get_oracle_history().len(), 1);
+}
+
+#[test]
+fn test_oracle_history_keeps_most_recent_grants() {
+    let ctx = TestContext::new();
+    let mut last = ctx.oracle.clone();
+    for _ in 0..crate::rbac::MAX_ORACLE_HISTORY {
+        last = ctx.generate_address();
+        ctx.client.set_oracle(&ctx.admin, &last);
+    }
+
+    let history = ctx.client.get_oracle_history();
+    assert_eq!(history.len(), crate::rbac::MAX_ORACLE_HISTORY);
+    // The grant made during setup has been evicted.
+    assert!(history.iter().all(|g| g.oracle != ctx.oracle));
+    assert_eq!(history.last().unwrap().oracle, last);
+}
+
+#[test]
+fn test_admin_can_revoke_oracle() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.revoke_oracle(&admin, &ctx.oracle);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    assert_eq!(
+        last_event.1,
+        vec![
+            &ctx.env,
+            symbol_short!("role_del").into_val(&ctx.env),
+            ctx.oracle.into_val(&ctx.env),
+        ]
+    );
+    let event_data: RoleRevoked = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        RoleRevoked {
+            revoker: admin,
+            target: ctx.oracle.clone(),
+        }
+    );
+
+    // Addresses hold a single role, so the oracle is left with none.
+    assert_eq!(ctx.client.role_of(&ctx.oracle), None);
+    assert!(ctx.client.list_role_holders(&Role::Oracle).is_empty());
+}
+
+#[test]
+fn test_revoke_oracle_leaves_other_roles_intact() {
+    let ctx = TestContext::new();
+    let auditor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+    let role_not_found = Err(Ok(soroban_sdk::Error::from_contract_error(
+        crate::Error::RoleNotFound as u32,
+    )));
+
+    assert_eq!(
+        ctx.client.try_revoke_oracle(&ctx.admin, &auditor),
+        role_not_found
+    );
+    assert_eq!(
+        ctx.client.try_revoke_oracle(&ctx.admin, &ctx.admin),
+        role_not_found
+    );
+    assert_eq!(
+        ctx.client
+            .try_revoke_oracle(&ctx.admin, &ctx.generate_address()),
+        role_not_found
+    );
+
+    assert!(ctx.client.has_role(&auditor, &Role::Auditor));
+    assert!(ctx.client.has_role(&ctx.admin, &Role::SuperAdmin));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_non_admin_cannot_revoke_oracle() {
+    let ctx = TestContext::new();
+
+    ctx.client.revoke_oracle(&ctx.manager, &ctx.oracle);
+}
+
+#[test]
+fn test_revoked_oracle_cannot_verify() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(100);
+
+    ctx.client.revoke_oracle(&ctx.admin, &ctx.oracle);
+
+    let result = ctx
+        .client
+        .try_verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::NotAuthorized as u32
+        )))
+    );
+}
@@ -7,7 +7,25 @@
 //! | Key              | Type      | Description                        |
 //! |------------------|-----------|------------------------------------|
 //! | `ProjectCount`   | `u64`     | Auto-increment project ID counter  |
+//! | `CompletedCount` | `u64`     | Projects that reached `Completed`  |
+//! | `ExpiredCount`   | `u64`     | Projects that reached `Expired`    |
+//! | `RefundCount`    | `u64`     | Refund payouts made to donors      |
+//! | `StatusCount(status)` | `u64` | Projects stored with `status`      |
+//! | `ReceiptCount`   | `u64`     | Auto-increment donation receipt ID counter |
+//! | `PledgeCount`    | `u64`     | Auto-increment pledge ID counter   |
 //! | `OracleKey`      | `Address` | Active trusted oracle address      |
+//! | `FeeBps`         | `u32`     | Protocol fee taken on release      |
+//! | `QuorumThreshold`| `u32`     | Matching votes needed to release   |
+//! | `ReleaseDelay`   | `u64`     | Protocol-wide payout delay (secs)  |
+//! | `GracePeriod`    | `u64`     | Deposits still accepted this long past a deadline (secs) |
+//! | `RefundGrace`    | `u64`     | Refund window after the deadline before unclaimed funds may be swept (secs) |
+//! | `MaxActiveProjects` | `u32`  | Open projects a creator may have at once; absent or 0 means unlimited |
+//! | `Version`        | `u32`     | Contract version, bumped on upgrade |
+//! | `RegistrationFee`| `(Address, i128)` | Token and amount charged per registration |
+//! | `CreatorBond`    | `(Address, i128)` | Token and amount of the bond posted at registration |
+//! | `AllowedTokens`  | `Vec<Address>` | Tokens projects may accept; empty means any |
+//! | `ProtocolParams` | `ProtocolParams` | Registration limits; absent means defaults |
+//! | `TracksHeld`     | `bool`    | Present when `TotalHeld` has counted every transfer since deployment |
 //!
 //! Instance TTL is bumped by **7 days** whenever it falls below 1 day remaining.
 //!
@@ -17,10 +35,45 @@
 //! |--------------------|-----------------|----------------------------------|
 //! | `ProjConfig(id)`   | `ProjectConfig` | Immutable project configuration  |
 //! | `ProjState(id)`    | `ProjectState`  | Mutable project state            |
+//! | `StatusBucket(status, n)` | `u64`    | Bitmap of the projects stored with `status` among ids `64n..64n + 64` |
 //! | `DonatorBalance(id, token, donator)` | `i128` | Per-donator refundable amount |
+//! | `Receipt(id)`      | `DonationReceipt` | Record of a single deposit |
+//! | `DonorReceiptCount(donator)` | `u32` | Receipts issued to a donor |
+//! | `DonorReceipt(donator, n)` | `u64` | Id of a donor's `n`-th receipt |
+//! | `Pledge(id)`       | `Pledge`        | Recurring donation awaiting its next pull |
+//! | `Blocked(address)` | `bool`         | Present while `address` is denylisted |
+//! | `ActiveProjects(creator)` | `u32`   | Creator's projects not yet `Completed`, `Expired` or `Cancelled` |
+//! | `Frozen(id)`       | `bool`          | Present while a project is frozen by an admin |
+//! | `ProjectDonor(id, donator)` | `bool` | Donator has deposited in any token |
+//! | `Votes(id)`        | `Map<Address, BytesN<32>>` | Oracle verification votes |
+//! | `OraclePubKey(pubkey)` | `Address` | Oracle a signing key belongs to |
+//! | `ReleaseApproval(id)` | `ReleaseApproval` | Oracle approval awaiting co-sign |
+//! | `Vesting(id, token)` | `VestingSchedule` | Released funds vesting to the creator |
+//! | `ReleaseAfter(id)` | `u64` | Earliest `claim_release` time of a verified project |
+//! | `FeeBalance(token)` | `i128` | Protocol fees held by the contract, per token |
+//! | `ProjectBond(id)` | `ProjectBond` | Creator bond held until release or slashing |
+//! | `TotalRaised(id, token)` | `i128` | Lifetime deposits per token; never decremented |
+//! | `TotalVolume(token)` | `i128` | Lifetime deposits of a token across all projects |
+//! | `TotalHeld(token)` | `i128` | Running total of a token held for escrow, pools, bonds, vesting, waived refunds and fees |
+//! | `HeldSynced(token)` | `bool` | Present once `TotalHeld(token)` was seeded after an upgrade |
+//! | `SchemaVersion(id)` | `u32` | Layout of the project's entries; absent means 1 |
+//! | `RejectOverfunding(id)` | `bool` | Present when deposits may not exceed the goal |
+//! | `RefundExcess(id)` | `bool` | Present when release leaves the excess over the goal to donors |
+//! | `ExcessRefund(id, token)` | `ExcessRefund` | Excess set aside for donors at release |
+//! | `PartiallyReleased(id, token)` | `bool` | Present once part of the token's escrow was released early |
+//! | `ShortfallRefund(id, token)` | `ExcessRefund` | Escrow left after a partial release, shared across refunds |
+//! | `MatchingPool(id, token)` | `MatchingPool` | Sponsor funds not yet used to match donations |
+//! | `Waived(id, token)` | `i128` | Refunds donors waived, claimable by the creator |
 //!
 //! Persistent TTL is bumped by **30 days** whenever it falls below 7 days remaining.
 //!
+//! ## Schema versions
+//!
+//! Project entries record the layout they were written with under
+//! `SchemaVersion(id)`. Entries from before versioning have no record and are
+//! read as version 1 ([`ProjectConfigV1`]/[`ProjectStateV1`]);
+//! [`migrate_project`] rewrites them in the current layout.
+//!
 //! ## Why split Config and State?
 //!
 //! Deposits are high-frequency writes. Writing the full `Project` struct (~150 bytes)
@@ -28,9 +81,14 @@
 //! ledger write costs by ~87% per deposit while keeping the public API clean via
 //! the reconstructed [`Project`] return type.
 
-use soroban_sdk::{contracttype, Address, Env, Vec};
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Map, String, Vec};
 
-use crate::types::{Project, ProjectBalances, ProjectConfig, ProjectState, TokenBalance};
+use crate::types::{
+    DonationReceipt, ExcessRefund, MatchingPool, Pledge, Project, ProjectBalances, ProjectBond,
+    ProjectConfig, ProjectConfigV1, ProjectState, ProjectStateV1, ProjectStatus, ProtocolParams,
+    ProtocolStats, ReleaseApproval, TokenBalance, VestingSchedule,
+};
+use crate::Error;
 
 // ── TTL Constants ────────────────────────────────────────────────────
 
@@ -45,6 +103,13 @@ const INSTANCE_LIFETIME_THRESHOLD: u32 = DAY_IN_LEDGERS;
 const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const PERSISTENT_LIFETIME_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
 
+/// Layout written by `save_project`; bump when `ProjectConfig` or
+/// `ProjectState` change shape and teach [`migrate_project`] the old one.
+pub const PROJECT_SCHEMA_VERSION: u32 = 2;
+
+/// Refund window used until a `SuperAdmin` sets one: 30 days.
+pub const DEFAULT_REFUND_GRACE_SECS: u64 = 2_592_000;
+
 // ── Storage Keys ─────────────────────────────────────────────────────
 
 /// All contract storage keys.
@@ -57,10 +122,41 @@ const PERSISTENT_LIFETIME_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
 pub enum DataKey {
     /// Global auto-increment counter for project IDs (Instance).
     ProjectCount,
+    /// Number of projects that reached `Completed` (Instance).
+    CompletedCount,
+    /// Number of projects that reached `Expired` (Instance).
+    ExpiredCount,
+    /// Number of refund payouts made to donors (Instance).
+    RefundCount,
+    /// Number of projects stored with a status (Instance).
+    StatusCount(ProjectStatus),
+    /// Global auto-increment counter for donation receipt IDs (Instance).
+    ReceiptCount,
+    /// Donation receipt keyed by ID (Persistent).
+    Receipt(u64),
+    /// Number of receipts issued to a donor (Persistent).
+    DonorReceiptCount(Address),
+    /// Receipt ID of a donor's `n`-th deposit, keyed by (donator, n) (Persistent).
+    DonorReceipt(Address, u32),
+    /// Auto-incrementing pledge ID counter (Instance).
+    PledgeCount,
+    /// Recurring pledge keyed by ID (Persistent).
+    Pledge(u64),
+    /// Marks an address barred from depositing or registering (Persistent).
+    Blocked(Address),
+    /// Open projects a creator may have at once, when above 0 (Instance).
+    MaxActiveProjects,
+    /// A creator's projects that have not reached a final status (Persistent).
+    ActiveProjects(Address),
+    /// Marks a project quarantined by an admin (Persistent).
+    Frozen(u64),
     /// Immutable project configuration keyed by ID (Persistent).
     ProjConfig(u64),
     /// Mutable project state keyed by ID (Persistent).
     ProjState(u64),
+    /// Bitmap of the projects stored with a status among 64 consecutive
+    /// ids (Persistent).
+    StatusBucket(ProjectStatus, u64),
     /// Token balance for a specific project and token (Persistent).
     TokenBalance(u64, Address),
     /// Protocol pause state (Instance).
@@ -69,6 +165,76 @@ pub enum DataKey {
     DonatorSeen(u64, Address, Address),
     /// Per-donator refundable balance keyed by (project_id, token, donator) (Persistent).
     DonatorBalance(u64, Address, Address),
+    /// Tracks whether a donator has deposited into a project in any token (Persistent).
+    ProjectDonor(u64, Address),
+    /// Matching oracle votes required to release a project (Instance).
+    QuorumThreshold,
+    /// Verification votes for a project, oracle → proof hash (Persistent).
+    Votes(u64),
+    /// Oracle address that an ed25519 public key signs for (Persistent).
+    OraclePubKey(BytesN<32>),
+    /// Oracle approval awaiting an admin co-signature (Persistent).
+    ReleaseApproval(u64),
+    /// Vesting schedule of released funds, keyed by (project_id, token) (Persistent).
+    Vesting(u64, Address),
+    /// Timestamp after which a verified project's funds may be claimed (Persistent).
+    ReleaseAfter(u64),
+    /// Protocol-wide delay between verification and payout, in seconds (Instance).
+    ReleaseDelay,
+    /// Seconds past its deadline a project keeps accepting deposits (Instance).
+    GracePeriod,
+    /// Seconds past an expired project's deadline before unclaimed refunds
+    /// may be swept (Instance).
+    RefundGrace,
+    /// Contract version, bumped on every upgrade (Instance).
+    Version,
+    /// Protocol fee in basis points applied on release (Instance).
+    FeeBps,
+    /// Accumulated protocol fees per token, kept apart from project escrow (Persistent).
+    FeeBalance(Address),
+    /// Token and amount charged to register a project; absent when disabled (Instance).
+    RegistrationFee,
+    /// Token and amount every creator must bond at registration (Instance).
+    CreatorBond,
+    /// Bond a creator posted for a project (Persistent).
+    ProjectBond(u64),
+    /// Lifetime deposits for a specific project and token (Persistent).
+    TotalRaised(u64, Address),
+    /// Lifetime deposits of a token across every project (Persistent).
+    TotalVolume(Address),
+    /// Running total of a token the contract holds on others' behalf (Persistent).
+    TotalHeld(Address),
+    /// Set at `init`: `TotalHeld` counts every transfer since deployment (Instance).
+    TracksHeld,
+    /// Marks a token whose `TotalHeld` was seeded after an upgrade (Persistent).
+    HeldSynced(Address),
+    /// Storage layout version of a project's entries (Persistent).
+    SchemaVersion(u64),
+    /// Marks a project that refuses deposits beyond its goal (Persistent).
+    /// Kept out of `ProjectConfig` so the config layout stays unchanged.
+    RejectOverfunding(u64),
+    /// Marks a project whose release returns the excess over its goal to
+    /// donors (Persistent). Kept out of `ProjectConfig` like `RejectOverfunding`.
+    RefundExcess(u64),
+    /// Excess of a token set aside for donors at release (Persistent).
+    ExcessRefund(u64, Address),
+    /// Marks a token of which part of the escrow was released early (Persistent).
+    PartiallyReleased(u64, Address),
+    /// Escrow left after a partial release, shared pro-rata across the
+    /// donors' refunds (Persistent).
+    ShortfallRefund(u64, Address),
+    /// Sponsor funds matching donations in one token (Persistent).
+    MatchingPool(u64, Address),
+    /// Smallest deposit a project accepts, when above 0 (Persistent). Kept
+    /// out of `ProjectConfig` like `RejectOverfunding`.
+    MinDeposit(u64),
+    /// Contributions donors of an expired project gave up in favour of the
+    /// creator, not yet claimed (Persistent).
+    Waived(u64, Address),
+    /// Protocol allowlist of tokens projects may accept (Instance).
+    AllowedTokens,
+    /// Registration limits overriding the built-in defaults (Instance).
+    ProtocolParams,
 }
 
 // ── Instance Storage Helpers ─────────────────────────────────────────
@@ -99,6 +265,73 @@ pub fn get_and_increment_project_id(env: &Env) -> u64 {
     current
 }
 
+/// Number of projects registered so far; ids run from 0 to this, exclusive.
+pub fn get_project_count(env: &Env) -> u64 {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .get(&DataKey::ProjectCount)
+        .unwrap_or(0)
+}
+
+// ─────────────────────────────────────────────────────────
+// Protocol stats
+// ─────────────────────────────────────────────────────────
+
+fn increment_counter(env: &Env, key: &DataKey) {
+    bump_instance(env);
+    let current: u64 = env.storage().instance().get(key).unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(key, &current.saturating_add(1));
+}
+
+fn get_counter(env: &Env, key: &DataKey) -> u64 {
+    env.storage().instance().get(key).unwrap_or(0)
+}
+
+/// Count a project reaching `Completed`.
+pub fn record_completion(env: &Env) {
+    increment_counter(env, &DataKey::CompletedCount);
+}
+
+/// Count a project reaching `Expired`.
+pub fn record_expiry(env: &Env) {
+    increment_counter(env, &DataKey::ExpiredCount);
+}
+
+/// Count one refund payout.
+pub fn record_refund(env: &Env) {
+    increment_counter(env, &DataKey::RefundCount);
+}
+
+/// Add `delta` (+1 or -1) to the number of projects stored with `status`.
+fn adjust_status_count(env: &Env, status: &ProjectStatus, delta: i64) {
+    let key = DataKey::StatusCount(status.clone());
+    let current = get_counter(env, &key);
+    let next = match current.checked_add_signed(delta) {
+        Some(next) => next,
+        None => panic_with_error!(env, Error::Overflow),
+    };
+    bump_instance(env);
+    env.storage().instance().set(&key, &next);
+}
+
+/// Snapshot the protocol-wide counters.
+pub fn get_stats(env: &Env) -> ProtocolStats {
+    let status_count = |status: ProjectStatus| get_counter(env, &DataKey::StatusCount(status));
+    ProtocolStats {
+        total_projects: get_project_count(env),
+        total_completed: get_counter(env, &DataKey::CompletedCount),
+        total_expired: get_counter(env, &DataKey::ExpiredCount),
+        total_refunds: get_counter(env, &DataKey::RefundCount),
+        funding_count: status_count(ProjectStatus::Funding),
+        active_count: status_count(ProjectStatus::Active),
+        verified_count: status_count(ProjectStatus::Verified),
+        total_cancelled: status_count(ProjectStatus::Cancelled),
+    }
+}
+
 /// Return true if the protocol is currently paused.
 pub fn is_paused(env: &Env) -> bool {
     env.storage()
@@ -113,6 +346,159 @@ pub fn set_paused(env: &Env, paused: bool) {
     env.storage().instance().set(&DataKey::IsPaused, &paused);
 }
 
+/// Return the contract version (1 until the first upgrade).
+pub fn get_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::Version).unwrap_or(1)
+}
+
+/// Record the contract version.
+pub fn set_version(env: &Env, version: u32) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::Version, &version);
+}
+
+/// Return the protocol fee in basis points (0 when unset).
+pub fn get_fee_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0)
+}
+
+/// Set the protocol fee in basis points.
+pub fn set_fee_bps(env: &Env, bps: u32) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::FeeBps, &bps);
+}
+
+/// Return the number of matching oracle votes required to release (1 when unset).
+pub fn get_quorum_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::QuorumThreshold)
+        .unwrap_or(1)
+}
+
+/// Set the number of matching oracle votes required to release.
+pub fn set_quorum_threshold(env: &Env, threshold: u32) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::QuorumThreshold, &threshold);
+}
+
+/// Protocol-wide delay between verification and payout, in seconds.
+///
+/// Defaults to 0 (no delay) when never configured.
+pub fn get_release_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReleaseDelay)
+        .unwrap_or(0)
+}
+
+/// Set the protocol-wide delay between verification and payout.
+pub fn set_release_delay(env: &Env, delay_secs: u64) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::ReleaseDelay, &delay_secs);
+}
+
+/// Seconds past its deadline a project keeps accepting deposits.
+///
+/// Defaults to 0 (deposits close at the deadline) when never configured.
+pub fn get_grace_period(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::GracePeriod)
+        .unwrap_or(0)
+}
+
+/// Set how long past its deadline a project keeps accepting deposits.
+pub fn set_grace_period(env: &Env, grace_secs: u64) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::GracePeriod, &grace_secs);
+}
+
+/// Seconds past an expired project's deadline during which only donors may
+/// take its funds.
+///
+/// Defaults to [`DEFAULT_REFUND_GRACE_SECS`] when never configured.
+pub fn get_refund_grace(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RefundGrace)
+        .unwrap_or(DEFAULT_REFUND_GRACE_SECS)
+}
+
+/// Set the refund window that precedes sweeping unclaimed funds.
+pub fn set_refund_grace(env: &Env, grace_secs: u64) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::RefundGrace, &grace_secs);
+}
+
+/// Return the `(token, amount)` charged per registration, if any.
+pub fn get_registration_fee(env: &Env) -> Option<(Address, i128)> {
+    env.storage().instance().get(&DataKey::RegistrationFee)
+}
+
+/// Set the registration fee; `None` disables it.
+pub fn set_registration_fee(env: &Env, fee: Option<(Address, i128)>) {
+    bump_instance(env);
+    match fee {
+        Some(fee) => env
+            .storage()
+            .instance()
+            .set(&DataKey::RegistrationFee, &fee),
+        None => env.storage().instance().remove(&DataKey::RegistrationFee),
+    }
+}
+
+/// Return the `(token, amount)` bond required per registration, if any.
+pub fn get_creator_bond(env: &Env) -> Option<(Address, i128)> {
+    env.storage().instance().get(&DataKey::CreatorBond)
+}
+
+/// Set the creator bond; `None` disables it.
+pub fn set_creator_bond(env: &Env, bond: Option<(Address, i128)>) {
+    bump_instance(env);
+    match bond {
+        Some(bond) => env.storage().instance().set(&DataKey::CreatorBond, &bond),
+        None => env.storage().instance().remove(&DataKey::CreatorBond),
+    }
+}
+
+/// Return the token allowlist; empty when registration is permissionless.
+pub fn get_allowed_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AllowedTokens)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Replace the token allowlist.
+pub fn set_allowed_tokens(env: &Env, tokens: &Vec<Address>) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::AllowedTokens, tokens);
+}
+
+/// Return the stored registration limits, if a `SuperAdmin` set any.
+pub fn get_protocol_params(env: &Env) -> Option<ProtocolParams> {
+    env.storage().instance().get(&DataKey::ProtocolParams)
+}
+
+/// Store the registration limits.
+pub fn set_protocol_params(env: &Env, params: &ProtocolParams) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::ProtocolParams, params);
+}
+
 // ── Persistent Storage Helpers ───────────────────────────────────────
 
 /// Extend the TTL for a persistent storage key.
@@ -136,17 +522,30 @@ pub fn save_project(env: &Env, project: &Project) {
         goal: project.goal,
         proof_hash: project.proof_hash.clone(),
         deadline: project.deadline,
+        metadata_uri: project.metadata_uri.clone(),
+        assigned_oracle: project.assigned_oracle.clone(),
+        requires_admin_cosign: project.requires_admin_cosign,
+        vesting_duration_secs: project.vesting_duration_secs,
+        release_delay: project.release_delay,
+        payout_address: project.payout_address.clone(),
+        max_contribution: project.max_contribution,
+        token_goals: project.token_goals.clone(),
+        require_all_goals: project.require_all_goals,
     };
 
     let state = ProjectState {
         status: project.status.clone(),
         donation_count: project.donation_count,
+        unique_donors: project.unique_donors,
+        total_deposited: project.total_deposited,
     };
 
     env.storage().persistent().set(&config_key, &config);
     env.storage().persistent().set(&state_key, &state);
     bump_persistent(env, &config_key);
     bump_persistent(env, &state_key);
+    set_schema_version(env, project.id, PROJECT_SCHEMA_VERSION);
+    add_to_status_index(env, project.id, &project.status);
 
     // Initialise balances to 0 for all accepted tokens.
     for token in project.accepted_tokens.iter() {
@@ -154,6 +553,91 @@ pub fn save_project(env: &Env, project: &Project) {
     }
 }
 
+/// Storage layout version of a project's entries; 1 when unrecorded.
+pub fn get_schema_version(env: &Env, id: u64) -> u32 {
+    let key = DataKey::SchemaVersion(id);
+    let version: Option<u32> = env.storage().persistent().get(&key);
+    if version.is_some() {
+        bump_persistent(env, &key);
+    }
+    version.unwrap_or(1)
+}
+
+fn set_schema_version(env: &Env, id: u64, version: u32) {
+    let key = DataKey::SchemaVersion(id);
+    env.storage().persistent().set(&key, &version);
+    bump_persistent(env, &key);
+}
+
+/// Rewrite a project's entries in the current layout.
+///
+/// Fields that version 1 did not have get their "off" defaults, as if the
+/// project had been registered with `ProjectOptions::default()` and an empty
+/// metadata URI. Counters v1 did not keep are seeded from what is still on
+/// the ledger: `total_deposited` and each token's lifetime total start at the
+/// escrowed balance, and `unique_donors` at 0. Returns `false` if the
+/// project was already current.
+pub fn migrate_project(env: &Env, id: u64) -> bool {
+    if get_schema_version(env, id) >= PROJECT_SCHEMA_VERSION {
+        return false;
+    }
+    let config_key = DataKey::ProjConfig(id);
+    let state_key = DataKey::ProjState(id);
+    let old_config: ProjectConfigV1 = env
+        .storage()
+        .persistent()
+        .get(&config_key)
+        .expect("project not found");
+    let old_state: ProjectStateV1 = env
+        .storage()
+        .persistent()
+        .get(&state_key)
+        .expect("project not found");
+
+    let mut total_deposited: i128 = 0;
+    for token in old_config.accepted_tokens.iter() {
+        let balance = get_token_balance(env, id, &token);
+        total_deposited = total_deposited
+            .checked_add(balance)
+            .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+        if get_total_raised(env, id, &token) == 0 {
+            set_total_raised(env, id, &token, balance);
+        }
+    }
+
+    let config = ProjectConfig {
+        id: old_config.id,
+        creator: old_config.creator,
+        accepted_tokens: old_config.accepted_tokens,
+        goal: old_config.goal,
+        proof_hash: old_config.proof_hash,
+        deadline: old_config.deadline,
+        metadata_uri: String::from_str(env, ""),
+        assigned_oracle: None,
+        requires_admin_cosign: false,
+        vesting_duration_secs: 0,
+        release_delay: 0,
+        payout_address: None,
+        max_contribution: 0,
+        token_goals: Vec::new(env),
+        require_all_goals: false,
+    };
+    let state = ProjectState {
+        status: old_state.status,
+        donation_count: old_state.donation_count,
+        unique_donors: 0,
+        total_deposited,
+    };
+
+    env.storage().persistent().set(&config_key, &config);
+    env.storage().persistent().set(&state_key, &state);
+    bump_persistent(env, &config_key);
+    bump_persistent(env, &state_key);
+    set_schema_version(env, id, PROJECT_SCHEMA_VERSION);
+    add_to_status_index(env, id, &state.status);
+    true
+}
+
 /// Load only the immutable project configuration.
 ///
 /// This helper panics with a generic string if the project does not exist. It
@@ -172,6 +656,16 @@ pub fn load_project_state(env: &Env, id: u64) -> ProjectState {
     maybe_load_project_state(env, id).expect("project not found")
 }
 
+/// Overwrite the project configuration.
+///
+/// Configuration is written once at registration; this is only used by the
+/// rare administrative updates such as a deadline extension.
+pub fn save_project_config(env: &Env, config: &ProjectConfig) {
+    let key = DataKey::ProjConfig(config.id);
+    env.storage().persistent().set(&key, config);
+    bump_persistent(env, &key);
+}
+
 /// Save only the mutable project state (optimized for deposits/verification).
 pub fn save_project_state(env: &Env, id: u64, state: &ProjectState) {
     let key = DataKey::ProjState(id);
@@ -179,6 +673,93 @@ pub fn save_project_state(env: &Env, id: u64, state: &ProjectState) {
     bump_persistent(env, &key);
 }
 
+// ── Status Index ─────────────────────────────────────────────────────
+
+/// Project ids covered by one `StatusBucket` entry.
+const STATUS_BUCKET_SIZE: u64 = 64;
+
+/// Bitmap of the projects stored with `status` in `bucket`; bit `n` stands
+/// for id `bucket * 64 + n`.
+fn get_status_bucket(env: &Env, status: &ProjectStatus, bucket: u64) -> u64 {
+    let key = DataKey::StatusBucket(status.clone(), bucket);
+    let bits: Option<u64> = env.storage().persistent().get(&key);
+    if bits.is_some() {
+        bump_persistent(env, &key);
+    }
+    bits.unwrap_or(0)
+}
+
+fn set_status_bucket(env: &Env, status: &ProjectStatus, bucket: u64, bits: u64) {
+    let key = DataKey::StatusBucket(status.clone(), bucket);
+    if bits == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &bits);
+        bump_persistent(env, &key);
+    }
+}
+
+/// Ids in `start..end` of the projects stored with `status`, ascending and
+/// at most `limit` of them. Reads one entry per 64 ids in the range.
+pub fn get_ids_with_status(
+    env: &Env,
+    status: &ProjectStatus,
+    start: u64,
+    end: u64,
+    limit: u32,
+) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    let mut bucket = start / STATUS_BUCKET_SIZE;
+    while bucket * STATUS_BUCKET_SIZE < end && ids.len() < limit {
+        let base = bucket * STATUS_BUCKET_SIZE;
+        let mut bits = get_status_bucket(env, status, bucket);
+        while bits != 0 && ids.len() < limit {
+            let id = base + u64::from(bits.trailing_zeros());
+            bits &= bits - 1;
+            if id >= start && id < end {
+                ids.push_back(id);
+            }
+        }
+        bucket += 1;
+    }
+    ids
+}
+
+/// Add `project_id` to the index of `status`.
+pub fn add_to_status_index(env: &Env, project_id: u64, status: &ProjectStatus) {
+    let bucket = project_id / STATUS_BUCKET_SIZE;
+    let bit = 1u64 << (project_id % STATUS_BUCKET_SIZE);
+    let bits = get_status_bucket(env, status, bucket);
+    if bits & bit == 0 {
+        set_status_bucket(env, status, bucket, bits | bit);
+        adjust_status_count(env, status, 1);
+    }
+}
+
+/// Remove `project_id` from the index of `status`, if present.
+fn remove_from_status_index(env: &Env, project_id: u64, status: &ProjectStatus) {
+    let bucket = project_id / STATUS_BUCKET_SIZE;
+    let bit = 1u64 << (project_id % STATUS_BUCKET_SIZE);
+    let bits = get_status_bucket(env, status, bucket);
+    if bits & bit != 0 {
+        set_status_bucket(env, status, bucket, bits & !bit);
+        adjust_status_count(env, status, -1);
+    }
+}
+
+/// Move a project to `next`, updating `state.status` and the status indexes
+/// together. The caller still saves `state`.
+///
+/// Each index entry covers a fixed 64 ids, so a transition rewrites two
+/// small entries however many projects share the status. A project
+/// registered before the indexes existed is missing from its old index; it
+/// simply joins the new one.
+pub fn set_status(env: &Env, project_id: u64, state: &mut ProjectState, next: ProjectStatus) {
+    remove_from_status_index(env, project_id, &state.status);
+    add_to_status_index(env, project_id, &next);
+    state.status = next;
+}
+
 // ── New retrieval helpers ─────────────────────────────────────────
 
 /// Returns `true` if a project with the given `id` exists in persistent storage.
@@ -197,7 +778,6 @@ pub fn project_exists(env: &Env, id: u64) -> bool {
 /// The returned option will be `None` if the project is not found. When a value
 /// is returned the entry's TTL is bumped as usual; if the project does not
 /// exist **no TTL bump occurs**.
-#[allow(dead_code)]
 pub fn maybe_load_project_config(env: &Env, id: u64) -> Option<ProjectConfig> {
     let key = DataKey::ProjConfig(id);
     let opt: Option<ProjectConfig> = env.storage().persistent().get(&key);
@@ -263,8 +843,19 @@ pub fn load_project(env: &Env, id: u64) -> Project {
         goal: config.goal,
         proof_hash: config.proof_hash,
         deadline: config.deadline,
+        metadata_uri: config.metadata_uri,
+        assigned_oracle: config.assigned_oracle,
+        requires_admin_cosign: config.requires_admin_cosign,
+        vesting_duration_secs: config.vesting_duration_secs,
+        release_delay: config.release_delay,
+        payout_address: config.payout_address.clone(),
+        max_contribution: config.max_contribution,
+        token_goals: config.token_goals,
+        require_all_goals: config.require_all_goals,
         status: state.status,
         donation_count: state.donation_count,
+        unique_donors: state.unique_donors,
+        total_deposited: state.total_deposited,
     }
 }
 
@@ -290,8 +881,19 @@ pub fn maybe_load_project(env: &Env, id: u64) -> Option<Project> {
         goal: config.goal,
         proof_hash: config.proof_hash,
         deadline: config.deadline,
+        metadata_uri: config.metadata_uri,
+        assigned_oracle: config.assigned_oracle,
+        requires_admin_cosign: config.requires_admin_cosign,
+        vesting_duration_secs: config.vesting_duration_secs,
+        release_delay: config.release_delay,
+        payout_address: config.payout_address.clone(),
+        max_contribution: config.max_contribution,
+        token_goals: config.token_goals,
+        require_all_goals: config.require_all_goals,
         status: state.status,
         donation_count: state.donation_count,
+        unique_donors: state.unique_donors,
+        total_deposited: state.total_deposited,
     })
 }
 
@@ -314,11 +916,51 @@ pub fn set_token_balance(env: &Env, project_id: u64, token: &Address, balance: i
 /// Returns the new balance.
 pub fn add_to_token_balance(env: &Env, project_id: u64, token: &Address, amount: i128) -> i128 {
     let current = get_token_balance(env, project_id, token);
-    let new_balance = current.checked_add(amount).expect("balance overflow");
+    let new_balance = current
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
     set_token_balance(env, project_id, token, new_balance);
     new_balance
 }
 
+/// Lifetime deposits of `token` into `project_id`; 0 before the first one.
+pub fn get_total_raised(env: &Env, project_id: u64, token: &Address) -> i128 {
+    let key = DataKey::TotalRaised(project_id, token.clone());
+    let total: Option<i128> = env.storage().persistent().get(&key);
+    if total.is_some() {
+        bump_persistent(env, &key);
+    }
+    total.unwrap_or(0)
+}
+
+/// Set the lifetime deposits of `token` into `project_id`.
+pub fn set_total_raised(env: &Env, project_id: u64, token: &Address, total: i128) {
+    let key = DataKey::TotalRaised(project_id, token.clone());
+    env.storage().persistent().set(&key, &total);
+    bump_persistent(env, &key);
+}
+
+/// Lifetime deposits of `token` across every project; 0 before the first.
+pub fn get_total_volume(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::TotalVolume(token.clone());
+    let total: Option<i128> = env.storage().persistent().get(&key);
+    if total.is_some() {
+        bump_persistent(env, &key);
+    }
+    total.unwrap_or(0)
+}
+
+/// Count `amount` of `token` towards its protocol-wide lifetime volume.
+pub fn add_to_total_volume(env: &Env, token: &Address, amount: i128) {
+    let total = match get_total_volume(env, token).checked_add(amount) {
+        Some(total) => total,
+        None => panic_with_error!(env, Error::Overflow),
+    };
+    let key = DataKey::TotalVolume(token.clone());
+    env.storage().persistent().set(&key, &total);
+    bump_persistent(env, &key);
+}
+
 /// Zero out the balance of `token` for `project_id` and return what it was.
 /// Called during `verify_and_release` after transferring funds to the creator.
 #[allow(dead_code)]
@@ -333,16 +975,24 @@ pub fn drain_token_balance(env: &Env, project_id: u64, token: &Address) -> i128
 /// Build a `ProjectBalances` snapshot by reading each accepted token's balance.
 #[allow(dead_code)]
 pub fn get_all_balances(env: &Env, project: &Project) -> ProjectBalances {
+    get_balances_for(env, project.id, &project.accepted_tokens)
+}
+
+/// Read balances for `tokens` only, in the given order. Callers are
+/// responsible for checking the tokens belong to the project.
+pub fn get_balances_for(env: &Env, project_id: u64, tokens: &Vec<Address>) -> ProjectBalances {
     let mut balances: Vec<TokenBalance> = Vec::new(env);
-    for token in project.accepted_tokens.iter() {
-        let balance = get_token_balance(env, project.id, &token);
+    for token in tokens.iter() {
+        let balance = get_token_balance(env, project_id, &token);
+        let total_raised = get_total_raised(env, project_id, &token);
         balances.push_back(TokenBalance {
             token: token.clone(),
             balance,
+            total_raised,
         });
     }
     ProjectBalances {
-        project_id: project.id,
+        project_id,
         balances,
     }
 }
@@ -366,6 +1016,23 @@ pub fn mark_donator_seen(env: &Env, project_id: u64, donator: &Address, token: &
     bump_persistent(env, &key);
 }
 
+/// Check if `donator` has donated to `project_id` in any token before.
+pub fn is_project_donor(env: &Env, project_id: u64, donator: &Address) -> bool {
+    let key = DataKey::ProjectDonor(project_id, donator.clone());
+    let seen = env.storage().persistent().has(&key);
+    if seen {
+        bump_persistent(env, &key);
+    }
+    seen
+}
+
+/// Mark `donator` as having donated to `project_id`.
+pub fn mark_project_donor(env: &Env, project_id: u64, donator: &Address) {
+    let key = DataKey::ProjectDonor(project_id, donator.clone());
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
 /// Retrieve a donator's contributed balance for (project_id, token).
 pub fn get_donator_balance(env: &Env, project_id: u64, token: &Address, donator: &Address) -> i128 {
     let key = DataKey::DonatorBalance(project_id, token.clone(), donator.clone());
@@ -400,7 +1067,585 @@ pub fn add_to_donator_balance(
     let current = get_donator_balance(env, project_id, token, donator);
     let new_balance = current
         .checked_add(amount)
-        .expect("donator balance overflow");
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
     set_donator_balance(env, project_id, token, donator, new_balance);
     new_balance
 }
+
+// ── Verification Votes ───────────────────────────────────────────────
+
+/// Return the verification votes recorded for `project_id` (empty if none).
+pub fn get_votes(env: &Env, project_id: u64) -> Map<Address, BytesN<32>> {
+    let key = DataKey::Votes(project_id);
+    let votes: Option<Map<Address, BytesN<32>>> = env.storage().persistent().get(&key);
+    match votes {
+        Some(votes) => {
+            bump_persistent(env, &key);
+            votes
+        }
+        None => Map::new(env),
+    }
+}
+
+/// Overwrite the verification votes recorded for `project_id`.
+pub fn set_votes(env: &Env, project_id: u64, votes: &Map<Address, BytesN<32>>) {
+    let key = DataKey::Votes(project_id);
+    env.storage().persistent().set(&key, votes);
+    bump_persistent(env, &key);
+}
+
+/// Drop every verification vote recorded for `project_id`.
+pub fn clear_votes(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Votes(project_id));
+}
+
+// ── Oracle Signing Keys ──────────────────────────────────────────────
+
+/// Return the oracle address registered for `pubkey`, if any.
+pub fn get_oracle_key(env: &Env, pubkey: &BytesN<32>) -> Option<Address> {
+    let key = DataKey::OraclePubKey(pubkey.clone());
+    let oracle: Option<Address> = env.storage().persistent().get(&key);
+    if oracle.is_some() {
+        bump_persistent(env, &key);
+    }
+    oracle
+}
+
+/// Map `pubkey` to `oracle`.
+pub fn set_oracle_key(env: &Env, pubkey: &BytesN<32>, oracle: &Address) {
+    let key = DataKey::OraclePubKey(pubkey.clone());
+    env.storage().persistent().set(&key, oracle);
+    bump_persistent(env, &key);
+}
+
+// ── Release Approvals ────────────────────────────────────────────────
+
+/// Return the pending oracle approval for `project_id`, if any.
+pub fn get_release_approval(env: &Env, project_id: u64) -> Option<ReleaseApproval> {
+    let key = DataKey::ReleaseApproval(project_id);
+    let approval: Option<ReleaseApproval> = env.storage().persistent().get(&key);
+    if approval.is_some() {
+        bump_persistent(env, &key);
+    }
+    approval
+}
+
+/// Record a pending oracle approval for `project_id`.
+pub fn set_release_approval(env: &Env, project_id: u64, approval: &ReleaseApproval) {
+    let key = DataKey::ReleaseApproval(project_id);
+    env.storage().persistent().set(&key, approval);
+    bump_persistent(env, &key);
+}
+
+/// Drop the pending oracle approval for `project_id`.
+pub fn remove_release_approval(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ReleaseApproval(project_id));
+}
+
+// ── Timelocked Release ───────────────────────────────────────────────
+
+/// Return the earliest `claim_release` timestamp for `project_id`, if scheduled.
+pub fn get_release_after(env: &Env, project_id: u64) -> Option<u64> {
+    let key = DataKey::ReleaseAfter(project_id);
+    let release_after: Option<u64> = env.storage().persistent().get(&key);
+    if release_after.is_some() {
+        bump_persistent(env, &key);
+    }
+    release_after
+}
+
+/// Schedule the release of `project_id` for `release_after`.
+pub fn set_release_after(env: &Env, project_id: u64, release_after: u64) {
+    let key = DataKey::ReleaseAfter(project_id);
+    env.storage().persistent().set(&key, &release_after);
+    bump_persistent(env, &key);
+}
+
+/// Drop the scheduled release of `project_id`.
+pub fn remove_release_after(env: &Env, project_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ReleaseAfter(project_id));
+}
+
+// ── Overfunding ──────────────────────────────────────────────────────
+
+/// Whether `project_id` refuses deposits beyond its goal.
+pub fn rejects_overfunding(env: &Env, project_id: u64) -> bool {
+    let key = DataKey::RejectOverfunding(project_id);
+    let rejects = env.storage().persistent().has(&key);
+    if rejects {
+        bump_persistent(env, &key);
+    }
+    rejects
+}
+
+/// Make `project_id` refuse deposits beyond its goal.
+pub fn set_rejects_overfunding(env: &Env, project_id: u64) {
+    let key = DataKey::RejectOverfunding(project_id);
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+/// Whether releasing `project_id` leaves the excess over its goal to donors.
+pub fn refunds_excess(env: &Env, project_id: u64) -> bool {
+    let key = DataKey::RefundExcess(project_id);
+    let refunds = env.storage().persistent().has(&key);
+    if refunds {
+        bump_persistent(env, &key);
+    }
+    refunds
+}
+
+/// Make releasing `project_id` leave the excess over its goal to donors.
+pub fn set_refunds_excess(env: &Env, project_id: u64) {
+    let key = DataKey::RefundExcess(project_id);
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+/// Return the smallest deposit `project_id` accepts (0 when unrestricted).
+pub fn get_min_deposit(env: &Env, project_id: u64) -> i128 {
+    let key = DataKey::MinDeposit(project_id);
+    let min: Option<i128> = env.storage().persistent().get(&key);
+    if min.is_some() {
+        bump_persistent(env, &key);
+    }
+    min.unwrap_or(0)
+}
+
+/// Record the smallest deposit `project_id` accepts.
+pub fn set_min_deposit(env: &Env, project_id: u64, min_deposit: i128) {
+    let key = DataKey::MinDeposit(project_id);
+    env.storage().persistent().set(&key, &min_deposit);
+    bump_persistent(env, &key);
+}
+
+/// Return the excess of `token` set aside for donors of `project_id`, if any.
+pub fn get_excess_refund(env: &Env, project_id: u64, token: &Address) -> Option<ExcessRefund> {
+    let key = DataKey::ExcessRefund(project_id, token.clone());
+    let excess: Option<ExcessRefund> = env.storage().persistent().get(&key);
+    if excess.is_some() {
+        bump_persistent(env, &key);
+    }
+    excess
+}
+
+/// Store the excess of `token` set aside for donors of `project_id`.
+pub fn set_excess_refund(env: &Env, project_id: u64, token: &Address, excess: &ExcessRefund) {
+    let key = DataKey::ExcessRefund(project_id, token.clone());
+    env.storage().persistent().set(&key, excess);
+    bump_persistent(env, &key);
+}
+
+/// Whether part of `project_id`'s escrow of `token` was released early.
+pub fn is_partially_released(env: &Env, project_id: u64, token: &Address) -> bool {
+    let key = DataKey::PartiallyReleased(project_id, token.clone());
+    let released = env.storage().persistent().has(&key);
+    if released {
+        bump_persistent(env, &key);
+    }
+    released
+}
+
+/// Record that part of `project_id`'s escrow of `token` was released early.
+pub fn mark_partially_released(env: &Env, project_id: u64, token: &Address) {
+    let key = DataKey::PartiallyReleased(project_id, token.clone());
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+/// Return how the escrow of `token` left after a partial release is being
+/// shared across refunds, once the first refund has snapshotted it.
+pub fn get_shortfall_refund(env: &Env, project_id: u64, token: &Address) -> Option<ExcessRefund> {
+    let key = DataKey::ShortfallRefund(project_id, token.clone());
+    let share: Option<ExcessRefund> = env.storage().persistent().get(&key);
+    if share.is_some() {
+        bump_persistent(env, &key);
+    }
+    share
+}
+
+/// Store how the escrow of `token` left after a partial release is shared.
+pub fn set_shortfall_refund(env: &Env, project_id: u64, token: &Address, share: &ExcessRefund) {
+    let key = DataKey::ShortfallRefund(project_id, token.clone());
+    env.storage().persistent().set(&key, share);
+    bump_persistent(env, &key);
+}
+
+/// Return the matching pool for `token` in `project_id`, if one is funded.
+pub fn get_matching_pool(env: &Env, project_id: u64, token: &Address) -> Option<MatchingPool> {
+    let key = DataKey::MatchingPool(project_id, token.clone());
+    let pool: Option<MatchingPool> = env.storage().persistent().get(&key);
+    if pool.is_some() {
+        bump_persistent(env, &key);
+    }
+    pool
+}
+
+/// Store the matching pool for `token` in `project_id`.
+pub fn set_matching_pool(env: &Env, project_id: u64, token: &Address, pool: &MatchingPool) {
+    let key = DataKey::MatchingPool(project_id, token.clone());
+    env.storage().persistent().set(&key, pool);
+    bump_persistent(env, &key);
+}
+
+/// Delete an emptied matching pool.
+pub fn remove_matching_pool(env: &Env, project_id: u64, token: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MatchingPool(project_id, token.clone()));
+}
+
+/// Return the waived contributions of `token` the creator of `project_id`
+/// has yet to claim (0 when none).
+pub fn get_waived(env: &Env, project_id: u64, token: &Address) -> i128 {
+    let key = DataKey::Waived(project_id, token.clone());
+    let waived: Option<i128> = env.storage().persistent().get(&key);
+    if waived.is_some() {
+        bump_persistent(env, &key);
+    }
+    waived.unwrap_or(0)
+}
+
+/// Record the waived contributions of `token` claimable for `project_id`.
+pub fn set_waived(env: &Env, project_id: u64, token: &Address, amount: i128) {
+    let key = DataKey::Waived(project_id, token.clone());
+    env.storage().persistent().set(&key, &amount);
+    bump_persistent(env, &key);
+}
+
+// ── Creator Bonds ────────────────────────────────────────────────────
+
+/// Return the bond posted for `project_id`, if any.
+pub fn get_project_bond(env: &Env, project_id: u64) -> Option<ProjectBond> {
+    let key = DataKey::ProjectBond(project_id);
+    let bond: Option<ProjectBond> = env.storage().persistent().get(&key);
+    if bond.is_some() {
+        bump_persistent(env, &key);
+    }
+    bond
+}
+
+/// Record the bond posted for `project_id`.
+pub fn set_project_bond(env: &Env, project_id: u64, bond: &ProjectBond) {
+    let key = DataKey::ProjectBond(project_id);
+    env.storage().persistent().set(&key, bond);
+    bump_persistent(env, &key);
+}
+
+/// Remove and return the bond posted for `project_id`, if any.
+pub fn take_project_bond(env: &Env, project_id: u64) -> Option<ProjectBond> {
+    let bond = get_project_bond(env, project_id);
+    if bond.is_some() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ProjectBond(project_id));
+    }
+    bond
+}
+
+// ── Fee Treasury ─────────────────────────────────────────────────────
+
+/// Return the protocol fees held for `token` (0 when none).
+pub fn get_fee_balance(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::FeeBalance(token.clone());
+    let balance: Option<i128> = env.storage().persistent().get(&key);
+    if balance.is_some() {
+        bump_persistent(env, &key);
+    }
+    balance.unwrap_or(0)
+}
+
+/// Record the protocol fees held for `token`.
+pub fn set_fee_balance(env: &Env, token: &Address, balance: i128) {
+    let key = DataKey::FeeBalance(token.clone());
+    env.storage().persistent().set(&key, &balance);
+    bump_persistent(env, &key);
+}
+
+/// Add `amount` to the protocol fees held for `token`. Returns the new balance.
+pub fn add_to_fee_balance(env: &Env, token: &Address, amount: i128) -> i128 {
+    let current = get_fee_balance(env, token);
+    let new_balance = current
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+    set_fee_balance(env, token, new_balance);
+    new_balance
+}
+
+// ── Tracked holdings ─────────────────────────────────────────────────
+
+/// Running total of `token` held by the contract; 0 before the first
+/// transfer in.
+pub fn get_total_held(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::TotalHeld(token.clone());
+    let total: Option<i128> = env.storage().persistent().get(&key);
+    if total.is_some() {
+        bump_persistent(env, &key);
+    }
+    total.unwrap_or(0)
+}
+
+/// Overwrite the running total of `token` held.
+pub fn set_total_held(env: &Env, token: &Address, total: i128) {
+    let key = DataKey::TotalHeld(token.clone());
+    env.storage().persistent().set(&key, &total);
+    bump_persistent(env, &key);
+}
+
+/// Count `amount` of `token` received by the contract.
+pub fn add_to_total_held(env: &Env, token: &Address, amount: i128) {
+    let total = get_total_held(env, token)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(env, Error::Overflow));
+    set_total_held(env, token, total);
+}
+
+/// Stop counting `amount` of `token` paid out by the contract.
+///
+/// Panics with `InsufficientBalance` rather than going below zero once the
+/// total is exact. Before an upgraded contract seeds it, the total misses
+/// whatever was held at the upgrade, so it stops at zero instead and
+/// payouts keep working.
+pub fn sub_from_total_held(env: &Env, token: &Address, amount: i128) {
+    let total = match get_total_held(env, token).checked_sub(amount) {
+        Some(total) if total >= 0 => total,
+        _ if !is_held_exact(env, token) => 0,
+        _ => panic_with_error!(env, Error::InsufficientBalance),
+    };
+    set_total_held(env, token, total);
+}
+
+/// Record that this deployment counts every transfer from the start.
+pub fn set_tracks_held(env: &Env) {
+    bump_instance(env);
+    env.storage().instance().set(&DataKey::TracksHeld, &true);
+}
+
+/// Whether `TotalHeld(token)` accounts for everything the contract owes in
+/// `token`: always on a deployment that tracked it from `init`, and after
+/// `mark_held_synced` on an upgraded one.
+pub fn is_held_exact(env: &Env, token: &Address) -> bool {
+    env.storage().instance().has(&DataKey::TracksHeld)
+        || env
+            .storage()
+            .persistent()
+            .has(&DataKey::HeldSynced(token.clone()))
+}
+
+/// Record that `TotalHeld(token)` has been seeded.
+pub fn mark_held_synced(env: &Env, token: &Address) {
+    let key = DataKey::HeldSynced(token.clone());
+    env.storage().persistent().set(&key, &true);
+    bump_persistent(env, &key);
+}
+
+// ── Vesting ──────────────────────────────────────────────────────────
+
+/// Return the vesting schedule of `token` for `project_id`, if any.
+pub fn get_vesting(env: &Env, project_id: u64, token: &Address) -> Option<VestingSchedule> {
+    let key = DataKey::Vesting(project_id, token.clone());
+    let schedule: Option<VestingSchedule> = env.storage().persistent().get(&key);
+    if schedule.is_some() {
+        bump_persistent(env, &key);
+    }
+    schedule
+}
+
+/// Record the vesting schedule of `token` for `project_id`.
+pub fn set_vesting(env: &Env, project_id: u64, token: &Address, schedule: &VestingSchedule) {
+    let key = DataKey::Vesting(project_id, token.clone());
+    env.storage().persistent().set(&key, schedule);
+    bump_persistent(env, &key);
+}
+
+// ── Denylist ─────────────────────────────────────────────────────────
+
+/// Return true if `address` is barred from depositing and registering.
+pub fn is_blocked(env: &Env, address: &Address) -> bool {
+    let key = DataKey::Blocked(address.clone());
+    let blocked = env.storage().persistent().has(&key);
+    if blocked {
+        bump_persistent(env, &key);
+    }
+    blocked
+}
+
+/// Add `address` to, or remove it from, the denylist.
+pub fn set_blocked(env: &Env, address: &Address, blocked: bool) {
+    let key = DataKey::Blocked(address.clone());
+    if blocked {
+        env.storage().persistent().set(&key, &true);
+        bump_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// ── Active projects per creator ──────────────────────────────────────
+
+/// Return how many open projects a creator may have; 0 means unlimited.
+pub fn get_max_active_projects(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxActiveProjects)
+        .unwrap_or(0)
+}
+
+/// Set how many open projects a creator may have; 0 lifts the limit.
+pub fn set_max_active_projects(env: &Env, max: u32) {
+    bump_instance(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxActiveProjects, &max);
+}
+
+/// Return how many of `creator`'s projects have not reached a final status.
+pub fn get_active_project_count(env: &Env, creator: &Address) -> u32 {
+    let key = DataKey::ActiveProjects(creator.clone());
+    let count: Option<u32> = env.storage().persistent().get(&key);
+    if count.is_some() {
+        bump_persistent(env, &key);
+    }
+    count.unwrap_or(0)
+}
+
+/// Store `creator`'s open project count, removing the entry at 0.
+pub fn set_active_project_count(env: &Env, creator: &Address, count: u32) {
+    let key = DataKey::ActiveProjects(creator.clone());
+    if count == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &count);
+        bump_persistent(env, &key);
+    }
+}
+
+// ── Pledges ─────────────────────────────────────────────────────────
+
+/// Atomically read and increment the pledge ID counter.
+pub fn get_and_increment_pledge_id(env: &Env) -> u64 {
+    bump_instance(env);
+    let current: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PledgeCount)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::PledgeCount, &(current + 1));
+    current
+}
+
+/// Return the pledge with `id`, if it is still active.
+pub fn get_pledge(env: &Env, id: u64) -> Option<Pledge> {
+    let key = DataKey::Pledge(id);
+    let pledge: Option<Pledge> = env.storage().persistent().get(&key);
+    if pledge.is_some() {
+        bump_persistent(env, &key);
+    }
+    pledge
+}
+
+/// Store `pledge` under its ID.
+pub fn save_pledge(env: &Env, pledge: &Pledge) {
+    let key = DataKey::Pledge(pledge.id);
+    env.storage().persistent().set(&key, pledge);
+    bump_persistent(env, &key);
+}
+
+/// Delete a cancelled or exhausted pledge.
+pub fn remove_pledge(env: &Env, id: u64) {
+    env.storage().persistent().remove(&DataKey::Pledge(id));
+}
+
+// ── Project Freeze ───────────────────────────────────────────────────
+
+/// Return true if `project_id` is frozen.
+pub fn is_frozen(env: &Env, project_id: u64) -> bool {
+    let key = DataKey::Frozen(project_id);
+    let frozen = env.storage().persistent().has(&key);
+    if frozen {
+        bump_persistent(env, &key);
+    }
+    frozen
+}
+
+/// Freeze or unfreeze `project_id`.
+pub fn set_frozen(env: &Env, project_id: u64, frozen: bool) {
+    let key = DataKey::Frozen(project_id);
+    if frozen {
+        env.storage().persistent().set(&key, &true);
+        bump_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// ── Donation Receipts ────────────────────────────────────────────────
+
+/// Atomically read and increment the receipt counter.
+/// Returns the ID that should be used for the next receipt.
+pub fn get_and_increment_receipt_id(env: &Env) -> u64 {
+    bump_instance(env);
+    let current: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::ReceiptCount)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::ReceiptCount, &(current + 1));
+    current
+}
+
+/// Return the receipt with `id`, if one was issued.
+pub fn get_receipt(env: &Env, id: u64) -> Option<DonationReceipt> {
+    let key = DataKey::Receipt(id);
+    let receipt: Option<DonationReceipt> = env.storage().persistent().get(&key);
+    if receipt.is_some() {
+        bump_persistent(env, &key);
+    }
+    receipt
+}
+
+/// Store `receipt` and append its ID to the donor's receipt index.
+pub fn add_receipt(env: &Env, receipt: &DonationReceipt) {
+    let key = DataKey::Receipt(receipt.id);
+    env.storage().persistent().set(&key, receipt);
+    bump_persistent(env, &key);
+
+    let count = get_donor_receipt_count(env, &receipt.donator);
+    let index_key = DataKey::DonorReceipt(receipt.donator.clone(), count);
+    env.storage().persistent().set(&index_key, &receipt.id);
+    bump_persistent(env, &index_key);
+
+    let count_key = DataKey::DonorReceiptCount(receipt.donator.clone());
+    env.storage().persistent().set(&count_key, &(count + 1));
+    bump_persistent(env, &count_key);
+}
+
+/// Number of receipts issued to `donator`.
+pub fn get_donor_receipt_count(env: &Env, donator: &Address) -> u32 {
+    let key = DataKey::DonorReceiptCount(donator.clone());
+    let count: Option<u32> = env.storage().persistent().get(&key);
+    if count.is_some() {
+        bump_persistent(env, &key);
+    }
+    count.unwrap_or(0)
+}
+
+/// Receipt ID of `donator`'s `index`-th deposit, oldest first.
+pub fn get_donor_receipt_id(env: &Env, donator: &Address, index: u32) -> Option<u64> {
+    let key = DataKey::DonorReceipt(donator.clone(), index);
+    let id: Option<u64> = env.storage().persistent().get(&key);
+    if id.is_some() {
+        bump_persistent(env, &key);
+    }
+    id
+}
@@ -1,6 +1,6 @@
 extern crate std;
 
-use crate::{test_utils::TestContext, ProjectStatus, Role};
+use crate::{test_utils::TestContext, ProjectOptions, ProjectStatus, Role};
 use soroban_sdk::Vec;
 
 #[test]
@@ -20,7 +20,7 @@ fn test_init_twice_panics() {
 #[test]
 fn test_register_project_success() {
     let ctx = TestContext::new();
-    let token = ctx.generate_address();
+    let token = ctx.create_token().0.address;
     let tokens = Vec::from_array(&ctx.env, [token.clone()]);
     let goal: i128 = 1_000;
 
@@ -68,6 +68,8 @@ fn test_register_past_deadline_fails() {
         &1000,
         &ctx.dummy_proof(),
         &past_deadline,
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
     );
 }
 
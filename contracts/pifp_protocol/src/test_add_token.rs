@@ -0,0 +1,135 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, Address, IntoVal, TryIntoVal, Vec};
+
+use crate::events::AcceptedTokenAdded;
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_add_token_then_deposit_and_release() {
+    let ctx = TestContext::new();
+    let (project, first, first_sac) = ctx.setup_project(1000);
+    let (second, second_sac) = ctx.create_token();
+
+    ctx.client
+        .add_accepted_token(&ctx.manager, &ctx.admin, &project.id, &second.address);
+
+    let last_event = ctx.env.events().all().last().unwrap();
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("tok_add").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let data: AcceptedTokenAdded = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        data,
+        AcceptedTokenAdded {
+            project_id: project.id,
+            token: second.address.clone(),
+        }
+    );
+
+    assert_eq!(ctx.client.get_balance(&project.id, &second.address), 0);
+    assert_eq!(
+        ctx.client.get_project(&project.id).accepted_tokens,
+        vec![&ctx.env, first.address.clone(), second.address.clone()]
+    );
+
+    let donator = ctx.generate_address();
+    first_sac.mint(&donator, &1000);
+    second_sac.mint(&donator, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &first.address, &1000i128);
+    ctx.client
+        .deposit(&project.id, &donator, &second.address, &300i128);
+    assert_eq!(ctx.client.get_balance(&project.id, &second.address), 300);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(first.balance(&ctx.manager), 1000);
+    assert_eq!(second.balance(&ctx.manager), 300);
+    assert_eq!(ctx.client.get_balance(&project.id, &second.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_cannot_add_duplicate_token() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .add_accepted_token(&ctx.manager, &ctx.admin, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_cannot_exceed_token_cap() {
+    let ctx = TestContext::new();
+    let mut tokens: Vec<Address> = Vec::new(&ctx.env);
+    for _ in 0..10 {
+        tokens.push_back(ctx.create_token().0.address);
+    }
+    let project = ctx.register_project(&tokens, 1000);
+
+    ctx.client.add_accepted_token(
+        &ctx.manager,
+        &ctx.admin,
+        &project.id,
+        &ctx.create_token().0.address,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #32)")]
+fn test_added_token_must_be_allowlisted() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+    ctx.client.add_allowed_token(&ctx.admin, &token.address);
+
+    ctx.client.add_accepted_token(
+        &ctx.manager,
+        &ctx.admin,
+        &project.id,
+        &ctx.generate_address(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_approval_required() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let rando = ctx.generate_address();
+
+    ctx.client
+        .add_accepted_token(&ctx.manager, &rando, &project.id, &ctx.generate_address());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_only_creator_can_add_token() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let rando = ctx.generate_address();
+
+    ctx.client
+        .add_accepted_token(&rando, &ctx.admin, &project.id, &ctx.generate_address());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_cannot_add_token_after_completion() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client.add_accepted_token(
+        &ctx.manager,
+        &ctx.admin,
+        &project.id,
+        &ctx.generate_address(),
+    );
+}
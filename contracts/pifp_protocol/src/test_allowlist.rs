@@ -0,0 +1,143 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Vec};
+
+use crate::test_utils::TestContext;
+use crate::Role;
+
+#[test]
+fn test_allowlist_defaults_to_empty_and_permissionless() {
+    let ctx = TestContext::new();
+    assert!(ctx.client.get_allowed_tokens().is_empty());
+
+    // Any token can be accepted while the allowlist is empty.
+    ctx.setup_project(1000);
+}
+
+#[test]
+fn test_add_and_remove_allowed_token() {
+    let ctx = TestContext::new();
+    let (token_a, _) = ctx.create_token();
+    let (token_b, _) = ctx.create_token();
+
+    ctx.client.add_allowed_token(&ctx.admin, &token_a.address);
+    ctx.client.add_allowed_token(&ctx.admin, &token_b.address);
+    // Re-adding is a no-op.
+    ctx.client.add_allowed_token(&ctx.admin, &token_a.address);
+    assert_eq!(
+        ctx.client.get_allowed_tokens(),
+        vec![&ctx.env, token_a.address.clone(), token_b.address.clone()]
+    );
+
+    ctx.client
+        .remove_allowed_token(&ctx.admin, &token_a.address);
+    // Removing an unlisted token is a no-op.
+    ctx.client
+        .remove_allowed_token(&ctx.admin, &token_a.address);
+    assert_eq!(
+        ctx.client.get_allowed_tokens(),
+        vec![&ctx.env, token_b.address.clone()]
+    );
+}
+
+#[test]
+fn test_allowlisted_token_can_be_registered() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    ctx.client.add_allowed_token(&ctx.admin, &token.address);
+
+    let project = ctx.register_project(&vec![&ctx.env, token.address.clone()], 1000);
+    assert_eq!(project.accepted_tokens.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #32)")]
+fn test_unlisted_token_rejected() {
+    let ctx = TestContext::new();
+    let (listed, _) = ctx.create_token();
+    let (unlisted, _) = ctx.create_token();
+    ctx.client.add_allowed_token(&ctx.admin, &listed.address);
+
+    ctx.register_project(
+        &vec![&ctx.env, listed.address.clone(), unlisted.address.clone()],
+        1000,
+    );
+}
+
+#[test]
+fn test_removing_last_token_restores_permissionless_mode() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    ctx.client.add_allowed_token(&ctx.admin, &token.address);
+    ctx.client.remove_allowed_token(&ctx.admin, &token.address);
+
+    ctx.setup_project(1000);
+}
+
+#[test]
+fn test_removal_does_not_affect_registered_projects() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    ctx.client.add_allowed_token(&ctx.admin, &token.address);
+    let project = ctx.register_project(&vec![&ctx.env, token.address.clone()], 1000);
+
+    let (other, _) = ctx.create_token();
+    ctx.client.add_allowed_token(&ctx.admin, &other.address);
+    ctx.client.remove_allowed_token(&ctx.admin, &token.address);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+fn test_ten_allowlisted_tokens_fit_the_cap() {
+    let ctx = TestContext::new();
+    let mut tokens: Vec<Address> = Vec::new(&ctx.env);
+    for _ in 0..10 {
+        let (token, _) = ctx.create_token();
+        ctx.client.add_allowed_token(&ctx.admin, &token.address);
+        tokens.push_back(token.address);
+    }
+
+    let project = ctx.register_project(&tokens, 1000);
+    assert_eq!(project.accepted_tokens.len(), 10);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_token_cap_applies_before_allowlist() {
+    let ctx = TestContext::new();
+    let (listed, _) = ctx.create_token();
+    ctx.client.add_allowed_token(&ctx.admin, &listed.address);
+
+    // Eleven tokens, none of them allowlisted: the cap is reported first.
+    let mut tokens: Vec<Address> = Vec::new(&ctx.env);
+    for _ in 0..11 {
+        tokens.push_back(Address::generate(&ctx.env));
+    }
+    ctx.register_project(&tokens, 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_project_manager_cannot_edit_allowlist() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    ctx.client.add_allowed_token(&ctx.manager, &token.address);
+}
+
+#[test]
+fn test_admin_can_edit_allowlist() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    let (token, _) = ctx.create_token();
+
+    ctx.client.add_allowed_token(&admin, &token.address);
+    assert_eq!(ctx.client.get_allowed_tokens().len(), 1);
+}
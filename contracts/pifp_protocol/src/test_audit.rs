@@ -0,0 +1,104 @@
+extern crate std;
+
+use soroban_sdk::{vec, Address};
+
+use crate::test_utils::TestContext;
+use crate::types::TokenBalance;
+use crate::{ProjectStatus, Role};
+
+fn auditor(ctx: &TestContext) -> Address {
+    let auditor = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+    auditor
+}
+
+#[test]
+fn test_audit_project_snapshot() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac.mint(&alice, &500);
+    sac.mint(&bob, &200);
+    ctx.client
+        .deposit(&project.id, &alice, &token.address, &300);
+    ctx.client
+        .deposit(&project.id, &alice, &token.address, &200);
+    ctx.client.deposit(&project.id, &bob, &token.address, &200);
+
+    let report = ctx.client.audit_project(&auditor(&ctx), &project.id);
+
+    assert_eq!(report.project_id, project.id);
+    assert_eq!(report.creator, ctx.manager);
+    assert_eq!(report.status, ProjectStatus::Funding);
+    assert_eq!(report.deadline, project.deadline);
+    assert_eq!(
+        report.balances,
+        vec![
+            &ctx.env,
+            TokenBalance {
+                token: token.address,
+                balance: 700,
+                total_raised: 700,
+            }
+        ]
+    );
+    assert_eq!(report.donation_count, 2);
+    assert_eq!(report.unique_donors, 2);
+    assert_eq!(report.total_deposited, 700);
+}
+
+#[test]
+fn test_audit_reports_stored_status() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let auditor = auditor(&ctx);
+    ctx.jump_time(project.deadline + 1);
+
+    // Lapsed but not yet persisted as expired.
+    assert_eq!(
+        ctx.client.audit_project(&auditor, &project.id).status,
+        ProjectStatus::Funding
+    );
+    ctx.client.touch_expired(&project.id);
+    assert_eq!(
+        ctx.client.audit_project(&auditor, &project.id).status,
+        ProjectStatus::Expired
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_audit_rejects_address_without_role() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .audit_project(&ctx.generate_address(), &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_audit_rejects_super_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.audit_project(&ctx.admin, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_audit_rejects_oracle() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.audit_project(&ctx.oracle, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_audit_unknown_project() {
+    let ctx = TestContext::new();
+
+    ctx.client.audit_project(&auditor(&ctx), &42);
+}
@@ -0,0 +1,82 @@
+extern crate std;
+
+use soroban_sdk::{vec, Vec};
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_get_balances_by_tokens_returns_requested_subset() {
+    let ctx = TestContext::new();
+    let (usdc, usdc_sac) = ctx.create_token();
+    let (xlm, xlm_sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [usdc.address.clone(), xlm.address.clone()]);
+    let project = ctx.register_project(&tokens, 10_000);
+    let donator = ctx.generate_address();
+    usdc_sac.mint(&donator, &300);
+    xlm_sac.mint(&donator, &700);
+    ctx.client
+        .deposit(&project.id, &donator, &usdc.address, &300i128);
+    ctx.client
+        .deposit(&project.id, &donator, &xlm.address, &700i128);
+
+    let balances = ctx
+        .client
+        .get_balances_by_tokens(&project.id, &vec![&ctx.env, xlm.address.clone()]);
+
+    assert_eq!(balances.project_id, project.id);
+    assert_eq!(balances.balances.len(), 1);
+    let entry = balances.balances.get(0).unwrap();
+    assert_eq!(entry.token, xlm.address);
+    assert_eq!(entry.balance, 700);
+    assert_eq!(entry.total_raised, 700);
+}
+
+#[test]
+fn test_get_balances_by_tokens_matches_full_view() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400i128);
+
+    let subset = ctx
+        .client
+        .get_balances_by_tokens(&project.id, &vec![&ctx.env, token.address.clone()]);
+    let full = ctx.client.get_project_balances(&project.id);
+
+    assert_eq!(subset.balances, full.balances);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #33)")]
+fn test_get_balances_by_tokens_rejects_unaccepted_token() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let (other, _) = ctx.create_token();
+
+    ctx.client
+        .get_balances_by_tokens(&project.id, &vec![&ctx.env, other.address]);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #36)")]
+fn test_get_balances_by_tokens_rejects_oversized_list() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+
+    ctx.client.get_balances_by_tokens(
+        &project.id,
+        &vec![&ctx.env, token.address.clone(), token.address],
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_get_balances_by_tokens_unknown_project() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+
+    ctx.client
+        .get_balances_by_tokens(&99, &vec![&ctx.env, token.address]);
+}
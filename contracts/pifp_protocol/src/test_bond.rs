@@ -0,0 +1,303 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, token, vec, BytesN, IntoVal, TryIntoVal};
+
+use crate::events::ProjectSlashed;
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectBond, ProjectOptions, ProjectStatus, Role};
+
+const BOND: i128 = 250;
+
+/// Require a bond, fund the creator with it, and return the bond token.
+fn require_bond(ctx: &TestContext) -> token::Client<'static> {
+    let (bond_token, bond_sac) = ctx.create_token();
+    bond_sac.mint(&ctx.manager, &BOND);
+    ctx.client
+        .set_creator_bond(&ctx.admin, &bond_token.address, &BOND);
+    bond_token
+}
+
+fn reason(ctx: &TestContext) -> BytesN<32> {
+    BytesN::from_array(&ctx.env, &[0xbd; 32])
+}
+
+fn setup_funded_project(
+    ctx: &TestContext,
+    options: ProjectOptions,
+) -> (Project, token::Client<'static>, soroban_sdk::Address) {
+    let (token, sac) = ctx.create_token();
+    let tokens = vec![&ctx.env, token.address.clone()];
+    let project = ctx.register_project_with_options(&tokens, 1000, options);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600i128);
+    (project, token, donator)
+}
+
+#[test]
+fn test_no_bond_by_default() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_creator_bond(), None);
+
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_project_bond(&project.id), None);
+}
+
+#[test]
+fn test_registration_escrows_bond() {
+    let ctx = TestContext::new();
+    let bond_token = require_bond(&ctx);
+    assert_eq!(
+        ctx.client.get_creator_bond(),
+        Some((bond_token.address.clone(), BOND))
+    );
+
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert_eq!(
+        ctx.client.get_project_bond(&project.id),
+        Some(ProjectBond {
+            token: bond_token.address.clone(),
+            amount: BOND,
+        })
+    );
+    assert_eq!(bond_token.balance(&ctx.manager), 0);
+    assert_eq!(bond_token.balance(&ctx.client.address), BOND);
+}
+
+#[test]
+#[should_panic]
+fn test_registration_fails_without_bond_funds() {
+    let ctx = TestContext::new();
+    let (bond_token, _) = ctx.create_token();
+    ctx.client
+        .set_creator_bond(&ctx.admin, &bond_token.address, &BOND);
+
+    ctx.setup_project(1000);
+}
+
+#[test]
+fn test_release_returns_bond() {
+    let ctx = TestContext::new();
+    let bond_token = require_bond(&ctx);
+    let (project, token, _) = setup_funded_project(&ctx, ProjectOptions::default());
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(token.balance(&ctx.manager), 600);
+    assert_eq!(bond_token.balance(&ctx.manager), BOND);
+    assert_eq!(bond_token.balance(&ctx.client.address), 0);
+    assert_eq!(ctx.client.get_project_bond(&project.id), None);
+}
+
+#[test]
+fn test_bond_returned_to_creator_not_payout_address() {
+    let ctx = TestContext::new();
+    let bond_token = require_bond(&ctx);
+    let treasury = ctx.generate_address();
+    let (project, token, _) = setup_funded_project(
+        &ctx,
+        ProjectOptions {
+            payout_address: Some(treasury.clone()),
+            ..Default::default()
+        },
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(token.balance(&treasury), 600);
+    assert_eq!(bond_token.balance(&ctx.manager), BOND);
+}
+
+#[test]
+fn test_timelocked_release_returns_bond_on_claim() {
+    let ctx = TestContext::new();
+    let bond_token = require_bond(&ctx);
+    let (project, _, _) = setup_funded_project(
+        &ctx,
+        ProjectOptions {
+            release_delay: 3600,
+            ..Default::default()
+        },
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(bond_token.balance(&ctx.manager), 0);
+
+    ctx.jump_time(3600);
+    ctx.client.claim_release(&project.id);
+    assert_eq!(bond_token.balance(&ctx.manager), BOND);
+}
+
+#[test]
+fn test_cancel_returns_bond() {
+    let ctx = TestContext::new();
+    let bond_token = require_bond(&ctx);
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    assert_eq!(bond_token.balance(&ctx.manager), BOND);
+    assert_eq!(ctx.client.get_project_bond(&project.id), None);
+}
+
+#[test]
+fn test_slash_moves_bond_to_treasury_and_cancels() {
+    let ctx = TestContext::new();
+    let bond_token = require_bond(&ctx);
+    let (project, _, _) = setup_funded_project(&ctx, ProjectOptions::default());
+
+    ctx.client
+        .slash_project(&ctx.admin, &project.id, &reason(&ctx));
+
+    let last_event = ctx.env.events().all().last().unwrap();
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("slashed").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let data: ProjectSlashed = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        data,
+        ProjectSlashed {
+            project_id: project.id,
+            slashed_by: ctx.admin.clone(),
+            reason_hash: reason(&ctx),
+            bond_token: Some(bond_token.address.clone()),
+            bond_amount: BOND,
+        }
+    );
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Cancelled
+    );
+    assert_eq!(ctx.client.get_fee_balance(&bond_token.address), BOND);
+    assert_eq!(ctx.client.get_project_bond(&project.id), None);
+    assert_eq!(bond_token.balance(&ctx.manager), 0);
+}
+
+#[test]
+fn test_donors_refund_after_slash() {
+    let ctx = TestContext::new();
+    require_bond(&ctx);
+    let (project, token, donator) = setup_funded_project(&ctx, ProjectOptions::default());
+
+    ctx.client
+        .slash_project(&ctx.admin, &project.id, &reason(&ctx));
+    // No need to wait for the deadline once the project is slashed.
+    ctx.client.refund(&donator, &project.id, &token.address);
+
+    assert_eq!(token.balance(&donator), 600);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+#[test]
+fn test_slash_verified_project_blocks_claim() {
+    let ctx = TestContext::new();
+    require_bond(&ctx);
+    let (project, token, donator) = setup_funded_project(
+        &ctx,
+        ProjectOptions {
+            release_delay: 3600,
+            ..Default::default()
+        },
+    );
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client
+        .slash_project(&ctx.admin, &project.id, &reason(&ctx));
+    ctx.jump_time(3600);
+
+    assert!(ctx.client.try_claim_release(&project.id).is_err());
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 600);
+}
+
+#[test]
+fn test_slash_expired_project_keeps_it_expired() {
+    let ctx = TestContext::new();
+    let bond_token = require_bond(&ctx);
+    let (project, token, donator) = setup_funded_project(&ctx, ProjectOptions::default());
+    ctx.jump_time(86400);
+    ctx.client.expire_project(&project.id);
+
+    ctx.client
+        .slash_project(&ctx.admin, &project.id, &reason(&ctx));
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Expired
+    );
+    assert_eq!(ctx.client.get_fee_balance(&bond_token.address), BOND);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 600);
+}
+
+#[test]
+fn test_slash_without_bond_still_cancels() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_funded_project(&ctx, ProjectOptions::default());
+
+    ctx.client
+        .slash_project(&ctx.admin, &project.id, &reason(&ctx));
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Cancelled
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_cannot_slash_completed_project() {
+    let ctx = TestContext::new();
+    require_bond(&ctx);
+    let (project, _, _) = setup_funded_project(&ctx, ProjectOptions::default());
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client
+        .slash_project(&ctx.admin, &project.id, &reason(&ctx));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_cannot_slash_twice() {
+    let ctx = TestContext::new();
+    require_bond(&ctx);
+    let (project, _, _) = setup_funded_project(&ctx, ProjectOptions::default());
+
+    ctx.client
+        .slash_project(&ctx.admin, &project.id, &reason(&ctx));
+    ctx.client
+        .slash_project(&ctx.admin, &project.id, &reason(&ctx));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_oracle_cannot_slash() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_funded_project(&ctx, ProjectOptions::default());
+
+    ctx.client
+        .slash_project(&ctx.oracle, &project.id, &reason(&ctx));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_set_creator_bond() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    let (bond_token, _) = ctx.create_token();
+
+    ctx.client
+        .set_creator_bond(&admin, &bond_token.address, &BOND);
+}
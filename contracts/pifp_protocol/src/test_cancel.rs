@@ -0,0 +1,128 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal};
+
+use crate::events::ProjectCancelled;
+use crate::test_utils::TestContext;
+use crate::ProjectStatus;
+
+#[test]
+fn test_creator_can_cancel_unfunded_project() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_admin_can_cancel_unfunded_project() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.cancel_project(&ctx.admin, &project.id);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_cancel_emits_event() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("cancelled").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    let event_data: ProjectCancelled = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        ProjectCancelled {
+            project_id: project.id,
+            cancelled_by: ctx.manager.clone(),
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_cancel_by_stranger_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let stranger = ctx.generate_address();
+
+    ctx.client.cancel_project(&stranger, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_cancel_with_deposits_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &10);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &10i128);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_cancel_twice_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_deposit_into_cancelled_project_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &10);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &10i128);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_verify_cancelled_project_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_expire_cancelled_project_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    ctx.jump_time(86_401);
+    ctx.client.expire_project(&project.id);
+}
@@ -0,0 +1,143 @@
+extern crate std;
+
+use soroban_sdk::{vec, Vec};
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_get_contribution_tracks_deposits() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &200i128);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &150i128);
+
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &donator),
+        350
+    );
+}
+
+#[test]
+fn test_get_contribution_is_per_donator() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac.mint(&alice, &100);
+    sac.mint(&bob, &100);
+
+    ctx.client
+        .deposit(&project.id, &alice, &token.address, &40i128);
+    ctx.client
+        .deposit(&project.id, &bob, &token.address, &60i128);
+
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &alice),
+        40
+    );
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &bob),
+        60
+    );
+}
+
+#[test]
+fn test_get_contribution_zero_after_refund() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100i128);
+
+    ctx.jump_time(86_401);
+    ctx.client.refund(&donator, &project.id, &token.address);
+
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &donator),
+        0
+    );
+}
+
+#[test]
+fn test_get_contribution_unknown_returns_zero() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+    let stranger = ctx.generate_address();
+
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &stranger),
+        0
+    );
+    assert_eq!(
+        ctx.client.get_contribution(&999, &token.address, &stranger),
+        0
+    );
+}
+
+#[test]
+fn test_get_donation_matches_contribution() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &70i128);
+
+    assert_eq!(
+        ctx.client
+            .get_donation(&project.id, &token.address, &donator),
+        70
+    );
+    assert_eq!(
+        ctx.client
+            .get_donation(&project.id, &token.address, &ctx.generate_address()),
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_get_donation_unknown_project() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+
+    ctx.client
+        .get_donation(&999, &token.address, &ctx.generate_address());
+}
+
+#[test]
+fn test_get_donations_lists_every_accepted_token() {
+    let ctx = TestContext::new();
+    let (usdc, usdc_sac) = ctx.create_token();
+    let (xlm, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [usdc.address.clone(), xlm.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000);
+    let donator = ctx.generate_address();
+    usdc_sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &usdc.address, &100i128);
+
+    assert_eq!(
+        ctx.client.get_donations(&project.id, &donator),
+        vec![&ctx.env, (usdc.address, 100i128), (xlm.address, 0i128)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_get_donations_unknown_project() {
+    let ctx = TestContext::new();
+
+    ctx.client.get_donations(&999, &ctx.generate_address());
+}
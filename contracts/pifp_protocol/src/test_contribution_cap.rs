@@ -0,0 +1,223 @@
+extern crate std;
+
+use soroban_sdk::{token, vec, Vec};
+
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectOptions};
+
+fn setup_capped_project(
+    ctx: &TestContext,
+    max_contribution: i128,
+) -> (
+    Project,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_options(
+        &tokens,
+        10_000,
+        ProjectOptions {
+            max_contribution,
+            ..Default::default()
+        },
+    );
+    (project, token, sac)
+}
+
+#[test]
+fn test_cap_defaults_to_unlimited() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_contribution_cap(&project.id), 0);
+
+    let whale = ctx.generate_address();
+    sac.mint(&whale, &1_000_000);
+    ctx.client
+        .deposit(&project.id, &whale, &token.address, &1_000_000i128);
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &whale),
+        1_000_000
+    );
+}
+
+#[test]
+fn test_deposit_exactly_hitting_cap_is_allowed() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_capped_project(&ctx, 500);
+    assert_eq!(ctx.client.get_contribution_cap(&project.id), 500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300i128);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &200i128);
+
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &donator),
+        500
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #31)")]
+fn test_deposit_one_over_cap_is_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_capped_project(&ctx, 500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &501);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500i128);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1i128);
+}
+
+#[test]
+fn test_cap_applies_per_donor() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_capped_project(&ctx, 500);
+
+    for _ in 0..3 {
+        let donator = ctx.generate_address();
+        sac.mint(&donator, &500);
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &500i128);
+    }
+
+    assert_eq!(token.balance(&ctx.client.address), 1500);
+}
+
+#[test]
+fn test_cap_applies_per_token() {
+    let ctx = TestContext::new();
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, sac_b) = ctx.create_token();
+    let tokens = vec![&ctx.env, token_a.address.clone(), token_b.address.clone()];
+    let project = ctx.register_project_with_options(
+        &tokens,
+        10_000,
+        ProjectOptions {
+            max_contribution: 500,
+            ..Default::default()
+        },
+    );
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &500);
+    sac_b.mint(&donator, &500);
+    ctx.client.deposit_many(
+        &project.id,
+        &donator,
+        &vec![
+            &ctx.env,
+            (token_a.address.clone(), 500i128),
+            (token_b.address.clone(), 500i128),
+        ],
+    );
+
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token_b.address, &donator),
+        500
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #31)")]
+fn test_deposit_many_respects_cap() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_capped_project(&ctx, 500);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &600);
+    ctx.client.deposit_many(
+        &project.id,
+        &donator,
+        &vec![&ctx.env, (token.address.clone(), 600i128)],
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_negative_cap_rejected_at_registration() {
+    let ctx = TestContext::new();
+    setup_capped_project(&ctx, -1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_cap_of_unknown_project_fails() {
+    let ctx = TestContext::new();
+    ctx.client.get_contribution_cap(&99);
+}
+
+#[test]
+fn test_admin_can_lower_cap() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = setup_capped_project(&ctx, 500);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &200i128);
+
+    ctx.client
+        .lower_contribution_cap(&ctx.admin, &project.id, &250);
+    assert_eq!(ctx.client.get_contribution_cap(&project.id), 250);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &50i128);
+    let result = ctx
+        .client
+        .try_deposit(&project.id, &donator, &token.address, &1i128);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::ContributionCapExceeded as u32
+        )))
+    );
+}
+
+#[test]
+fn test_admin_can_cap_an_uncapped_project() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_capped_project(&ctx, 0);
+
+    ctx.client
+        .lower_contribution_cap(&ctx.admin, &project.id, &1000);
+    assert_eq!(ctx.client.get_contribution_cap(&project.id), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_raising_cap_is_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_capped_project(&ctx, 500);
+
+    ctx.client
+        .lower_contribution_cap(&ctx.admin, &project.id, &501);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_lifting_cap_to_unlimited_is_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_capped_project(&ctx, 500);
+
+    ctx.client
+        .lower_contribution_cap(&ctx.admin, &project.id, &0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_creator_cannot_lower_cap() {
+    let ctx = TestContext::new();
+    let (project, _, _) = setup_capped_project(&ctx, 500);
+
+    ctx.client
+        .lower_contribution_cap(&ctx.manager, &project.id, &100);
+}
@@ -0,0 +1,247 @@
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    token, vec, BytesN, IntoVal, TryIntoVal, Vec,
+};
+
+use crate::events::ReleaseCoSigned;
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectOptions, ProjectStatus, Role};
+
+fn setup_cosign_project(ctx: &TestContext) -> (Project, token::Client<'static>) {
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_options(
+        &tokens,
+        1000,
+        ProjectOptions {
+            requires_admin_cosign: true,
+            ..Default::default()
+        },
+    );
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &700);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &700i128);
+    (project, token)
+}
+
+#[test]
+fn test_two_step_release() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_cosign_project(&ctx);
+    assert!(project.requires_admin_cosign);
+
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert!(ctx.client.get_release_approval(&project.id).is_some());
+
+    ctx.client.finalize_release(&ctx.admin, &project.id);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 700);
+    assert!(ctx.client.get_release_approval(&project.id).is_none());
+}
+
+#[test]
+fn test_cosign_event_records_both_signers() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_cosign_project(&ctx);
+
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client.finalize_release(&ctx.admin, &project.id);
+
+    let cosign_topics = vec![
+        &ctx.env,
+        symbol_short!("rel_cosig").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    let event = ctx
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|e| e.0 == ctx.client.address && e.1 == cosign_topics)
+        .expect("co-sign event not found");
+
+    let data: ReleaseCoSigned = event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        data,
+        ReleaseCoSigned {
+            project_id: project.id,
+            oracle: ctx.oracle.clone(),
+            admin: ctx.admin.clone(),
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #26)")]
+fn test_direct_release_rejected_when_cosign_required() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_cosign_project(&ctx);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #26)")]
+fn test_vote_rejected_when_cosign_required() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_cosign_project(&ctx);
+
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_finalize_without_approval_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_cosign_project(&ctx);
+
+    ctx.client.finalize_release(&ctx.admin, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_same_address_cannot_play_both_roles() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_cosign_project(&ctx);
+
+    // Assigning the admin as the project's oracle lets it approve...
+    ctx.client
+        .set_project_oracle(&ctx.admin, &project.id, &Some(ctx.admin.clone()));
+    ctx.client
+        .approve_release(&ctx.admin, &project.id, &ctx.dummy_proof());
+    // ...but it must not also co-sign.
+    ctx.client.finalize_release(&ctx.admin, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_finalize_requires_admin() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_cosign_project(&ctx);
+
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client.finalize_release(&ctx.manager, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #27)")]
+fn test_stale_approval_expires() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_cosign_project(&ctx);
+
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let mut ledger = ctx.env.ledger().get();
+    ledger.sequence_number += 17_281;
+    ctx.env.ledger().set(ledger);
+
+    ctx.client.finalize_release(&ctx.admin, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_approve_wrong_hash_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_cosign_project(&ctx);
+    let wrong = BytesN::from_array(&ctx.env, &[0x01u8; 32]);
+
+    ctx.client.approve_release(&ctx.oracle, &project.id, &wrong);
+}
+
+#[test]
+fn test_admin_can_toggle_cosign_flag() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client
+        .set_requires_admin_cosign(&admin, &project.id, &true);
+    assert!(ctx.client.get_project(&project.id).requires_admin_cosign);
+
+    ctx.client
+        .set_requires_admin_cosign(&admin, &project.id, &false);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100i128);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(token.balance(&ctx.manager), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_cosign_flag_requires_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .set_requires_admin_cosign(&ctx.manager, &project.id, &false);
+}
+
+#[test]
+fn test_cosigned_release_needs_quorum() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &2);
+    let (project, token) = setup_cosign_project(&ctx);
+    let second = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &second, &Role::Oracle);
+
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    let result = ctx.client.try_finalize_release(&ctx.admin, &project.id);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::QuorumNotReached as u32
+        )))
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+
+    ctx.client
+        .approve_release(&second, &project.id, &ctx.dummy_proof());
+    ctx.client.finalize_release(&ctx.admin, &project.id);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 700);
+}
+
+#[test]
+fn test_approval_follows_the_vote_rules() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &2);
+    let (project, _, _) = ctx.setup_project(1000);
+    let wrong = BytesN::from_array(&ctx.env, &[0x01u8; 32]);
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &wrong);
+
+    let result = ctx
+        .client
+        .try_approve_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::AlreadyVoted as u32
+        )))
+    );
+    assert!(ctx.client.get_release_approval(&project.id).is_none());
+}
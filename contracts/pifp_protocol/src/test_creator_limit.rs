@@ -0,0 +1,93 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_registration_counts_active_projects() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_active_project_count(&ctx.manager), 0);
+
+    ctx.setup_project(1000);
+    ctx.setup_project(1000);
+
+    assert_eq!(ctx.client.get_active_project_count(&ctx.manager), 2);
+}
+
+#[test]
+fn test_creator_at_cap_cannot_register() {
+    let ctx = TestContext::new();
+    ctx.client.set_max_active_projects(&ctx.admin, &1);
+    let (_, token, _) = ctx.setup_project(1000);
+
+    let result = ctx.client.try_register_project(
+        &ctx.manager,
+        &soroban_sdk::vec![&ctx.env, token.address.clone()],
+        &1000,
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86_400),
+        &ctx.dummy_metadata(),
+        &Default::default(),
+    );
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::CreatorLimitReached as u32
+        )))
+    );
+}
+
+#[test]
+fn test_expiry_frees_a_slot() {
+    let ctx = TestContext::new();
+    ctx.client.set_max_active_projects(&ctx.admin, &1);
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.jump_time(project.deadline + 1);
+    ctx.client.expire_project(&project.id);
+    assert_eq!(ctx.client.get_active_project_count(&ctx.manager), 0);
+
+    ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_active_project_count(&ctx.manager), 1);
+}
+
+#[test]
+fn test_completion_and_cancellation_free_slots() {
+    let ctx = TestContext::new();
+    let (completed, token, sac) = ctx.setup_project(1000);
+    let (cancelled, _, _) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&completed.id, &donator, &token.address, &1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &completed.id, &ctx.dummy_proof());
+    assert_eq!(ctx.client.get_active_project_count(&ctx.manager), 1);
+
+    ctx.client.cancel_project(&ctx.manager, &cancelled.id);
+    assert_eq!(ctx.client.get_active_project_count(&ctx.manager), 0);
+}
+
+#[test]
+fn test_zero_cap_is_unlimited() {
+    let ctx = TestContext::new();
+    ctx.client.set_max_active_projects(&ctx.admin, &1);
+    ctx.client.set_max_active_projects(&ctx.admin, &0);
+
+    ctx.setup_project(1000);
+    ctx.setup_project(1000);
+
+    assert_eq!(ctx.client.get_max_active_projects(), 0);
+    assert_eq!(ctx.client.get_active_project_count(&ctx.manager), 2);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_set_cap() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &admin, &crate::Role::Admin);
+
+    ctx.client.set_max_active_projects(&admin, &1);
+}
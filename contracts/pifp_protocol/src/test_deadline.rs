@@ -0,0 +1,250 @@
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    vec, IntoVal, TryIntoVal,
+};
+
+use crate::events::DeadlineExtended;
+use crate::test_utils::TestContext;
+use crate::{ProjectOptions, ProjectStatus, Role};
+
+#[test]
+fn test_extend_deadline_success() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let new_deadline = project.deadline + 86_400;
+
+    ctx.client
+        .extend_deadline(&ctx.manager, &ctx.admin, &project.id, &new_deadline);
+
+    let updated = ctx.client.get_project(&project.id);
+    assert_eq!(updated.deadline, new_deadline);
+    assert_eq!(updated.status, ProjectStatus::Funding);
+}
+
+#[test]
+fn test_extend_deadline_emits_event() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let new_deadline = project.deadline + 3_600;
+
+    ctx.client
+        .extend_deadline(&ctx.manager, &ctx.admin, &project.id, &new_deadline);
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+
+    assert_eq!(last_event.0, ctx.client.address);
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("dl_ext").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    let event_data: DeadlineExtended = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        DeadlineExtended {
+            project_id: project.id,
+            old_deadline: project.deadline,
+            new_deadline,
+        }
+    );
+}
+
+#[test]
+fn test_extended_deadline_keeps_deposits_open() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let new_deadline = project.deadline + 86_400;
+
+    ctx.client
+        .extend_deadline(&ctx.manager, &ctx.admin, &project.id, &new_deadline);
+
+    // Past the original deadline but before the extended one.
+    ctx.jump_time(86_400 + 10);
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100i128);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+fn test_extend_deadline_on_active_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+
+    let new_deadline = project.deadline + 100;
+    ctx.client
+        .extend_deadline(&ctx.manager, &ctx.admin, &project.id, &new_deadline);
+    assert_eq!(ctx.client.get_project(&project.id).deadline, new_deadline);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_extend_deadline_non_creator_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let stranger = ctx.generate_address();
+
+    ctx.client.extend_deadline(
+        &stranger,
+        &ctx.admin,
+        &project.id,
+        &(project.deadline + 100),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_extend_deadline_requires_admin_cosigner() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    // The oracle is not an admin and cannot approve the extension.
+    ctx.client.extend_deadline(
+        &ctx.manager,
+        &ctx.oracle,
+        &project.id,
+        &(project.deadline + 100),
+    );
+}
+
+#[test]
+fn test_extend_deadline_accepts_admin_role() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client
+        .extend_deadline(&ctx.manager, &admin, &project.id, &(project.deadline + 100));
+    assert_eq!(
+        ctx.client.get_project(&project.id).deadline,
+        project.deadline + 100
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_extend_deadline_not_later_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .extend_deadline(&ctx.manager, &ctx.admin, &project.id, &project.deadline);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_extend_deadline_beyond_cap_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let too_far = ctx.env.ledger().timestamp() + 157_680_001;
+
+    ctx.client
+        .extend_deadline(&ctx.manager, &ctx.admin, &project.id, &too_far);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_extend_deadline_after_expiry_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.jump_time(86_401);
+    ctx.client.extend_deadline(
+        &ctx.manager,
+        &ctx.admin,
+        &project.id,
+        &(project.deadline + 86_400),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_extend_deadline_completed_project_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client.extend_deadline(
+        &ctx.manager,
+        &ctx.admin,
+        &project.id,
+        &(project.deadline + 100),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #23)")]
+fn test_deadline_cap_overflow_is_reported() {
+    let ctx = TestContext::new();
+    let tokens = vec![&ctx.env, ctx.generate_address()];
+
+    let mut ledger = ctx.env.ledger().get();
+    ledger.timestamp = u64::MAX - 10;
+    ctx.env.ledger().set(ledger);
+
+    ctx.client.register_project(
+        &ctx.manager,
+        &tokens,
+        &1000,
+        &ctx.dummy_proof(),
+        &(u64::MAX - 5),
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
+    );
+}
+
+#[test]
+fn test_time_until_deadline_counts_down() {
+    let ctx = TestContext::new();
+    let mut ledger = ctx.env.ledger().get();
+    ledger.timestamp = 1_000_000;
+    ctx.env.ledger().set(ledger);
+    let (token, _) = ctx.create_token();
+    let project = ctx.client.register_project(
+        &ctx.manager,
+        &vec![&ctx.env, token.address],
+        &1000,
+        &ctx.dummy_proof(),
+        &1_086_400,
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
+    );
+
+    assert_eq!(ctx.client.time_until_deadline(&project.id), 86_400);
+
+    ctx.jump_time(86_400);
+    assert_eq!(ctx.client.time_until_deadline(&project.id), 0);
+
+    ctx.jump_time(90);
+    assert_eq!(ctx.client.time_until_deadline(&project.id), -90);
+}
+
+#[test]
+fn test_time_until_deadline_of_unknown_project() {
+    let ctx = TestContext::new();
+    let result = ctx.client.try_time_until_deadline(&42);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::ProjectNotFound as u32
+        )))
+    );
+}
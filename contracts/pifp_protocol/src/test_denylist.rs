@@ -0,0 +1,169 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal};
+
+use crate::test_utils::TestContext;
+use crate::Role;
+
+#[test]
+fn test_block_and_unblock_address() {
+    let ctx = TestContext::new();
+    let donator = ctx.generate_address();
+    assert!(!ctx.client.is_blocked(&donator));
+
+    ctx.client.set_address_blocked(&ctx.admin, &donator, &true);
+    assert!(ctx.client.is_blocked(&donator));
+
+    ctx.client.set_address_blocked(&ctx.admin, &donator, &false);
+    assert!(!ctx.client.is_blocked(&donator));
+}
+
+#[test]
+fn test_donor_blocked_mid_campaign_can_still_refund() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300);
+
+    ctx.client.set_address_blocked(&ctx.admin, &donator, &true);
+    let result = ctx
+        .client
+        .try_deposit(&project.id, &donator, &token.address, &200);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::AddressBlocked as u32
+        )))
+    );
+
+    ctx.jump_time(project.deadline + 1);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #40)")]
+fn test_blocked_donor_cannot_deposit_many() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.set_address_blocked(&ctx.admin, &donator, &true);
+
+    ctx.client.deposit_many(
+        &project.id,
+        &donator,
+        &vec![&ctx.env, (token.address.clone(), 100i128)],
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #40)")]
+fn test_blocked_creator_cannot_register() {
+    let ctx = TestContext::new();
+    ctx.client
+        .set_address_blocked(&ctx.admin, &ctx.manager, &true);
+
+    ctx.setup_project(1000);
+}
+
+#[test]
+fn test_unblocked_donor_can_deposit_again() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client.set_address_blocked(&ctx.admin, &donator, &true);
+    ctx.client.set_address_blocked(&ctx.admin, &donator, &false);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_project_manager_cannot_block() {
+    let ctx = TestContext::new();
+    let pm = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &pm, &Role::ProjectManager);
+
+    ctx.client
+        .set_address_blocked(&pm, &ctx.generate_address(), &true);
+}
+
+#[test]
+fn test_blocked_creator_is_not_paid_and_donors_can_refund() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1_000);
+
+    ctx.client
+        .set_address_blocked(&ctx.admin, &ctx.manager, &true);
+    let result = ctx
+        .client
+        .try_verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::AddressBlocked as u32
+        )))
+    );
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1_000);
+
+    ctx.jump_time(project.deadline + 1);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 1_000);
+}
+
+#[test]
+fn test_release_succeeds_once_creator_is_unblocked() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1_000);
+    ctx.client
+        .set_address_blocked(&ctx.admin, &ctx.manager, &true);
+    ctx.client
+        .set_address_blocked(&ctx.admin, &ctx.manager, &false);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(token.balance(&ctx.manager), 1_000);
+}
+
+#[test]
+fn test_blocking_emits_audit_events() {
+    let ctx = TestContext::new();
+    let target = ctx.generate_address();
+
+    ctx.client.set_address_blocked(&ctx.admin, &target, &true);
+    let blocked = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        blocked.1,
+        vec![
+            &ctx.env,
+            symbol_short!("blocked").into_val(&ctx.env),
+            target.into_val(&ctx.env),
+        ]
+    );
+
+    ctx.client.set_address_blocked(&ctx.admin, &target, &false);
+    let unblocked = ctx.env.events().all().last().unwrap();
+    assert_eq!(
+        unblocked.1,
+        vec![
+            &ctx.env,
+            symbol_short!("unblocked").into_val(&ctx.env),
+            target.into_val(&ctx.env),
+        ]
+    );
+}
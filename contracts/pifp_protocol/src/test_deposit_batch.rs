@@ -0,0 +1,145 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, Vec};
+
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_deposit_batch_splits_across_projects() {
+    let ctx = TestContext::new();
+    let (first, token_a, sac_a) = ctx.setup_project(10_000);
+    let (second, token_b, sac_b) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &500);
+    sac_b.mint(&donator, &500);
+
+    let receipts = ctx.client.deposit_batch(
+        &donator,
+        &vec![
+            &ctx.env,
+            (first.id, token_a.address.clone(), 100i128),
+            (second.id, token_b.address.clone(), 200i128),
+            (first.id, token_a.address.clone(), 50i128),
+        ],
+    );
+
+    assert_eq!(receipts, vec![&ctx.env, 0, 1, 2]);
+    assert_eq!(ctx.client.get_balance(&first.id, &token_a.address), 150);
+    assert_eq!(ctx.client.get_balance(&second.id, &token_b.address), 200);
+    assert_eq!(token_a.balance(&donator), 350);
+    assert_eq!(token_b.balance(&donator), 300);
+
+    let p = ctx.client.get_project(&first.id);
+    assert_eq!(p.donation_count, 1);
+    assert_eq!(p.unique_donors, 1);
+    assert_eq!(p.total_deposited, 150);
+}
+
+#[test]
+fn test_deposit_batch_emits_funded_per_entry() {
+    let ctx = TestContext::new();
+    let (first, token_a, sac_a) = ctx.setup_project(10_000);
+    let (second, token_b, sac_b) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &100);
+    sac_b.mint(&donator, &100);
+
+    ctx.client.deposit_batch(
+        &donator,
+        &vec![
+            &ctx.env,
+            (first.id, token_a.address.clone(), 100i128),
+            (second.id, token_b.address.clone(), 100i128),
+        ],
+    );
+
+    let all_events = ctx.env.events().all();
+    for (project_id, token) in [(first.id, &token_a), (second.id, &token_b)] {
+        let funded_topics = vec![
+            &ctx.env,
+            symbol_short!("funded").into_val(&ctx.env),
+            project_id.into_val(&ctx.env),
+            token.address.into_val(&ctx.env),
+        ];
+        let funded = all_events
+            .iter()
+            .filter(|e| e.0 == ctx.client.address && e.1 == funded_topics)
+            .count();
+        assert_eq!(funded, 1);
+    }
+}
+
+#[test]
+fn test_deposit_batch_is_all_or_nothing() {
+    let ctx = TestContext::new();
+    let (first, token_a, sac_a) = ctx.setup_project(10_000);
+    let (second, _, _) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &300);
+
+    // The second project does not accept token_a, so nothing may move.
+    let result = ctx.client.try_deposit_batch(
+        &donator,
+        &vec![
+            &ctx.env,
+            (first.id, token_a.address.clone(), 100i128),
+            (second.id, token_a.address.clone(), 100i128),
+            (first.id, token_a.address.clone(), 100i128),
+        ],
+    );
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::TokenNotAccepted as u32
+        )))
+    );
+
+    assert_eq!(token_a.balance(&donator), 300);
+    assert_eq!(ctx.client.get_balance(&first.id, &token_a.address), 0);
+    assert_eq!(ctx.client.get_project(&first.id).donation_count, 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_deposit_batch_rejects_expired_project() {
+    let ctx = TestContext::new();
+    let (first, token_a, sac_a) = ctx.setup_project(10_000);
+    let (expired, token_b, sac_b) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &100);
+    sac_b.mint(&donator, &100);
+    ctx.jump_time(expired.deadline + 1);
+
+    ctx.client.deposit_batch(
+        &donator,
+        &vec![
+            &ctx.env,
+            (first.id, token_a.address.clone(), 100i128),
+            (expired.id, token_b.address.clone(), 100i128),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_deposit_batch_rejects_empty_batch() {
+    let ctx = TestContext::new();
+    let donator = ctx.generate_address();
+
+    ctx.client.deposit_batch(&donator, &Vec::new(&ctx.env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #36)")]
+fn test_deposit_batch_rejects_more_than_ten_entries() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+
+    let mut deposits = Vec::new(&ctx.env);
+    for _ in 0..11 {
+        deposits.push_back((project.id, token.address.clone(), 1i128));
+    }
+    ctx.client.deposit_batch(&donator, &deposits);
+}
@@ -0,0 +1,274 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, Vec};
+
+use crate::test_utils::TestContext;
+use crate::ProjectStatus;
+
+#[test]
+fn test_deposit_many_across_tokens() {
+    let ctx = TestContext::new();
+    let (token1, sac1) = ctx.create_token();
+    let (token2, sac2) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token1.address.clone(), token2.address.clone()]);
+    let project = ctx.register_project(&tokens, 10_000);
+
+    let donator = ctx.generate_address();
+    sac1.mint(&donator, &1_000);
+    sac2.mint(&donator, &1_000);
+
+    let deposits = vec![
+        &ctx.env,
+        (token1.address.clone(), 300i128),
+        (token2.address.clone(), 700i128),
+    ];
+    let receipts = ctx.client.deposit_many(&project.id, &donator, &deposits);
+
+    assert_eq!(receipts.len(), 2);
+    let first = ctx.client.get_receipt(&receipts.get(0).unwrap());
+    assert_eq!((first.token, first.amount), (token1.address.clone(), 300));
+    let second = ctx.client.get_receipt(&receipts.get(1).unwrap());
+    assert_eq!((second.token, second.amount), (token2.address.clone(), 700));
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token1.address), 300);
+    assert_eq!(ctx.client.get_balance(&project.id, &token2.address), 700);
+    assert_eq!(token1.balance(&donator), 700);
+    assert_eq!(token2.balance(&donator), 300);
+
+    let p = ctx.client.get_project(&project.id);
+    assert_eq!(p.donation_count, 2);
+    assert_eq!(p.unique_donors, 1);
+    assert_eq!(p.total_deposited, 1_000);
+}
+
+#[test]
+fn test_deposit_many_emits_funded_per_entry() {
+    let ctx = TestContext::new();
+    let (token1, sac1) = ctx.create_token();
+    let (token2, sac2) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token1.address.clone(), token2.address.clone()]);
+    let project = ctx.register_project(&tokens, 10_000);
+
+    let donator = ctx.generate_address();
+    sac1.mint(&donator, &100);
+    sac2.mint(&donator, &100);
+
+    let deposits = vec![
+        &ctx.env,
+        (token1.address.clone(), 50i128),
+        (token2.address.clone(), 60i128),
+    ];
+    ctx.client.deposit_many(&project.id, &donator, &deposits);
+
+    let all_events = ctx.env.events().all();
+    for token in [&token1, &token2] {
+        let funded_topics = vec![
+            &ctx.env,
+            symbol_short!("funded").into_val(&ctx.env),
+            project.id.into_val(&ctx.env),
+            token.address.into_val(&ctx.env),
+        ];
+        let funded = all_events
+            .iter()
+            .filter(|e| e.0 == ctx.client.address && e.1 == funded_topics)
+            .count();
+        assert_eq!(funded, 1);
+    }
+}
+
+#[test]
+fn test_deposit_many_reaches_goal() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+
+    let deposits = vec![&ctx.env, (token.address.clone(), 1_000i128)];
+    ctx.client.deposit_many(&project.id, &donator, &deposits);
+
+    let p = ctx.client.get_project(&project.id);
+    assert_eq!(p.status, ProjectStatus::Active);
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &donator),
+        1_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #33)")]
+fn test_deposit_many_rejects_unaccepted_token() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let (other, other_sac) = ctx.create_token();
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    other_sac.mint(&donator, &100);
+
+    let deposits = vec![
+        &ctx.env,
+        (token.address.clone(), 100i128),
+        (other.address.clone(), 100i128),
+    ];
+    ctx.client.deposit_many(&project.id, &donator, &deposits);
+}
+
+#[test]
+fn test_deposit_many_is_all_or_nothing() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    // The second entry is invalid, so the first must not move any funds.
+    let deposits = vec![
+        &ctx.env,
+        (token.address.clone(), 100i128),
+        (token.address.clone(), 0i128),
+    ];
+    let result = ctx
+        .client
+        .try_deposit_many(&project.id, &donator, &deposits);
+    assert!(result.is_err());
+
+    assert_eq!(token.balance(&donator), 100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_deposit_many_rejects_empty_batch() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+
+    ctx.client
+        .deposit_many(&project.id, &donator, &Vec::new(&ctx.env));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_deposit_many_rejects_more_than_ten_entries() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+
+    let mut deposits = Vec::new(&ctx.env);
+    for _ in 0..11 {
+        deposits.push_back((token.address.clone(), 1i128));
+    }
+    ctx.client.deposit_many(&project.id, &donator, &deposits);
+}
+
+#[test]
+fn test_deposit_many_follows_max_accepted_tokens() {
+    let ctx = TestContext::new();
+    ctx.client.set_max_accepted_tokens(&ctx.admin, &20);
+    let donator = ctx.generate_address();
+    let mut tokens = Vec::new(&ctx.env);
+    let mut deposits = Vec::new(&ctx.env);
+    for _ in 0..12 {
+        let (token, sac) = ctx.create_token();
+        sac.mint(&donator, &100);
+        tokens.push_back(token.address.clone());
+        deposits.push_back((token.address, 100i128));
+    }
+    let project = ctx.register_project(&tokens, 10_000);
+
+    ctx.client.deposit_many(&project.id, &donator, &deposits);
+
+    for token in tokens.iter() {
+        assert_eq!(ctx.client.get_balance(&project.id, &token), 100);
+    }
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 12);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_deposit_many_after_deadline_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    ctx.jump_time(86_401);
+    let deposits = vec![&ctx.env, (token.address.clone(), 100i128)];
+    ctx.client.deposit_many(&project.id, &donator, &deposits);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_deposit_many_rejects_duplicate_token() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    let deposits = vec![
+        &ctx.env,
+        (token.address.clone(), 40i128),
+        (token.address.clone(), 60i128),
+    ];
+    ctx.client.deposit_many(&project.id, &donator, &deposits);
+}
+
+#[test]
+fn test_deposit_many_matches_sequential_deposits() {
+    let ctx = TestContext::new();
+    let (token1, sac1) = ctx.create_token();
+    let (token2, sac2) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token1.address.clone(), token2.address.clone()]);
+    let batched = ctx.register_project(&tokens, 10_000);
+    let sequential = ctx.register_project(&tokens, 10_000);
+
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    for donator in [&alice, &bob] {
+        sac1.mint(donator, &1_000);
+        sac2.mint(donator, &1_000);
+    }
+
+    // Alice already gave token1 to both projects; Bob is new to both.
+    ctx.client
+        .deposit(&batched.id, &alice, &token1.address, &10i128);
+    ctx.client
+        .deposit(&sequential.id, &alice, &token1.address, &10i128);
+
+    for donator in [&alice, &bob] {
+        ctx.client.deposit_many(
+            &batched.id,
+            donator,
+            &vec![
+                &ctx.env,
+                (token1.address.clone(), 100i128),
+                (token2.address.clone(), 200i128),
+            ],
+        );
+        ctx.client
+            .deposit(&sequential.id, donator, &token1.address, &100i128);
+        ctx.client
+            .deposit(&sequential.id, donator, &token2.address, &200i128);
+    }
+
+    let a = ctx.client.get_project(&batched.id);
+    let b = ctx.client.get_project(&sequential.id);
+    assert_eq!(a.donation_count, b.donation_count);
+    assert_eq!(a.unique_donors, b.unique_donors);
+    assert_eq!(a.total_deposited, b.total_deposited);
+    for token in [&token1, &token2] {
+        assert_eq!(
+            ctx.client.get_balance(&batched.id, &token.address),
+            ctx.client.get_balance(&sequential.id, &token.address)
+        );
+        for donator in [&alice, &bob] {
+            assert_eq!(
+                ctx.client
+                    .get_contribution(&batched.id, &token.address, donator),
+                ctx.client
+                    .get_contribution(&sequential.id, &token.address, donator)
+            );
+        }
+    }
+}
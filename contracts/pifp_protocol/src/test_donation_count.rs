@@ -1,6 +1,7 @@
 extern crate std;
 
 use crate::test_utils::TestContext;
+use crate::ProjectOptions;
 
 #[test]
 fn test_donation_count_initialized_to_zero() {
@@ -74,6 +75,8 @@ fn test_donation_count_increments_for_same_donor_different_tokens() {
         &10_000,
         &ctx.dummy_proof(),
         &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
     );
 
     let donator = ctx.generate_address();
@@ -102,6 +105,8 @@ fn test_donation_count_complex_scenario() {
         &10_000,
         &ctx.dummy_proof(),
         &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
     );
 
     let donator1 = ctx.generate_address();
@@ -143,3 +148,89 @@ fn test_donation_count_complex_scenario() {
         .deposit(&project.id, &donator2, &token2.address, &100i128);
     assert_eq!(ctx.client.get_project(&project.id).donation_count, 5);
 }
+
+#[test]
+fn test_unique_donors_and_total_initialized_to_zero() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(10000);
+    assert_eq!(project.unique_donors, 0);
+    assert_eq!(project.total_deposited, 0);
+}
+
+#[test]
+fn test_unique_donors_complex_scenario() {
+    let ctx = TestContext::new();
+    let (token1, sac1) = ctx.create_token();
+    let (token2, sac2) = ctx.create_token();
+    let tokens =
+        soroban_sdk::Vec::from_array(&ctx.env, [token1.address.clone(), token2.address.clone()]);
+    let project = ctx.client.register_project(
+        &ctx.manager,
+        &tokens,
+        &10_000,
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
+    );
+
+    let donator1 = ctx.generate_address();
+    let donator2 = ctx.generate_address();
+    let donator3 = ctx.generate_address();
+
+    sac1.mint(&donator1, &5_000);
+    sac1.mint(&donator2, &5_000);
+    sac1.mint(&donator3, &5_000);
+    sac2.mint(&donator1, &5_000);
+    sac2.mint(&donator2, &5_000);
+
+    ctx.client
+        .deposit(&project.id, &donator1, &token1.address, &100i128);
+    let p = ctx.client.get_project(&project.id);
+    assert_eq!((p.unique_donors, p.total_deposited), (1, 100));
+
+    ctx.client
+        .deposit(&project.id, &donator1, &token1.address, &100i128);
+    let p = ctx.client.get_project(&project.id);
+    assert_eq!((p.unique_donors, p.total_deposited), (1, 200));
+
+    ctx.client
+        .deposit(&project.id, &donator2, &token1.address, &200i128);
+    let p = ctx.client.get_project(&project.id);
+    assert_eq!((p.unique_donors, p.total_deposited), (2, 400));
+
+    // Same donor, second token: a new pair but not a new donor.
+    ctx.client
+        .deposit(&project.id, &donator1, &token2.address, &150i128);
+    let p = ctx.client.get_project(&project.id);
+    assert_eq!(p.donation_count, 3);
+    assert_eq!((p.unique_donors, p.total_deposited), (2, 550));
+
+    ctx.client
+        .deposit(&project.id, &donator3, &token1.address, &300i128);
+    let p = ctx.client.get_project(&project.id);
+    assert_eq!((p.unique_donors, p.total_deposited), (3, 850));
+
+    ctx.client
+        .deposit(&project.id, &donator2, &token2.address, &250i128);
+    let p = ctx.client.get_project(&project.id);
+    assert_eq!(p.donation_count, 5);
+    assert_eq!((p.unique_donors, p.total_deposited), (3, 1100));
+}
+
+#[test]
+fn test_total_deposited_not_reduced_by_refund() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500i128);
+
+    ctx.jump_time(86_401);
+    ctx.client.refund(&donator, &project.id, &token.address);
+
+    let p = ctx.client.get_project(&project.id);
+    assert_eq!(p.unique_donors, 1);
+    assert_eq!(p.total_deposited, 500);
+}
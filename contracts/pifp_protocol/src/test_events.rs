@@ -1,9 +1,12 @@
 extern crate std;
 
-use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal};
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, String, TryIntoVal};
 
-use crate::events::{ProjectCreated, ProjectFunded, ProjectVerified};
+use crate::events::{
+    ProjectCreated, ProjectFunded, ProjectVerified, RoleGranted, RoleRevoked, SuperAdminTransferred,
+};
 use crate::test_utils::TestContext;
+use crate::{ProjectOptions, Role};
 
 #[test]
 fn test_project_created_event() {
@@ -50,12 +53,13 @@ fn test_project_funded_event() {
     let all_events = ctx.env.events().all();
     let last_event = all_events.last().expect("No events found");
 
-    // Topic: (symbol_short!("funded"), project_id)
+    // Topic: (symbol_short!("funded"), project_id, token)
     assert_eq!(last_event.0, ctx.client.address);
     let expected_topics = vec![
         &ctx.env,
         symbol_short!("funded").into_val(&ctx.env),
         project.id.into_val(&ctx.env),
+        token.address.into_val(&ctx.env),
     ];
     assert_eq!(last_event.1, expected_topics);
 
@@ -67,10 +71,172 @@ fn test_project_funded_event() {
             project_id: project.id,
             donator: donator.clone(),
             amount,
+            memo: None,
+            is_anonymous: false,
+            total_raised: amount,
+            goal: 10000,
         }
     );
 }
 
+#[test]
+fn test_project_funded_event_tracks_running_total() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    let last_event = ctx.env.events().all().last().expect("No events found");
+    let event_data: ProjectFunded = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(event_data.amount, 500);
+    assert_eq!(event_data.total_raised, 1500);
+    assert_eq!(event_data.goal, 10000);
+}
+
+#[test]
+fn test_anonymous_deposit_event_hides_donator() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &700);
+
+    ctx.client
+        .deposit_anonymous(&project.id, &donator, &token.address, &700);
+
+    let all_events = ctx.env.events().all();
+    let funded = all_events
+        .iter()
+        .find(|(_, topics, _)| {
+            topics.get(0).unwrap().try_into_val(&ctx.env) == Ok(symbol_short!("funded"))
+        })
+        .expect("No funded event");
+    let donator_val: soroban_sdk::Val = donator.into_val(&ctx.env);
+    for topic in funded.1.iter() {
+        assert!(!topic.shallow_eq(&donator_val));
+    }
+
+    let event_data: ProjectFunded = funded.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        ProjectFunded {
+            project_id: project.id,
+            donator: ctx.client.address.clone(),
+            amount: 700,
+            memo: None,
+            is_anonymous: true,
+            total_raised: 700,
+            goal: 10000,
+        }
+    );
+
+    // The contribution is still tracked against the real donor.
+    assert_eq!(
+        ctx.client
+            .get_donation(&project.id, &token.address, &donator),
+        700
+    );
+    let project = ctx.client.get_project(&project.id);
+    assert_eq!(project.donation_count, 1);
+    assert_eq!(project.unique_donors, 1);
+}
+
+#[test]
+fn test_project_funded_event_with_memo() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    let memo = String::from_str(&ctx.env, "in memory of Ada");
+
+    ctx.client.deposit_with_memo(
+        &project.id,
+        &donator,
+        &token.address,
+        &500,
+        &Some(memo.clone()),
+    );
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+
+    // Topics are unchanged by the memo.
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("funded").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+        token.address.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    let event_data: ProjectFunded = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(event_data.memo, Some(memo));
+    assert_eq!(event_data.amount, 500);
+}
+
+#[test]
+fn test_deposit_without_memo_matches_plain_deposit() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &200);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    let plain = ctx.env.events().all().last().unwrap();
+    ctx.client
+        .deposit_with_memo(&project.id, &donator, &token.address, &100, &None);
+    let with_none = ctx.env.events().all().last().unwrap();
+
+    assert_eq!(plain.1, with_none.1);
+    let plain_data: ProjectFunded = plain.2.try_into_val(&ctx.env).unwrap();
+    let none_data: ProjectFunded = with_none.2.try_into_val(&ctx.env).unwrap();
+    // Only the running total moves on between the two deposits.
+    assert_eq!(none_data.total_raised, 200);
+    assert_eq!(
+        plain_data,
+        ProjectFunded {
+            total_raised: 100,
+            ..none_data
+        }
+    );
+}
+
+#[test]
+fn test_memo_length_bounds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    let invalid = Err(Ok(soroban_sdk::Error::from_contract_error(
+        crate::Error::InvalidMemo as u32,
+    )));
+
+    let longest = String::from_str(&ctx.env, &"x".repeat(64));
+    ctx.client
+        .deposit_with_memo(&project.id, &donator, &token.address, &100, &Some(longest));
+
+    let too_long = String::from_str(&ctx.env, &"x".repeat(65));
+    let result = ctx.client.try_deposit_with_memo(
+        &project.id,
+        &donator,
+        &token.address,
+        &100,
+        &Some(too_long),
+    );
+    assert_eq!(result, invalid);
+
+    let empty = String::from_str(&ctx.env, "");
+    let result =
+        ctx.client
+            .try_deposit_with_memo(&project.id, &donator, &token.address, &100, &Some(empty));
+    assert_eq!(result, invalid);
+}
+
 #[test]
 fn test_project_verified_event() {
     let ctx = TestContext::new();
@@ -120,6 +286,8 @@ fn test_get_project_balances() {
         &10_000,
         &ctx.dummy_proof(),
         &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
     );
 
     let donator = ctx.generate_address();
@@ -193,10 +361,121 @@ fn test_refunded_event() {
         &ctx.env,
         symbol_short!("refunded").into_val(&ctx.env),
         project.id.into_val(&ctx.env),
+        token.address.into_val(&ctx.env),
     ];
     assert_eq!(last_event.1, expected_topics);
 
-    let event_data: (soroban_sdk::Address, i128) = last_event.2.try_into_val(&ctx.env).unwrap();
+    let event_data: (soroban_sdk::Address, i128, soroban_sdk::Address) =
+        last_event.2.try_into_val(&ctx.env).unwrap();
     assert_eq!(event_data.0, donator);
     assert_eq!(event_data.1, 400i128);
+    assert_eq!(event_data.2, donator);
+}
+
+#[test]
+fn test_role_granted_event() {
+    let ctx = TestContext::new();
+    let auditor = ctx.generate_address();
+
+    ctx.client.grant_role(&ctx.admin, &auditor, &Role::Auditor);
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+
+    // Topic: (symbol_short!("role_set"), target, role_symbol)
+    assert_eq!(last_event.0, ctx.client.address);
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("role_set").into_val(&ctx.env),
+        auditor.into_val(&ctx.env),
+        symbol_short!("auditor").into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    // Data: RoleGranted struct
+    let event_data: RoleGranted = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        RoleGranted {
+            granter: Some(ctx.admin.clone()),
+            target: auditor.clone(),
+            role: Role::Auditor,
+        }
+    );
+}
+
+#[test]
+fn test_role_revoked_event() {
+    let ctx = TestContext::new();
+
+    ctx.client.revoke_role(&ctx.admin, &ctx.oracle);
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+
+    // Topic: (symbol_short!("role_del"), target)
+    assert_eq!(last_event.0, ctx.client.address);
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("role_del").into_val(&ctx.env),
+        ctx.oracle.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    // Data: RoleRevoked struct
+    let event_data: RoleRevoked = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        RoleRevoked {
+            revoker: ctx.admin.clone(),
+            target: ctx.oracle.clone(),
+        }
+    );
+}
+
+#[test]
+fn test_super_admin_transferred_event() {
+    let ctx = TestContext::new();
+    let successor = ctx.generate_address();
+
+    ctx.client.transfer_super_admin(&ctx.admin, &successor);
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+
+    // Topic: (symbol_short!("sa_xfer"), new)
+    assert_eq!(last_event.0, ctx.client.address);
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("sa_xfer").into_val(&ctx.env),
+        successor.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    // Data: SuperAdminTransferred struct
+    let event_data: SuperAdminTransferred = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        SuperAdminTransferred {
+            old: ctx.admin.clone(),
+            new: successor.clone(),
+        }
+    );
+
+    // Preceded by the role change on each side.
+    let granted: RoleGranted = all_events
+        .get(all_events.len() - 2)
+        .unwrap()
+        .2
+        .try_into_val(&ctx.env)
+        .unwrap();
+    assert_eq!(granted.target, successor);
+    assert_eq!(granted.role, Role::SuperAdmin);
+    let revoked: RoleRevoked = all_events
+        .get(all_events.len() - 3)
+        .unwrap()
+        .2
+        .try_into_val(&ctx.env)
+        .unwrap();
+    assert_eq!(revoked.target, ctx.admin);
 }
@@ -1,6 +1,16 @@
 extern crate std;
 
-use crate::{test_utils::TestContext, ProjectStatus};
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, Symbol, TryIntoVal};
+
+use crate::events::ProjectExpired;
+use crate::{storage, test_utils::TestContext, ProjectStatus};
+
+/// Status as persisted, bypassing the lazy expiry applied by the queries.
+fn stored_status(ctx: &TestContext, project_id: u64) -> ProjectStatus {
+    ctx.env.as_contract(&ctx.client.address, || {
+        storage::load_project_state(&ctx.env, project_id).status
+    })
+}
 
 #[test]
 fn test_expire_project_success() {
@@ -78,3 +88,191 @@ fn test_expire_active_project_success() {
     let expired_project = ctx.client.get_project(&project.id);
     assert_eq!(expired_project.status, ProjectStatus::Expired);
 }
+
+#[test]
+fn test_queries_report_lapsed_project_as_expired() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.jump_time(project.deadline + 1);
+
+    // The view says `Expired`; storage still says `Funding` until someone
+    // persists the transition.
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Expired
+    );
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Expired
+    );
+    assert_eq!(stored_status(&ctx, project.id), ProjectStatus::Funding);
+
+    // So the explicit transition is still available.
+    ctx.client.expire_project(&project.id);
+    assert_eq!(stored_status(&ctx, project.id), ProjectStatus::Expired);
+}
+
+#[test]
+fn test_touch_expired_persists_transition() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    sac.mint(&ctx.admin, &1000);
+    ctx.client
+        .deposit(&project.id, &ctx.admin, &token.address, &1000);
+    ctx.jump_time(project.deadline + 1);
+
+    assert!(ctx.client.touch_expired(&project.id));
+    let (_, topics, data) = ctx.env.events().all().last().unwrap();
+    let topic: Symbol = topics.get(0).unwrap().into_val(&ctx.env);
+    let event: ProjectExpired = data.into_val(&ctx.env);
+    assert_eq!(topic, symbol_short!("expired"));
+    assert_eq!(
+        event,
+        ProjectExpired {
+            project_id: project.id,
+            deadline: project.deadline,
+        }
+    );
+
+    assert_eq!(stored_status(&ctx, project.id), ProjectStatus::Expired);
+
+    // Already expired: nothing left to do.
+    assert!(!ctx.client.touch_expired(&project.id));
+}
+
+#[test]
+fn test_touch_expired_before_deadline_is_noop() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert!(!ctx.client.touch_expired(&project.id));
+    assert_eq!(stored_status(&ctx, project.id), ProjectStatus::Funding);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+}
+
+#[test]
+fn test_completed_project_not_reported_expired() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(project.deadline + 1);
+
+    assert!(!ctx.client.touch_expired(&project.id));
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+/// Ids of the `ProjectExpired` events published by the last invocation.
+fn expired_event_ids(ctx: &TestContext) -> std::vec::Vec<u64> {
+    ctx.env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            let topic: Result<Symbol, _> = topics.get(0).unwrap().try_into_val(&ctx.env);
+            matches!(topic, Ok(t) if t == symbol_short!("expired"))
+        })
+        .map(|(_, _, data)| {
+            let event: ProjectExpired = data.into_val(&ctx.env);
+            event.project_id
+        })
+        .collect()
+}
+
+#[test]
+fn test_expire_projects_expires_only_lapsed_open_projects() {
+    let ctx = TestContext::new();
+    let (funding, _, _) = ctx.setup_project(1000);
+    let (active, token, sac) = ctx.setup_project(1000);
+    let (completed, _, _) = ctx.setup_project(1000);
+    let (already, _, _) = ctx.setup_project(1000);
+    let (later_token, _) = ctx.create_token();
+    let not_due = ctx.client.register_project(
+        &ctx.manager,
+        &vec![&ctx.env, later_token.address],
+        &1000,
+        &ctx.dummy_proof(),
+        &(funding.deadline * 3),
+        &ctx.dummy_metadata(),
+        &Default::default(),
+    );
+    sac.mint(&ctx.admin, &1000);
+    ctx.client
+        .deposit(&active.id, &ctx.admin, &token.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &completed.id, &ctx.dummy_proof());
+    ctx.jump_time(funding.deadline + 1);
+    ctx.client.expire_project(&already.id);
+
+    let expired = ctx.client.expire_projects(&vec![
+        &ctx.env,
+        funding.id,
+        active.id,
+        completed.id,
+        already.id,
+        not_due.id,
+    ]);
+
+    assert_eq!(expired, vec![&ctx.env, funding.id, active.id]);
+    assert_eq!(expired_event_ids(&ctx), [funding.id, active.id]);
+    assert_eq!(stored_status(&ctx, funding.id), ProjectStatus::Expired);
+    assert_eq!(stored_status(&ctx, active.id), ProjectStatus::Expired);
+    assert_eq!(stored_status(&ctx, completed.id), ProjectStatus::Completed);
+    assert_eq!(stored_status(&ctx, not_due.id), ProjectStatus::Funding);
+}
+
+/// Unknown ids are skipped instead of aborting the batch, so a keeper's
+/// stale list still expires everything else it names.
+#[test]
+fn test_expire_projects_skips_unknown_ids() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.jump_time(project.deadline + 1);
+
+    let expired = ctx
+        .client
+        .expire_projects(&vec![&ctx.env, 99, project.id, 100]);
+
+    assert_eq!(expired, vec![&ctx.env, project.id]);
+    assert_eq!(stored_status(&ctx, project.id), ProjectStatus::Expired);
+}
+
+#[test]
+fn test_expire_projects_repeated_id_expires_once() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.jump_time(project.deadline + 1);
+
+    let expired = ctx
+        .client
+        .expire_projects(&vec![&ctx.env, project.id, project.id]);
+
+    assert_eq!(expired, vec![&ctx.env, project.id]);
+    assert_eq!(expired_event_ids(&ctx), [project.id]);
+}
+
+#[test]
+fn test_expire_projects_batch_limit() {
+    let ctx = TestContext::new();
+    let mut ids = soroban_sdk::Vec::new(&ctx.env);
+    for id in 0..21u64 {
+        ids.push_back(id);
+    }
+
+    let result = ctx.client.try_expire_projects(&ids);
+
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::BatchTooLarge as u32
+        )))
+    );
+}
@@ -0,0 +1,308 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, token, vec, IntoVal, TryIntoVal};
+
+use crate::events::{FeeCollected, FeesWithdrawn};
+use crate::test_utils::TestContext;
+use crate::Role;
+
+/// Fund a fresh project with `amount` and release it, returning the token.
+fn release_with_fee(ctx: &TestContext, bps: u32, amount: i128) -> token::Client<'static> {
+    ctx.client.set_fee_bps(&ctx.admin, &bps);
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &amount);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &amount);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    token
+}
+
+#[test]
+fn test_fee_defaults_to_zero() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_fee_bps(), 0);
+}
+
+#[test]
+fn test_release_without_fee_pays_creator_in_full() {
+    let ctx = TestContext::new();
+    let token = release_with_fee(&ctx, 0, 1000);
+
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(ctx.client.get_fee_balance(&token.address), 0);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+#[test]
+fn test_release_keeps_fee_in_treasury() {
+    let ctx = TestContext::new();
+    let token = release_with_fee(&ctx, 250, 2000);
+
+    assert_eq!(token.balance(&ctx.manager), 1950);
+    assert_eq!(ctx.client.get_fee_balance(&token.address), 50);
+    // The fee stays in the contract until a Treasurer withdraws it.
+    assert_eq!(token.balance(&ctx.client.address), 50);
+}
+
+#[test]
+fn test_fee_rounds_in_creator_favour() {
+    let ctx = TestContext::new();
+    let token = release_with_fee(&ctx, 100, 999);
+
+    // 1% of 999 is 9.99, floored to 9.
+    assert_eq!(ctx.client.get_fee_balance(&token.address), 9);
+    assert_eq!(token.balance(&ctx.manager), 990);
+}
+
+#[test]
+fn test_fees_accumulate_across_projects() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.admin, &500);
+    let (token, sac) = ctx.create_token();
+    let tokens = vec![&ctx.env, token.address.clone()];
+
+    for _ in 0..2 {
+        let project = ctx.register_project(&tokens, 1000);
+        let donator = ctx.generate_address();
+        sac.mint(&donator, &1000);
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &1000i128);
+        ctx.client
+            .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    }
+
+    assert_eq!(ctx.client.get_fee_balance(&token.address), 100);
+}
+
+#[test]
+fn test_zero_balance_token_skips_fee() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.admin, &1000);
+
+    let (token_a, sac_a) = ctx.create_token();
+    let (token_b, _) = ctx.create_token();
+    let tokens = vec![&ctx.env, token_a.address.clone(), token_b.address.clone()];
+    let project = ctx.register_project(&tokens, 1000);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token_a.address, &1000i128);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let all_events = ctx.env.events().all();
+    let fee_events = all_events
+        .iter()
+        .filter(|e| {
+            e.1.get(0)
+                .map(|t| {
+                    let sym: Result<soroban_sdk::Symbol, _> = t.try_into_val(&ctx.env);
+                    sym.map(|s| s == symbol_short!("fee_coll")).unwrap_or(false)
+                })
+                .unwrap_or(false)
+        })
+        .count();
+    assert_eq!(fee_events, 1);
+    assert_eq!(ctx.client.get_fee_balance(&token_a.address), 100);
+    assert_eq!(ctx.client.get_fee_balance(&token_b.address), 0);
+}
+
+#[test]
+fn test_fee_collected_event() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.admin, &500);
+
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let all_events = ctx.env.events().all();
+    let fee_event = all_events
+        .iter()
+        .find(|e| {
+            e.1 == vec![
+                &ctx.env,
+                symbol_short!("fee_coll").into_val(&ctx.env),
+                project.id.into_val(&ctx.env),
+                token.address.into_val(&ctx.env),
+            ]
+        })
+        .expect("fee event not found");
+
+    let data: FeeCollected = fee_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        data,
+        FeeCollected {
+            project_id: project.id,
+            token: token.address.clone(),
+            amount: 50,
+        }
+    );
+}
+
+#[test]
+fn test_zero_fee_emits_no_fee_event() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let fee_topics = vec![
+        &ctx.env,
+        symbol_short!("fee_coll").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+        token.address.into_val(&ctx.env),
+    ];
+    assert!(!ctx.env.events().all().iter().any(|e| e.1 == fee_topics));
+}
+
+#[test]
+fn test_fee_and_payout_conserve_drained_balance() {
+    for (bps, amount) in [(1u32, 1i128), (7, 9_999), (250, 123_457), (1000, 1_000_001)] {
+        let ctx = TestContext::new();
+        let token = release_with_fee(&ctx, bps, amount);
+
+        let fee = ctx.client.get_fee_balance(&token.address);
+        let payout = token.balance(&ctx.manager);
+        assert_eq!(fee + payout, amount);
+        assert_eq!(fee, amount * bps as i128 / 10_000);
+        assert_eq!(token.balance(&ctx.client.address), fee);
+    }
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_fee_above_cap_rejected() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.admin, &1001);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_set_fee() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.client.set_fee_bps(&admin, &100);
+}
+
+// ─── Treasurer withdrawals ───────────────────────────────────
+
+fn treasurer(ctx: &TestContext) -> soroban_sdk::Address {
+    let treasurer = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &treasurer, &Role::Treasurer);
+    treasurer
+}
+
+#[test]
+fn test_treasurer_withdraws_fees() {
+    let ctx = TestContext::new();
+    let token = release_with_fee(&ctx, 500, 1000);
+    let treasurer = treasurer(&ctx);
+    let vault = ctx.generate_address();
+
+    ctx.client
+        .withdraw_fees(&treasurer, &token.address, &30, &vault);
+
+    let last_event = ctx.env.events().all().last().unwrap();
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("fee_wdraw").into_val(&ctx.env),
+        token.address.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let data: FeesWithdrawn = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        data,
+        FeesWithdrawn {
+            token: token.address.clone(),
+            amount: 30,
+            to: vault.clone(),
+        }
+    );
+
+    assert_eq!(token.balance(&vault), 30);
+    assert_eq!(ctx.client.get_fee_balance(&token.address), 20);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_over_withdrawal_fails() {
+    let ctx = TestContext::new();
+    let token = release_with_fee(&ctx, 500, 1000);
+    let treasurer = treasurer(&ctx);
+
+    ctx.client
+        .withdraw_fees(&treasurer, &token.address, &51, &treasurer);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_withdrawal_cannot_touch_escrow() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+    let treasurer = treasurer(&ctx);
+
+    ctx.client
+        .withdraw_fees(&treasurer, &token.address, &1, &treasurer);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_zero_withdrawal_fails() {
+    let ctx = TestContext::new();
+    let token = release_with_fee(&ctx, 500, 1000);
+    let treasurer = treasurer(&ctx);
+
+    ctx.client
+        .withdraw_fees(&treasurer, &token.address, &0, &treasurer);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_project_manager_cannot_withdraw_fees() {
+    let ctx = TestContext::new();
+    let token = release_with_fee(&ctx, 500, 1000);
+
+    ctx.client
+        .withdraw_fees(&ctx.manager, &token.address, &10, &ctx.manager);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_oracle_cannot_withdraw_fees() {
+    let ctx = TestContext::new();
+    let token = release_with_fee(&ctx, 500, 1000);
+
+    ctx.client
+        .withdraw_fees(&ctx.oracle, &token.address, &10, &ctx.oracle);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_grant_treasurer() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    let target = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.grant_role(&admin, &target, &Role::Treasurer);
+}
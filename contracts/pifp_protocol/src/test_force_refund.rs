@@ -0,0 +1,143 @@
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Events, vec, Address, Symbol, TryIntoVal, Vec,
+};
+
+use crate::test_utils::TestContext;
+use crate::{Project, Role};
+
+/// `(donator, amount, recipient)` of every refund event published by the
+/// last contract invocation.
+fn refunded_events(ctx: &TestContext) -> std::vec::Vec<(Address, i128, Address)> {
+    let mut found = std::vec::Vec::new();
+    for (_, topics, data) in ctx.env.events().all().iter() {
+        let topic: Result<Symbol, _> = topics.get(0).unwrap().try_into_val(&ctx.env);
+        if matches!(topic, Ok(t) if t == symbol_short!("refunded")) {
+            found.push(data.try_into_val(&ctx.env).unwrap());
+        }
+    }
+    found
+}
+
+/// An expired project funded by one donator per amount.
+fn expired_project(
+    ctx: &TestContext,
+    amounts: &[i128],
+) -> (Project, soroban_sdk::token::Client<'static>, Vec<Address>) {
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let mut donators = Vec::new(&ctx.env);
+    for amount in amounts {
+        let donator = ctx.generate_address();
+        sac.mint(&donator, amount);
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, amount);
+        donators.push_back(donator);
+    }
+    ctx.jump_time(project.deadline + 1);
+    ctx.client.expire_project(&project.id);
+    (project, token, donators)
+}
+
+#[test]
+fn test_force_refund_mixed_donors() {
+    let ctx = TestContext::new();
+    let (project, token, donators) = expired_project(&ctx, &[100, 200, 300]);
+    // The second donor already claimed on their own.
+    ctx.client
+        .refund(&donators.get(1).unwrap(), &project.id, &token.address);
+    let stranger = ctx.generate_address();
+    let mut batch = donators.clone();
+    batch.push_back(stranger.clone());
+
+    let refunded = ctx.client.force_refund(&ctx.admin, &project.id, &batch);
+
+    assert_eq!(refunded, 2);
+    let events = refunded_events(&ctx);
+    assert_eq!(
+        events,
+        std::vec![
+            (donators.get(0).unwrap(), 100, donators.get(0).unwrap()),
+            (donators.get(2).unwrap(), 300, donators.get(2).unwrap()),
+        ]
+    );
+    for (donator, amount) in donators.iter().zip([100, 200, 300]) {
+        assert_eq!(token.balance(&donator), amount);
+        assert_eq!(
+            ctx.client
+                .get_contribution(&project.id, &token.address, &donator),
+            0
+        );
+    }
+    assert_eq!(token.balance(&stranger), 0);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+#[test]
+fn test_force_refund_rerun_is_noop() {
+    let ctx = TestContext::new();
+    let (project, token, donators) = expired_project(&ctx, &[100, 200]);
+
+    ctx.client.force_refund(&ctx.admin, &project.id, &donators);
+    let refunded = ctx.client.force_refund(&ctx.admin, &project.id, &donators);
+
+    assert_eq!(refunded, 0);
+    assert!(refunded_events(&ctx).is_empty());
+    assert_eq!(token.balance(&donators.get(0).unwrap()), 100);
+    assert_eq!(token.balance(&donators.get(1).unwrap()), 200);
+}
+
+#[test]
+fn test_self_refund_after_force_refund_fails_cleanly() {
+    let ctx = TestContext::new();
+    let (project, token, donators) = expired_project(&ctx, &[100]);
+    ctx.client.force_refund(&ctx.admin, &project.id, &donators);
+
+    let result = ctx
+        .client
+        .try_refund(&donators.get(0).unwrap(), &project.id, &token.address);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::InsufficientBalance as u32
+        )))
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_force_refund_requires_expired_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+
+    ctx.client
+        .force_refund(&ctx.admin, &project.id, &vec![&ctx.env, donator]);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #36)")]
+fn test_force_refund_batch_limit() {
+    let ctx = TestContext::new();
+    let (project, _, _) = expired_project(&ctx, &[100]);
+    let mut batch = Vec::new(&ctx.env);
+    for _ in 0..51 {
+        batch.push_back(Address::generate(&ctx.env));
+    }
+
+    ctx.client.force_refund(&ctx.admin, &project.id, &batch);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_force_refund_requires_admin() {
+    let ctx = TestContext::new();
+    let (project, _, donators) = expired_project(&ctx, &[100]);
+    let oracle = Address::generate(&ctx.env);
+    ctx.client.grant_role(&ctx.admin, &oracle, &Role::Oracle);
+
+    ctx.client.force_refund(&oracle, &project.id, &donators);
+}
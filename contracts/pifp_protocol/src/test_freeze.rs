@@ -0,0 +1,101 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+use crate::Role;
+
+#[test]
+fn test_frozen_project_rejects_deposits_until_unfrozen() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &200);
+
+    ctx.client.freeze_project(&ctx.admin, &project.id, &true);
+    assert!(ctx.client.is_project_frozen(&project.id));
+    let result = ctx
+        .client
+        .try_deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::ProjectFrozen as u32
+        )))
+    );
+
+    ctx.client.freeze_project(&ctx.admin, &project.id, &false);
+    assert!(!ctx.client.is_project_frozen(&project.id));
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+fn test_freeze_leaves_other_projects_open() {
+    let ctx = TestContext::new();
+    let (frozen, _, _) = ctx.setup_project(1000);
+    let (open, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    ctx.client.freeze_project(&ctx.admin, &frozen.id, &true);
+    ctx.client.deposit(&open.id, &donator, &token.address, &100);
+
+    assert!(!ctx.client.is_project_frozen(&open.id));
+    assert_eq!(ctx.client.get_balance(&open.id, &token.address), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #42)")]
+fn test_frozen_project_cannot_be_released() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    ctx.client.freeze_project(&ctx.admin, &project.id, &true);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_frozen_project_blocks_refunds_until_unfrozen() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300);
+    ctx.jump_time(project.deadline + 1);
+
+    ctx.client.freeze_project(&ctx.admin, &project.id, &true);
+    assert!(ctx
+        .client
+        .try_refund(&donator, &project.id, &token.address)
+        .is_err());
+
+    ctx.client.freeze_project(&ctx.admin, &project.id, &false);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 300);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_project_manager_cannot_freeze() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let pm = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &pm, &Role::ProjectManager);
+
+    ctx.client.freeze_project(&pm, &project.id, &true);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_freeze_unknown_project() {
+    let ctx = TestContext::new();
+
+    ctx.client.freeze_project(&ctx.admin, &99, &true);
+}
@@ -0,0 +1,143 @@
+extern crate std;
+
+use soroban_sdk::vec;
+
+use crate::test_utils::TestContext;
+use crate::{ProjectOptions, ProjectStatus};
+
+#[test]
+fn test_progress_with_no_deposits() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let progress = ctx.client.get_funding_progress(&project.id);
+    assert_eq!(progress.goal, 1000);
+    assert_eq!(progress.raised, 0);
+    assert_eq!(progress.progress_bps, 0);
+    assert_eq!(progress.time_remaining, 86400);
+    assert_eq!(progress.status, ProjectStatus::Funding);
+}
+
+#[test]
+fn test_progress_partially_funded() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &333);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &333);
+    ctx.jump_time(400);
+
+    let progress = ctx.client.get_funding_progress(&project.id);
+    assert_eq!(progress.raised, 333);
+    assert_eq!(progress.progress_bps, 3330);
+    assert_eq!(progress.time_remaining, 86000);
+    assert_eq!(progress.status, ProjectStatus::Funding);
+}
+
+#[test]
+fn test_progress_exactly_funded() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    let progress = ctx.client.get_funding_progress(&project.id);
+    assert_eq!(progress.raised, 1000);
+    assert_eq!(progress.progress_bps, 10_000);
+    assert_eq!(progress.status, ProjectStatus::Active);
+}
+
+#[test]
+fn test_progress_overfunded_is_capped() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &2500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &2500);
+
+    let progress = ctx.client.get_funding_progress(&project.id);
+    assert_eq!(progress.raised, 2500);
+    assert_eq!(progress.progress_bps, 10_000);
+}
+
+#[test]
+fn test_progress_single_goal_counts_every_token() {
+    let ctx = TestContext::new();
+    let (first, first_sac) = ctx.create_token();
+    let (second, second_sac) = ctx.create_token();
+    let project = ctx.register_project(
+        &vec![&ctx.env, first.address.clone(), second.address.clone()],
+        1000,
+    );
+    let donator = ctx.generate_address();
+    first_sac.mint(&donator, &250);
+    second_sac.mint(&donator, &250);
+    ctx.client
+        .deposit(&project.id, &donator, &first.address, &250);
+    ctx.client
+        .deposit(&project.id, &donator, &second.address, &250);
+
+    let progress = ctx.client.get_funding_progress(&project.id);
+    assert_eq!(progress.raised, 500);
+    assert_eq!(progress.progress_bps, 5_000);
+    assert_eq!(progress.tokens.get(1).unwrap().raised, 250);
+}
+
+#[test]
+fn test_progress_after_deadline() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.jump_time(90_000);
+
+    let progress = ctx.client.get_funding_progress(&project.id);
+    assert_eq!(progress.time_remaining, 0);
+    assert_eq!(progress.status, ProjectStatus::Expired);
+}
+
+#[test]
+fn test_progress_per_token_goals() {
+    let ctx = TestContext::new();
+    let (usdc, usdc_sac) = ctx.create_token();
+    let (xlm, xlm_sac) = ctx.create_token();
+    let goals = vec![
+        &ctx.env,
+        (usdc.address.clone(), 1_000),
+        (xlm.address.clone(), 50_000),
+    ];
+    let deadline = ctx.env.ledger().timestamp() + 86400;
+    let any = ctx.client.register_project_v2(
+        &ctx.manager,
+        &goals,
+        &ctx.dummy_proof(),
+        &deadline,
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
+    );
+    let all = ctx.client.register_project_v2(
+        &ctx.manager,
+        &goals,
+        &ctx.dummy_proof(),
+        &deadline,
+        &ctx.dummy_metadata(),
+        &ProjectOptions {
+            require_all_goals: true,
+            ..Default::default()
+        },
+    );
+
+    let donator = ctx.generate_address();
+    usdc_sac.mint(&donator, &1_000);
+    xlm_sac.mint(&donator, &20_000);
+    for id in [any.id, all.id] {
+        ctx.client.deposit(&id, &donator, &usdc.address, &500);
+        ctx.client.deposit(&id, &donator, &xlm.address, &10_000);
+    }
+
+    // USDC is at 50%, XLM at 20%: the best token counts unless all must be met.
+    assert_eq!(ctx.client.get_funding_progress(&any.id).progress_bps, 5000);
+    assert_eq!(ctx.client.get_funding_progress(&all.id).progress_bps, 2000);
+}
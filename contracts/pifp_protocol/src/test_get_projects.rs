@@ -0,0 +1,86 @@
+extern crate std;
+
+use crate::storage::DataKey;
+use crate::test_utils::TestContext;
+use crate::ProjectStatus;
+
+#[test]
+fn test_project_count_tracks_registrations() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_project_count(), 0);
+
+    ctx.setup_project(1000);
+    ctx.setup_project(1000);
+
+    assert_eq!(ctx.client.get_project_count(), 2);
+}
+
+#[test]
+fn test_get_projects_pages_in_id_order() {
+    let ctx = TestContext::new();
+    for _ in 0..5 {
+        ctx.setup_project(1000);
+    }
+
+    let page = ctx.client.get_projects(&1, &3);
+
+    let ids: std::vec::Vec<u64> = page.iter().map(|p| p.id).collect();
+    assert_eq!(ids, [1, 2, 3]);
+}
+
+#[test]
+fn test_limit_beyond_remaining_range_is_truncated() {
+    let ctx = TestContext::new();
+    for _ in 0..3 {
+        ctx.setup_project(1000);
+    }
+
+    assert_eq!(ctx.client.get_projects(&1, &100).len(), 2);
+    assert!(ctx.client.get_projects(&3, &10).is_empty());
+    assert!(ctx.client.get_projects(&u64::MAX, &10).is_empty());
+}
+
+#[test]
+fn test_limit_is_capped() {
+    let ctx = TestContext::new();
+    let (_, token, _) = ctx.setup_project(1000);
+    let tokens = soroban_sdk::vec![&ctx.env, token.address.clone()];
+    for _ in 0..29 {
+        ctx.register_project(&tokens, 1000);
+    }
+
+    assert_eq!(ctx.client.get_projects(&0, &100).len(), 25);
+}
+
+#[test]
+fn test_missing_entries_are_skipped() {
+    let ctx = TestContext::new();
+    for _ in 0..3 {
+        ctx.setup_project(1000);
+    }
+    // Simulate the middle project's entries having been archived.
+    ctx.env.as_contract(&ctx.client.address, || {
+        let storage = ctx.env.storage().persistent();
+        storage.remove(&DataKey::ProjConfig(1));
+        storage.remove(&DataKey::ProjState(1));
+    });
+
+    let ids: std::vec::Vec<u64> = ctx
+        .client
+        .get_projects(&0, &3)
+        .iter()
+        .map(|p| p.id)
+        .collect();
+    assert_eq!(ids, [0, 2]);
+}
+
+#[test]
+fn test_lapsed_projects_are_reported_expired() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.jump_time(project.deadline + 1);
+
+    let page = ctx.client.get_projects(&0, &1);
+    assert_eq!(page.get(0).unwrap().status, ProjectStatus::Expired);
+}
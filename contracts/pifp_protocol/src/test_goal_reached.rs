@@ -0,0 +1,169 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, Symbol, TryIntoVal};
+
+use crate::events::GoalReached;
+use crate::test_utils::TestContext;
+use crate::{ProjectOptions, ProjectStatus};
+
+/// `GoalReached` events published by the last contract invocation.
+fn goal_reached_events(ctx: &TestContext) -> std::vec::Vec<GoalReached> {
+    let mut found = std::vec::Vec::new();
+    for (_, topics, data) in ctx.env.events().all().iter() {
+        let topic: Result<Symbol, _> = topics.get(0).unwrap().try_into_val(&ctx.env);
+        if matches!(topic, Ok(t) if t == symbol_short!("goal_rchd")) {
+            found.push(data.try_into_val(&ctx.env).unwrap());
+        }
+    }
+    found
+}
+
+#[test]
+fn test_deposit_hitting_goal_exactly() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    assert_eq!(
+        goal_reached_events(&ctx),
+        std::vec![GoalReached {
+            project_id: project.id,
+            total: 1000,
+        }]
+    );
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_single_deposit_overshooting_goal() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1500);
+
+    assert_eq!(
+        goal_reached_events(&ctx),
+        std::vec![GoalReached {
+            project_id: project.id,
+            total: 1500,
+        }]
+    );
+}
+
+#[test]
+fn test_later_deposits_do_not_re_emit() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1200);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &200);
+
+    assert!(goal_reached_events(&ctx).is_empty());
+}
+
+#[test]
+fn test_goal_accumulates_across_deposits() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600);
+    assert!(goal_reached_events(&ctx).is_empty());
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+    assert_eq!(
+        goal_reached_events(&ctx),
+        std::vec![GoalReached {
+            project_id: project.id,
+            total: 1000,
+        }]
+    );
+}
+
+#[test]
+fn test_single_goal_counts_every_token() {
+    let ctx = TestContext::new();
+    let (first, first_sac) = ctx.create_token();
+    let (second, second_sac) = ctx.create_token();
+    let project = ctx.register_project(
+        &vec![&ctx.env, first.address.clone(), second.address.clone()],
+        1000,
+    );
+    let donator = ctx.generate_address();
+    first_sac.mint(&donator, &600);
+    second_sac.mint(&donator, &400);
+
+    ctx.client
+        .deposit(&project.id, &donator, &first.address, &600);
+    assert!(goal_reached_events(&ctx).is_empty());
+
+    ctx.client
+        .deposit(&project.id, &donator, &second.address, &400);
+    assert_eq!(
+        goal_reached_events(&ctx),
+        std::vec![GoalReached {
+            project_id: project.id,
+            total: 1000,
+        }]
+    );
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_goal_reached_when_last_per_token_goal_is_met() {
+    let ctx = TestContext::new();
+    let (usdc, usdc_sac) = ctx.create_token();
+    let (xlm, xlm_sac) = ctx.create_token();
+    let project = ctx.client.register_project_v2(
+        &ctx.manager,
+        &vec![
+            &ctx.env,
+            (usdc.address.clone(), 1_000),
+            (xlm.address.clone(), 50_000),
+        ],
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions {
+            require_all_goals: true,
+            ..Default::default()
+        },
+    );
+    let donator = ctx.generate_address();
+    usdc_sac.mint(&donator, &1_000);
+    xlm_sac.mint(&donator, &50_000);
+
+    ctx.client
+        .deposit(&project.id, &donator, &xlm.address, &50_000);
+    assert!(goal_reached_events(&ctx).is_empty());
+
+    ctx.client
+        .deposit(&project.id, &donator, &usdc.address, &1_000);
+    assert_eq!(
+        goal_reached_events(&ctx),
+        std::vec![GoalReached {
+            project_id: project.id,
+            total: 1_000,
+        }]
+    );
+}
@@ -0,0 +1,117 @@
+extern crate std;
+
+use soroban_sdk::testutils::Ledger;
+
+use crate::test_utils::TestContext;
+use crate::{ProjectStatus, Role};
+
+const GRACE: u64 = 3_600;
+
+#[test]
+fn test_grace_period_defaults_to_zero() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_grace_period(), 0);
+
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.env.ledger().set_timestamp(project.deadline);
+
+    let result = ctx
+        .client
+        .try_deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::ProjectExpired as u32
+        )))
+    );
+}
+
+#[test]
+fn test_deposit_inside_grace_window() {
+    let ctx = TestContext::new();
+    ctx.client.set_grace_period(&ctx.admin, &GRACE);
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+
+    ctx.env.ledger().set_timestamp(project.deadline + GRACE - 1);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_deposit_after_grace_window() {
+    let ctx = TestContext::new();
+    ctx.client.set_grace_period(&ctx.admin, &GRACE);
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    ctx.env.ledger().set_timestamp(project.deadline + GRACE);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_verification_inside_grace_window_rejected() {
+    let ctx = TestContext::new();
+    ctx.client.set_grace_period(&ctx.admin, &GRACE);
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    ctx.env.ledger().set_timestamp(project.deadline + 1);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_refunds_open_after_grace_window() {
+    let ctx = TestContext::new();
+    ctx.client.set_grace_period(&ctx.admin, &GRACE);
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+
+    ctx.env.ledger().set_timestamp(project.deadline + 1);
+    let result = ctx.client.try_refund(&donator, &project.id, &token.address);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::ProjectNotExpired as u32
+        )))
+    );
+    assert!(!ctx.client.touch_expired(&project.id));
+
+    ctx.env.ledger().set_timestamp(project.deadline + GRACE);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 100);
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Expired
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_set_grace_period() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.set_grace_period(&admin, &GRACE);
+}
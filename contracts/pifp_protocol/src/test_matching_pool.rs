@@ -0,0 +1,190 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, token, vec, Address, IntoVal, TryIntoVal};
+
+use crate::events::DonationMatched;
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectStatus};
+
+/// Register a project with `goal` and a sponsor who has matched it with
+/// `pool` of the project's token.
+fn setup_matched(
+    ctx: &TestContext,
+    goal: i128,
+    pool: i128,
+) -> (
+    Project,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+    Address,
+) {
+    let (project, token, sac) = ctx.setup_project(goal);
+    let sponsor = ctx.generate_address();
+    sac.mint(&sponsor, &pool);
+    ctx.client
+        .fund_matching_pool(&sponsor, &project.id, &token.address, &pool);
+    (project, token, sac, sponsor)
+}
+
+#[test]
+fn test_deposit_is_fully_matched() {
+    let ctx = TestContext::new();
+    let (project, token, sac, sponsor) = setup_matched(&ctx, 10_000, 1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 600);
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &sponsor),
+        300
+    );
+    assert_eq!(
+        ctx.client
+            .get_matching_pool(&project.id, &token.address)
+            .unwrap()
+            .remaining,
+        700
+    );
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 2);
+}
+
+#[test]
+fn test_deposit_is_partially_matched_when_pool_runs_dry() {
+    let ctx = TestContext::new();
+    let (project, token, sac, sponsor) = setup_matched(&ctx, 10_000, 250);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &200);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &200);
+
+    // 200 matched in full, then only the 50 left in the pool.
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 650);
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &sponsor),
+        250
+    );
+    assert!(ctx
+        .client
+        .get_matching_pool(&project.id, &token.address)
+        .is_none());
+}
+
+#[test]
+fn test_match_can_reach_goal() {
+    let ctx = TestContext::new();
+    let (project, token, sac, _) = setup_matched(&ctx, 1_000, 1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_sponsor_refund_after_expiry_returns_matched_and_unspent() {
+    let ctx = TestContext::new();
+    let (project, token, sac, sponsor) = setup_matched(&ctx, 10_000, 1_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300);
+
+    ctx.jump_time(86_401);
+    ctx.client.refund(&sponsor, &project.id, &token.address);
+    ctx.client.refund(&donator, &project.id, &token.address);
+
+    assert_eq!(token.balance(&sponsor), 1_000);
+    assert_eq!(token.balance(&donator), 300);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+    assert!(ctx
+        .client
+        .get_matching_pool(&project.id, &token.address)
+        .is_none());
+}
+
+#[test]
+fn test_sponsor_withdraws_unspent_pool_after_completion() {
+    let ctx = TestContext::new();
+    let (project, token, sac, sponsor) = setup_matched(&ctx, 1_000, 800);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let withdrawn = ctx
+        .client
+        .withdraw_matching_pool(&sponsor, &project.id, &token.address);
+
+    assert_eq!(withdrawn, 300);
+    assert_eq!(token.balance(&sponsor), 300);
+    let again = ctx
+        .client
+        .try_withdraw_matching_pool(&sponsor, &project.id, &token.address);
+    assert_eq!(
+        again,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::InsufficientBalance as u32
+        )))
+    );
+}
+
+#[test]
+fn test_second_sponsor_cannot_fund_pool() {
+    let ctx = TestContext::new();
+    let (project, token, sac, _) = setup_matched(&ctx, 10_000, 100);
+    let other = ctx.generate_address();
+    sac.mint(&other, &100);
+
+    let result = ctx
+        .client
+        .try_fund_matching_pool(&other, &project.id, &token.address, &100);
+
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::NotAuthorized as u32
+        )))
+    );
+    assert_eq!(token.balance(&other), 100);
+}
+
+#[test]
+fn test_match_emits_donation_matched() {
+    let ctx = TestContext::new();
+    let (project, token, sac, sponsor) = setup_matched(&ctx, 10_000, 100);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300);
+
+    let events = ctx.env.events().all();
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("matched").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+        token.address.into_val(&ctx.env),
+    ];
+    let (_, _, data) = events
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("DonationMatched event not found");
+    let matched: DonationMatched = data.try_into_val(&ctx.env).unwrap();
+    assert_eq!(matched.sponsor, sponsor);
+    assert_eq!(matched.matched_amount, 100);
+}
@@ -0,0 +1,57 @@
+extern crate std;
+
+use soroban_sdk::{vec, String};
+
+use crate::test_utils::TestContext;
+use crate::types::{Project, ProjectOptions};
+
+fn register_with_metadata(ctx: &TestContext, metadata_uri: &String) -> Project {
+    let (token, _) = ctx.create_token();
+    let deadline = ctx.env.ledger().timestamp() + 86400;
+    ctx.client.register_project(
+        &ctx.manager,
+        &vec![&ctx.env, token.address],
+        &1000,
+        &ctx.dummy_proof(),
+        &deadline,
+        metadata_uri,
+        &ProjectOptions::default(),
+    )
+}
+
+#[test]
+fn test_metadata_uri_is_stored_and_exposed() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert_eq!(project.metadata_uri, ctx.dummy_metadata());
+    assert_eq!(
+        ctx.client.get_project(&project.id).metadata_uri,
+        ctx.dummy_metadata()
+    );
+}
+
+#[test]
+fn test_metadata_uri_at_max_length_accepted() {
+    let ctx = TestContext::new();
+    let uri = String::from_str(&ctx.env, &"a".repeat(200));
+
+    let project = register_with_metadata(&ctx, &uri);
+
+    assert_eq!(ctx.client.get_project(&project.id).metadata_uri, uri);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #28)")]
+fn test_empty_metadata_uri_fails() {
+    let ctx = TestContext::new();
+    register_with_metadata(&ctx, &String::from_str(&ctx.env, ""));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #28)")]
+fn test_metadata_uri_too_long_fails() {
+    let ctx = TestContext::new();
+    let uri = String::from_str(&ctx.env, &"a".repeat(201));
+    register_with_metadata(&ctx, &uri);
+}
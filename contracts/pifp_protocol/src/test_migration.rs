@@ -0,0 +1,117 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address, String};
+
+use crate::storage::{DataKey, PROJECT_SCHEMA_VERSION};
+use crate::test_utils::TestContext;
+use crate::types::{ProjectConfigV1, ProjectStateV1};
+use crate::{ProjectStatus, Role};
+
+const LEGACY_ID: u64 = 7;
+
+/// Write a project the way version 1 of the contract stored it, with
+/// `balance` of `token` already escrowed.
+fn write_v1_project(ctx: &TestContext, token: &Address, balance: i128) {
+    let config = ProjectConfigV1 {
+        id: LEGACY_ID,
+        creator: ctx.manager.clone(),
+        accepted_tokens: vec![&ctx.env, token.clone()],
+        goal: 1000,
+        proof_hash: ctx.dummy_proof(),
+        deadline: ctx.env.ledger().timestamp() + 86400,
+    };
+    let state = ProjectStateV1 {
+        status: ProjectStatus::Funding,
+        donation_count: 2,
+    };
+    ctx.env.as_contract(&ctx.client.address, || {
+        let storage = ctx.env.storage().persistent();
+        storage.set(&DataKey::ProjConfig(LEGACY_ID), &config);
+        storage.set(&DataKey::ProjState(LEGACY_ID), &state);
+        storage.set(&DataKey::TokenBalance(LEGACY_ID, token.clone()), &balance);
+    });
+}
+
+#[test]
+fn test_new_projects_use_current_schema() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert_eq!(
+        ctx.client.get_schema_version(&project.id),
+        PROJECT_SCHEMA_VERSION
+    );
+}
+
+#[test]
+fn test_migrate_v1_project() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    sac.mint(&ctx.client.address, &600);
+    write_v1_project(&ctx, &token.address, 600);
+    assert_eq!(ctx.client.get_schema_version(&LEGACY_ID), 1);
+
+    ctx.client.migrate_project(&ctx.admin, &LEGACY_ID);
+
+    assert_eq!(
+        ctx.client.get_schema_version(&LEGACY_ID),
+        PROJECT_SCHEMA_VERSION
+    );
+    let project = ctx.client.get_project(&LEGACY_ID);
+    assert_eq!(project.creator, ctx.manager);
+    assert_eq!(project.goal, 1000);
+    assert_eq!(project.status, ProjectStatus::Funding);
+    assert_eq!(project.donation_count, 2);
+    assert_eq!(project.metadata_uri, String::from_str(&ctx.env, ""));
+    assert_eq!(project.assigned_oracle, None);
+    assert_eq!(project.max_contribution, 0);
+    assert!(project.token_goals.is_empty());
+    assert_eq!(project.total_deposited, 600);
+    assert_eq!(ctx.client.get_total_raised(&LEGACY_ID, &token.address), 600);
+
+    // The migrated project keeps working: the seeded total counts towards
+    // the goal.
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&LEGACY_ID, &donator, &token.address, &400);
+    assert_eq!(
+        ctx.client.get_project(&LEGACY_ID).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_migrate_is_idempotent() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300);
+    let before = ctx.client.get_project(&project.id);
+
+    ctx.client.migrate_project(&ctx.admin, &project.id);
+    ctx.client.migrate_project(&ctx.admin, &project.id);
+
+    assert_eq!(ctx.client.get_project(&project.id), before);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_migrate_unknown_project() {
+    let ctx = TestContext::new();
+    ctx.client.migrate_project(&ctx.admin, &LEGACY_ID);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_migrate_requires_super_admin() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    write_v1_project(&ctx, &token.address, 0);
+    let admin = Address::generate(&ctx.env);
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.migrate_project(&admin, &LEGACY_ID);
+}
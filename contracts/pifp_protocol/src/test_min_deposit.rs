@@ -0,0 +1,91 @@
+extern crate std;
+
+use soroban_sdk::{vec, Vec};
+
+use crate::test_utils::TestContext;
+use crate::ProjectOptions;
+
+fn min_deposit_project(
+    ctx: &TestContext,
+    min_deposit: i128,
+) -> (
+    crate::Project,
+    soroban_sdk::token::Client<'static>,
+    soroban_sdk::token::StellarAssetClient<'static>,
+) {
+    let (token, sac) = ctx.create_token();
+    let project = ctx.register_project_with_options(
+        &Vec::from_array(&ctx.env, [token.address.clone()]),
+        10_000,
+        ProjectOptions {
+            min_deposit,
+            ..Default::default()
+        },
+    );
+    (project, token, sac)
+}
+
+#[test]
+fn test_min_deposit_boundary() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = min_deposit_project(&ctx, 100);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    assert_eq!(ctx.client.get_min_deposit(&project.id), 100);
+
+    let result = ctx
+        .client
+        .try_deposit(&project.id, &donator, &token.address, &99);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::BelowMinimumDeposit as u32
+        )))
+    );
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+    assert_eq!(ctx.client.get_project(&project.id).donation_count, 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #41)")]
+fn test_min_deposit_applies_to_deposit_many() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = min_deposit_project(&ctx, 100);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+
+    ctx.client.deposit_many(
+        &project.id,
+        &donator,
+        &vec![&ctx.env, (token.address.clone(), 50i128)],
+    );
+}
+
+#[test]
+fn test_min_deposit_defaults_to_zero() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1);
+
+    assert_eq!(ctx.client.get_min_deposit(&project.id), 0);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_negative_min_deposit_rejected() {
+    let ctx = TestContext::new();
+    min_deposit_project(&ctx, -1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_min_deposit_unknown_project() {
+    let ctx = TestContext::new();
+    ctx.client.get_min_deposit(&42);
+}
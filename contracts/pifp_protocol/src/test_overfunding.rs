@@ -0,0 +1,187 @@
+extern crate std;
+
+use soroban_sdk::{token, vec, Vec};
+
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectOptions, ProjectStatus};
+
+fn capped_project(
+    ctx: &TestContext,
+    goal: i128,
+) -> (
+    Project,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    let (token, sac) = ctx.create_token();
+    let project = ctx.register_project_with_options(
+        &Vec::from_array(&ctx.env, [token.address.clone()]),
+        goal,
+        ProjectOptions {
+            reject_overfunding: true,
+            ..Default::default()
+        },
+    );
+    (project, token, sac)
+}
+
+#[test]
+fn test_overfunding_allowed_by_default() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1500);
+
+    assert!(!ctx.client.rejects_overfunding(&project.id));
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1500);
+}
+
+#[test]
+fn test_deposit_up_to_exact_cap() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = capped_project(&ctx, 1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+
+    assert!(ctx.client.rejects_overfunding(&project.id));
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_deposit_past_cap_rejected_without_moving_funds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = capped_project(&ctx, 1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1001);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    let result = ctx
+        .client
+        .try_deposit(&project.id, &donator, &token.address, &1);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::GoalExceeded as u32
+        )))
+    );
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+    assert_eq!(token.balance(&donator), 1);
+    assert_eq!(
+        ctx.client.get_total_raised(&project.id, &token.address),
+        1000
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #35)")]
+fn test_single_deposit_above_cap_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = capped_project(&ctx, 1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1001);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1001);
+}
+
+#[test]
+fn test_single_goal_caps_every_token() {
+    let ctx = TestContext::new();
+    let (first, first_sac) = ctx.create_token();
+    let (second, second_sac) = ctx.create_token();
+    let project = ctx.register_project_with_options(
+        &vec![&ctx.env, first.address.clone(), second.address.clone()],
+        1000,
+        ProjectOptions {
+            reject_overfunding: true,
+            ..Default::default()
+        },
+    );
+    let donator = ctx.generate_address();
+    first_sac.mint(&donator, &600);
+    second_sac.mint(&donator, &5000);
+    ctx.client
+        .deposit(&project.id, &donator, &first.address, &600);
+
+    let result = ctx
+        .client
+        .try_deposit(&project.id, &donator, &second.address, &401);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::GoalExceeded as u32
+        )))
+    );
+
+    ctx.client
+        .deposit(&project.id, &donator, &second.address, &400);
+    assert_eq!(ctx.client.get_balance(&project.id, &second.address), 400);
+}
+
+#[test]
+fn test_tokens_without_goal_stay_uncapped() {
+    let ctx = TestContext::new();
+    let (usdc, _) = ctx.create_token();
+    let (xlm, xlm_sac) = ctx.create_token();
+    let project = ctx.client.register_project_v2(
+        &ctx.manager,
+        &vec![&ctx.env, (usdc.address.clone(), 1_000)],
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions {
+            reject_overfunding: true,
+            ..Default::default()
+        },
+    );
+    ctx.client
+        .add_accepted_token(&ctx.manager, &ctx.admin, &project.id, &xlm.address);
+    let donator = ctx.generate_address();
+    xlm_sac.mint(&donator, &5000);
+
+    ctx.client
+        .deposit(&project.id, &donator, &xlm.address, &5000);
+    assert_eq!(ctx.client.get_balance(&project.id, &xlm.address), 5000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #35)")]
+fn test_per_token_goals_cap_each_token() {
+    let ctx = TestContext::new();
+    let (usdc, _) = ctx.create_token();
+    let (xlm, xlm_sac) = ctx.create_token();
+    let project = ctx.client.register_project_v2(
+        &ctx.manager,
+        &vec![
+            &ctx.env,
+            (usdc.address.clone(), 1_000),
+            (xlm.address.clone(), 50_000),
+        ],
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions {
+            reject_overfunding: true,
+            ..Default::default()
+        },
+    );
+    let donator = ctx.generate_address();
+    xlm_sac.mint(&donator, &50_001);
+
+    ctx.client
+        .deposit(&project.id, &donator, &xlm.address, &50_001);
+}
@@ -0,0 +1,307 @@
+extern crate std;
+
+use soroban_sdk::{vec, BytesN, Vec};
+
+use crate::test_utils::TestContext;
+use crate::{ProjectOptions, ProjectStatus};
+
+fn unavailable<T>() -> Result<T, Result<soroban_sdk::Error, soroban_sdk::InvokeError>> {
+    Err(Ok(soroban_sdk::Error::from_contract_error(
+        crate::Error::PartialReleaseUnavailable as u32,
+    )))
+}
+
+#[test]
+fn test_partial_release_pays_requested_amount() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 400i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &amounts);
+
+    assert_eq!(token.balance(&ctx.manager), 400);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 600);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_partial_release_moves_funding_to_active() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &300i128);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 100i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &amounts);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_full_release_after_partial_drains_remainder() {
+    let ctx = TestContext::new();
+    let (token1, sac1) = ctx.create_token();
+    let (token2, sac2) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token1.address.clone(), token2.address.clone()]);
+    let project = ctx.register_project(&tokens, 1000);
+    let donator = ctx.generate_address();
+    sac1.mint(&donator, &1000);
+    sac2.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token1.address, &1000i128);
+    ctx.client
+        .deposit(&project.id, &donator, &token2.address, &500i128);
+
+    let amounts = vec![
+        &ctx.env,
+        (token1.address.clone(), 250i128),
+        (token2.address.clone(), 500i128),
+    ];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &amounts);
+    assert_eq!(token1.balance(&ctx.manager), 250);
+    assert_eq!(token2.balance(&ctx.manager), 500);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(token1.balance(&ctx.manager), 1000);
+    assert_eq!(ctx.client.get_balance(&project.id, &token1.address), 0);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_partial_release_applies_fee() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.admin, &1000);
+
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 500i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &amounts);
+
+    assert_eq!(ctx.client.get_fee_balance(&token.address), 50);
+    assert_eq!(token.balance(&ctx.manager), 450);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_partial_release_above_balance_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100i128);
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 101i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &amounts);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #33)")]
+fn test_partial_release_unaccepted_token_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let (other, _) = ctx.create_token();
+
+    let amounts = vec![&ctx.env, (other.address.clone(), 1i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &amounts);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_partial_release_wrong_hash_fails() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+    let wrong = BytesN::from_array(&ctx.env, &[0x01u8; 32]);
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 1i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &wrong, &amounts);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_partial_release_non_oracle_fails() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 1i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.manager, &project.id, &ctx.dummy_proof(), &amounts);
+}
+
+#[test]
+fn test_refunds_after_partial_release_are_pro_rata() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac.mint(&alice, &600);
+    sac.mint(&bob, &400);
+    ctx.client
+        .deposit(&project.id, &alice, &token.address, &600i128);
+    ctx.client
+        .deposit(&project.id, &bob, &token.address, &400i128);
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 700i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &amounts);
+
+    // The first to refund no longer takes more than their share of the 300
+    // left.
+    ctx.jump_time(86_401);
+    ctx.client.refund(&bob, &project.id, &token.address);
+    assert_eq!(token.balance(&bob), 120);
+    ctx.client.refund(&alice, &project.id, &token.address);
+    assert_eq!(token.balance(&alice), 180);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+fn test_shares_after_partial_release_add_up_to_the_escrow() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donors = [
+        (ctx.generate_address(), 500i128),
+        (ctx.generate_address(), 300i128),
+        (ctx.generate_address(), 200i128),
+    ];
+    for (donor, amount) in donors.iter() {
+        sac.mint(donor, amount);
+        ctx.client
+            .deposit(&project.id, donor, &token.address, amount);
+    }
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 899i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &amounts);
+    // A deposit after the tranche shares the shortfall like the others.
+    let late = ctx.generate_address();
+    sac.mint(&late, &100);
+    ctx.client
+        .deposit(&project.id, &late, &token.address, &100i128);
+
+    ctx.jump_time(86_401);
+    let waived = ctx
+        .client
+        .waive_refund(&donors[1].0, &project.id, &token.address);
+    ctx.client.refund(&late, &project.id, &token.address);
+    ctx.client.refund(&donors[2].0, &project.id, &token.address);
+    ctx.client.refund(&donors[0].0, &project.id, &token.address);
+
+    // 201 left of 1,100 contributed.
+    assert_eq!(waived, 54);
+    assert_eq!(token.balance(&late), 19);
+    assert_eq!(token.balance(&donors[2].0), 36);
+    assert_eq!(token.balance(&donors[0].0), 92);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+fn test_partial_release_unavailable_with_release_delay() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let project = ctx.register_project_with_options(
+        &vec![&ctx.env, token.address.clone()],
+        1000,
+        ProjectOptions {
+            release_delay: 3600,
+            ..Default::default()
+        },
+    );
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 400i128)];
+    let result = ctx.client.try_verify_and_release_partial(
+        &ctx.oracle,
+        &project.id,
+        &ctx.dummy_proof(),
+        &amounts,
+    );
+    assert_eq!(result, unavailable());
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+}
+
+#[test]
+fn test_partial_release_unavailable_with_protocol_delay() {
+    let ctx = TestContext::new();
+    ctx.client.set_release_delay(&ctx.admin, &3600);
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 400i128)];
+    let result = ctx.client.try_verify_and_release_partial(
+        &ctx.oracle,
+        &project.id,
+        &ctx.dummy_proof(),
+        &amounts,
+    );
+    assert_eq!(result, unavailable());
+    assert_eq!(token.balance(&ctx.manager), 0);
+}
+
+#[test]
+fn test_partial_release_unavailable_for_vesting_projects() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let project = ctx.register_project_with_options(
+        &vec![&ctx.env, token.address.clone()],
+        1000,
+        ProjectOptions {
+            vesting_duration_secs: 10_000,
+            ..Default::default()
+        },
+    );
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+
+    let amounts = vec![&ctx.env, (token.address.clone(), 400i128)];
+    let result = ctx.client.try_verify_and_release_partial(
+        &ctx.oracle,
+        &project.id,
+        &ctx.dummy_proof(),
+        &amounts,
+    );
+    assert_eq!(result, unavailable());
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert_eq!(ctx.client.get_vesting(&project.id, &token.address), None);
+}
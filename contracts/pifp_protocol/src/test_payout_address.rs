@@ -0,0 +1,180 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, token, vec, IntoVal, TryIntoVal, Vec};
+
+use crate::events::PayoutAddressChanged;
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectOptions, ProjectStatus};
+
+fn setup_funded_project(
+    ctx: &TestContext,
+    options: ProjectOptions,
+) -> (Project, token::Client<'static>) {
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_options(&tokens, 1000, options);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600i128);
+    (project, token)
+}
+
+#[test]
+fn test_release_goes_to_payout_address_set_at_registration() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    let (project, token) = setup_funded_project(
+        &ctx,
+        ProjectOptions {
+            payout_address: Some(treasury.clone()),
+            ..Default::default()
+        },
+    );
+    assert_eq!(project.payout_address, Some(treasury.clone()));
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(token.balance(&treasury), 600);
+    assert_eq!(token.balance(&ctx.manager), 0);
+}
+
+#[test]
+fn test_set_payout_address_redirects_release() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    let (project, token) = setup_funded_project(&ctx, ProjectOptions::default());
+
+    ctx.client
+        .set_payout_address(&ctx.manager, &project.id, &treasury);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).payout_address,
+        Some(treasury.clone())
+    );
+    assert_eq!(token.balance(&treasury), 600);
+    assert_eq!(token.balance(&ctx.manager), 0);
+}
+
+#[test]
+fn test_set_payout_address_while_active_redirects_later_releases() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    let (project, token) = setup_funded_project(&ctx, ProjectOptions::default());
+
+    let first = vec![&ctx.env, (token.address.clone(), 200i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &first);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+
+    ctx.client
+        .set_payout_address(&ctx.manager, &project.id, &treasury);
+    let second = vec![&ctx.env, (token.address.clone(), 300i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &second);
+
+    assert_eq!(token.balance(&ctx.manager), 200);
+    assert_eq!(token.balance(&treasury), 300);
+}
+
+#[test]
+fn test_set_payout_address_emits_event() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    let (project, _) = setup_funded_project(&ctx, ProjectOptions::default());
+
+    ctx.client
+        .set_payout_address(&ctx.manager, &project.id, &treasury);
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("payout").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    let event_data: PayoutAddressChanged = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        PayoutAddressChanged {
+            project_id: project.id,
+            old_address: ctx.manager.clone(),
+            new_address: treasury,
+        }
+    );
+}
+
+#[test]
+fn test_refund_ignores_payout_address() {
+    let ctx = TestContext::new();
+    let treasury = ctx.generate_address();
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_options(
+        &tokens,
+        1000,
+        ProjectOptions {
+            payout_address: Some(treasury.clone()),
+            ..Default::default()
+        },
+    );
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600i128);
+
+    ctx.jump_time(86400);
+    ctx.client.refund(&donator, &project.id, &token.address);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Expired
+    );
+    assert_eq!(token.balance(&donator), 600);
+    assert_eq!(token.balance(&treasury), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_payout_address_by_non_creator_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_funded_project(&ctx, ProjectOptions::default());
+
+    let stranger = ctx.generate_address();
+    ctx.client
+        .set_payout_address(&stranger, &project.id, &stranger);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_set_payout_address_after_completion_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_funded_project(&ctx, ProjectOptions::default());
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let treasury = ctx.generate_address();
+    ctx.client
+        .set_payout_address(&ctx.manager, &project.id, &treasury);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_set_payout_address_after_expiry_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_funded_project(&ctx, ProjectOptions::default());
+    ctx.jump_time(86400);
+    ctx.client.expire_project(&project.id);
+
+    let treasury = ctx.generate_address();
+    ctx.client
+        .set_payout_address(&ctx.manager, &project.id, &treasury);
+}
@@ -0,0 +1,213 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, token, vec, Address, IntoVal, TryIntoVal};
+
+use crate::events::{PledgeCreated, PledgeExecuted};
+use crate::test_utils::TestContext;
+use crate::Project;
+
+const DAY: u64 = 86_400;
+
+/// Register a 30-day project, fund `donator` and approve the contract to
+/// pull `allowance` of the project's token.
+fn setup_pledger(ctx: &TestContext, allowance: i128) -> (Project, token::Client<'static>, Address) {
+    let (project, token, sac) = ctx.setup_project(10_000);
+    ctx.client.update_deadline(
+        &ctx.manager,
+        &project.id,
+        &(ctx.env.ledger().timestamp() + 30 * DAY),
+    );
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1_000);
+    token.approve(&donator, &ctx.client.address, &allowance, &1_000);
+    (project, token, donator)
+}
+
+#[test]
+fn test_pledge_pulls_each_period_then_ends() {
+    let ctx = TestContext::new();
+    let (project, token, donator) = setup_pledger(&ctx, 300);
+    let pledge_id = ctx
+        .client
+        .create_pledge(&donator, &project.id, &token.address, &100, &DAY, &3);
+    let start = ctx.env.ledger().timestamp();
+
+    let receipt_id = ctx.client.execute_pledge(&pledge_id);
+    assert_eq!(ctx.client.get_receipt(&receipt_id).amount, 100);
+    let pledge = ctx.client.get_pledge(&pledge_id);
+    assert_eq!(pledge.remaining_periods, 2);
+    assert_eq!(pledge.next_due, start + DAY);
+
+    let early = ctx.client.try_execute_pledge(&pledge_id);
+    assert_eq!(
+        early,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::PledgeNotDue as u32
+        )))
+    );
+
+    ctx.jump_time(DAY);
+    ctx.client.execute_pledge(&pledge_id);
+    ctx.jump_time(DAY);
+    ctx.client.execute_pledge(&pledge_id);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 300);
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &donator),
+        300
+    );
+    assert_eq!(token.balance(&donator), 700);
+    assert!(ctx.client.try_get_pledge(&pledge_id).is_err());
+}
+
+#[test]
+fn test_anyone_can_execute_a_due_pledge() {
+    let ctx = TestContext::new();
+    let (project, token, donator) = setup_pledger(&ctx, 100);
+    let pledge_id = ctx
+        .client
+        .create_pledge(&donator, &project.id, &token.address, &100, &DAY, &1);
+
+    ctx.client.execute_pledge(&pledge_id);
+
+    // The pull runs on the donor's allowance, not on a fresh signature.
+    assert!(ctx.env.auths().is_empty());
+    assert_eq!(token.balance(&donator), 900);
+}
+
+#[test]
+fn test_pledge_on_expired_project_fails_without_pulling() {
+    let ctx = TestContext::new();
+    let (project, token, donator) = setup_pledger(&ctx, 300);
+    let pledge_id = ctx
+        .client
+        .create_pledge(&donator, &project.id, &token.address, &100, &DAY, &3);
+
+    ctx.jump_time(31 * DAY);
+    let result = ctx.client.try_execute_pledge(&pledge_id);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::ProjectExpired as u32
+        )))
+    );
+    assert_eq!(token.balance(&donator), 1_000);
+}
+
+#[test]
+fn test_cancel_pledge_stops_future_pulls() {
+    let ctx = TestContext::new();
+    let (project, token, donator) = setup_pledger(&ctx, 300);
+    let pledge_id = ctx
+        .client
+        .create_pledge(&donator, &project.id, &token.address, &100, &DAY, &3);
+    ctx.client.execute_pledge(&pledge_id);
+
+    ctx.client.cancel_pledge(&donator, &pledge_id);
+    ctx.jump_time(DAY);
+    let result = ctx.client.try_execute_pledge(&pledge_id);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::PledgeNotFound as u32
+        )))
+    );
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 100);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_only_donator_can_cancel_pledge() {
+    let ctx = TestContext::new();
+    let (project, token, donator) = setup_pledger(&ctx, 300);
+    let pledge_id = ctx
+        .client
+        .create_pledge(&donator, &project.id, &token.address, &100, &DAY, &3);
+
+    ctx.client
+        .cancel_pledge(&ctx.generate_address(), &pledge_id);
+}
+
+#[test]
+fn test_pledge_without_allowance_cannot_pull() {
+    let ctx = TestContext::new();
+    let (project, token, donator) = setup_pledger(&ctx, 50);
+    let pledge_id = ctx
+        .client
+        .create_pledge(&donator, &project.id, &token.address, &100, &DAY, &3);
+
+    assert!(ctx.client.try_execute_pledge(&pledge_id).is_err());
+    assert_eq!(token.balance(&donator), 1_000);
+    assert_eq!(ctx.client.get_pledge(&pledge_id).remaining_periods, 3);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_pledge_with_zero_period_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, donator) = setup_pledger(&ctx, 300);
+
+    ctx.client
+        .create_pledge(&donator, &project.id, &token.address, &100, &0, &3);
+}
+
+#[test]
+fn test_pledge_events() {
+    let ctx = TestContext::new();
+    let (project, token, donator) = setup_pledger(&ctx, 300);
+    let pledge_id = ctx
+        .client
+        .create_pledge(&donator, &project.id, &token.address, &100, &DAY, &3);
+
+    let created = ctx
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|e| {
+            e.0 == ctx.client.address
+                && e.1
+                    == vec![
+                        &ctx.env,
+                        symbol_short!("pledged").into_val(&ctx.env),
+                        project.id.into_val(&ctx.env),
+                        donator.into_val(&ctx.env),
+                    ]
+        })
+        .expect("pledged event");
+    let data: PledgeCreated = created.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        data,
+        PledgeCreated {
+            pledge_id,
+            project_id: project.id,
+            donator: donator.clone(),
+            token: token.address.clone(),
+            amount_per_period: 100,
+            period_secs: DAY,
+            periods: 3,
+        }
+    );
+
+    ctx.client.execute_pledge(&pledge_id);
+    let executed = ctx
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|e| {
+            e.0 == ctx.client.address
+                && e.1
+                    == vec![
+                        &ctx.env,
+                        symbol_short!("pledge_ex").into_val(&ctx.env),
+                        project.id.into_val(&ctx.env),
+                        pledge_id.into_val(&ctx.env),
+                    ]
+        })
+        .expect("pledge_ex event");
+    let data: PledgeExecuted = executed.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(data.amount, 100);
+    assert_eq!(data.remaining_periods, 2);
+}
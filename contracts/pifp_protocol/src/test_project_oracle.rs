@@ -0,0 +1,214 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal, Vec};
+
+use crate::events::OracleAssigned;
+use crate::test_utils::TestContext;
+use crate::{ProjectOptions, ProjectStatus, Role};
+
+#[test]
+fn test_register_with_assigned_oracle() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let assigned = ctx.generate_address();
+
+    let project = ctx.register_project_with_options(
+        &tokens,
+        1000,
+        ProjectOptions {
+            assigned_oracle: Some(assigned.clone()),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(project.assigned_oracle, Some(assigned.clone()));
+    assert_eq!(
+        ctx.client.get_project(&project.id).assigned_oracle,
+        Some(assigned)
+    );
+}
+
+#[test]
+fn test_assigned_oracle_can_verify_without_global_role() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let assigned = ctx.generate_address();
+    let project = ctx.register_project_with_options(
+        &tokens,
+        1000,
+        ProjectOptions {
+            assigned_oracle: Some(assigned.clone()),
+            ..Default::default()
+        },
+    );
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500i128);
+
+    ctx.client
+        .verify_and_release(&assigned, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_global_oracle_rejected_on_assigned_project() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_options(
+        &tokens,
+        1000,
+        ProjectOptions {
+            assigned_oracle: Some(ctx.generate_address()),
+            ..Default::default()
+        },
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_global_oracle_rejected_after_assignment() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let assigned = ctx.generate_address();
+    ctx.client
+        .set_project_oracle(&ctx.admin, &project.id, &Some(assigned));
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_clearing_assignment_restores_global_oracle() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .set_project_oracle(&ctx.admin, &project.id, &Some(ctx.generate_address()));
+    ctx.client
+        .set_project_oracle(&ctx.admin, &project.id, &None);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_set_project_oracle_emits_event() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let first = ctx.generate_address();
+    let second = ctx.generate_address();
+
+    ctx.client
+        .set_project_oracle(&ctx.admin, &project.id, &Some(first.clone()));
+    ctx.client
+        .set_project_oracle(&ctx.admin, &project.id, &Some(second.clone()));
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("orc_asgn").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    let event_data: OracleAssigned = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        OracleAssigned {
+            project_id: project.id,
+            old_oracle: Some(first),
+            new_oracle: Some(second),
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_project_oracle_requires_admin() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    // Even a global oracle cannot assign itself.
+    ctx.client
+        .set_project_oracle(&ctx.oracle, &project.id, &Some(ctx.oracle.clone()));
+}
+
+#[test]
+fn test_set_project_oracle_by_admin_role() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    let assigned = ctx.generate_address();
+
+    ctx.client
+        .set_project_oracle(&admin, &project.id, &Some(assigned.clone()));
+    assert_eq!(
+        ctx.client.get_project(&project.id).assigned_oracle,
+        Some(assigned)
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_set_project_oracle_on_completed_project_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client
+        .set_project_oracle(&ctx.admin, &project.id, &Some(ctx.generate_address()));
+}
+
+#[test]
+fn test_assigned_oracle_is_its_own_quorum() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &3);
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let assigned = ctx.generate_address();
+    let options = ProjectOptions {
+        assigned_oracle: Some(assigned.clone()),
+        ..Default::default()
+    };
+    let direct = ctx.register_project_with_options(&tokens, 1000, options.clone());
+    let voted = ctx.register_project_with_options(&tokens, 1000, options);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &2000);
+    ctx.client
+        .deposit(&direct.id, &donator, &token.address, &1000i128);
+    ctx.client
+        .deposit(&voted.id, &donator, &token.address, &1000i128);
+
+    ctx.client
+        .verify_and_release(&assigned, &direct.id, &ctx.dummy_proof());
+    ctx.client
+        .submit_verification_vote(&assigned, &voted.id, &ctx.dummy_proof());
+
+    for id in [direct.id, voted.id] {
+        assert_eq!(ctx.client.get_project(&id).status, ProjectStatus::Completed);
+    }
+    assert_eq!(token.balance(&ctx.manager), 2000);
+}
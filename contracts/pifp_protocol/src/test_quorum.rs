@@ -0,0 +1,279 @@
+extern crate std;
+
+use soroban_sdk::{Address, BytesN};
+
+use crate::test_utils::TestContext;
+use crate::{ProjectStatus, Role};
+
+fn add_oracles(ctx: &TestContext, n: usize) -> std::vec::Vec<Address> {
+    (0..n)
+        .map(|_| {
+            let oracle = ctx.generate_address();
+            ctx.client.grant_role(&ctx.admin, &oracle, &Role::Oracle);
+            oracle
+        })
+        .collect()
+}
+
+#[test]
+fn test_threshold_one_vote_releases_immediately() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400i128);
+
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 400);
+}
+
+#[test]
+fn test_threshold_three_requires_three_distinct_oracles() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &3);
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600i128);
+
+    let oracles = add_oracles(&ctx, 3);
+    ctx.client
+        .submit_verification_vote(&oracles[0], &project.id, &ctx.dummy_proof());
+    ctx.client
+        .submit_verification_vote(&oracles[1], &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+
+    ctx.client
+        .submit_verification_vote(&oracles[2], &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 600);
+}
+
+#[test]
+fn test_duplicate_vote_is_idempotent() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &2);
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(ctx.client.get_votes(&project.id).len(), 1);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+}
+
+#[test]
+fn test_conflicting_vote_is_rejected() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &2);
+    let (project, _, _) = ctx.setup_project(1000);
+    let other = add_oracles(&ctx, 1).pop().unwrap();
+    let wrong = BytesN::from_array(&ctx.env, &[0x01u8; 32]);
+
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &wrong);
+    let result =
+        ctx.client
+            .try_submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::AlreadyVoted as u32
+        )))
+    );
+    assert_eq!(
+        ctx.client.get_votes(&project.id).get(ctx.oracle.clone()),
+        Some(wrong)
+    );
+
+    // The first vote stands, so one more vote does not reach the quorum.
+    ctx.client
+        .submit_verification_vote(&other, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+}
+
+#[test]
+fn test_get_votes_empty_for_unvoted_project() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(ctx.client.get_votes(&project.id).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_quorum_on_wrong_hash_fails_verification() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let wrong = BytesN::from_array(&ctx.env, &[0x01u8; 32]);
+
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &wrong);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #25)")]
+fn test_verify_and_release_disabled_above_threshold_one() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &2);
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_vote_from_non_oracle_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let stranger = ctx.generate_address();
+
+    ctx.client
+        .submit_verification_vote(&stranger, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #3)")]
+fn test_vote_after_completion_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_zero_threshold_rejected() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &0);
+}
+
+#[test]
+fn test_admin_can_set_threshold() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_quorum_threshold(), 1);
+
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.client.set_quorum_threshold(&admin, &2);
+
+    assert_eq!(ctx.client.get_quorum_threshold(), 2);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_set_threshold_requires_admin() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.oracle, &2);
+}
+
+#[test]
+fn test_repeated_votes_never_reach_quorum_alone() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &2);
+    let (project, _, _) = ctx.setup_project(1000);
+
+    for _ in 0..3 {
+        ctx.client
+            .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    }
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+}
+
+#[test]
+fn test_lowering_threshold_releases_on_next_vote() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &3);
+    let (project, _, _) = ctx.setup_project(1000);
+    let oracles = add_oracles(&ctx, 2);
+
+    ctx.client
+        .submit_verification_vote(&oracles[0], &project.id, &ctx.dummy_proof());
+    ctx.client.set_quorum_threshold(&ctx.admin, &2);
+    ctx.client
+        .submit_verification_vote(&oracles[1], &project.id, &ctx.dummy_proof());
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+fn test_vote_on_expired_project_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.jump_time(86_401);
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_vote_on_explicitly_expired_project_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.jump_time(86_401);
+    ctx.client.expire_project(&project.id);
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_votes_of_revoked_oracles_are_not_counted() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &2);
+    let (project, _, _) = ctx.setup_project(1000);
+    let oracles = add_oracles(&ctx, 2);
+
+    ctx.client
+        .submit_verification_vote(&oracles[0], &project.id, &ctx.dummy_proof());
+    ctx.client.revoke_oracle(&ctx.admin, &oracles[0]);
+    ctx.client
+        .submit_verification_vote(&oracles[1], &project.id, &ctx.dummy_proof());
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
@@ -0,0 +1,160 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger, vec};
+
+use crate::test_utils::TestContext;
+use crate::DonationReceipt;
+
+#[test]
+fn test_deposit_returns_receipt() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &250);
+    ctx.env.ledger().set_timestamp(1_000);
+
+    let id = ctx
+        .client
+        .deposit(&project.id, &donator, &token.address, &250);
+
+    assert_eq!(
+        ctx.client.get_receipt(&id),
+        DonationReceipt {
+            id,
+            project_id: project.id,
+            donator,
+            token: token.address,
+            amount: 250,
+            timestamp: 1_000,
+        }
+    );
+}
+
+#[test]
+fn test_receipt_ids_increase_across_interleaved_projects() {
+    let ctx = TestContext::new();
+    let (first, token_a, sac_a) = ctx.setup_project(10_000);
+    let (second, token_b, sac_b) = ctx.setup_project(10_000);
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac_a.mint(&alice, &1000);
+    sac_b.mint(&alice, &1000);
+    sac_b.mint(&bob, &1000);
+
+    let ids = [
+        ctx.client.deposit(&first.id, &alice, &token_a.address, &10),
+        ctx.client.deposit(&second.id, &bob, &token_b.address, &20),
+        ctx.client
+            .deposit(&second.id, &alice, &token_b.address, &30),
+        ctx.client.deposit(&first.id, &alice, &token_a.address, &40),
+    ];
+
+    assert_eq!(ids, [0, 1, 2, 3]);
+    let receipt = ctx.client.get_receipt(&ids[2]);
+    assert_eq!(receipt.project_id, second.id);
+    assert_eq!(receipt.donator, alice);
+    assert_eq!(receipt.amount, 30);
+
+    let history = ctx.client.get_receipts_for_donor(&alice, &0, &10);
+    let history_ids: std::vec::Vec<u64> = history.iter().map(|r| r.id).collect();
+    assert_eq!(history_ids, [0, 2, 3]);
+    assert_eq!(
+        ctx.client.get_receipts_for_donor(&bob, &0, &10),
+        vec![&ctx.env, ctx.client.get_receipt(&1)]
+    );
+}
+
+#[test]
+fn test_deposit_many_issues_receipt_per_entry() {
+    let ctx = TestContext::new();
+    let (usdc, usdc_sac) = ctx.create_token();
+    let (xlm, xlm_sac) = ctx.create_token();
+    let tokens = vec![&ctx.env, usdc.address.clone(), xlm.address.clone()];
+    let project = ctx.register_project(&tokens, 10_000);
+    let donator = ctx.generate_address();
+    usdc_sac.mint(&donator, &100);
+    xlm_sac.mint(&donator, &200);
+
+    let ids = ctx.client.deposit_many(
+        &project.id,
+        &donator,
+        &vec![
+            &ctx.env,
+            (usdc.address.clone(), 100i128),
+            (xlm.address.clone(), 200i128),
+        ],
+    );
+
+    let history = ctx.client.get_receipts_for_donor(&donator, &0, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(
+        ids,
+        vec![
+            &ctx.env,
+            history.get(0).unwrap().id,
+            history.get(1).unwrap().id
+        ]
+    );
+    assert_eq!(history.get(0).unwrap().token, usdc.address);
+    assert_eq!(history.get(1).unwrap().amount, 200);
+}
+
+#[test]
+fn test_receipts_for_donor_paging() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    for amount in 1..=5i128 {
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &amount);
+    }
+
+    let page = ctx.client.get_receipts_for_donor(&donator, &1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().amount, 2);
+    assert_eq!(page.get(1).unwrap().amount, 3);
+
+    assert_eq!(
+        ctx.client.get_receipts_for_donor(&donator, &4, &10).len(),
+        1
+    );
+    assert!(ctx
+        .client
+        .get_receipts_for_donor(&donator, &5, &10)
+        .is_empty());
+    assert!(ctx
+        .client
+        .get_receipts_for_donor(&ctx.generate_address(), &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_failed_deposit_issues_no_receipt() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+
+    assert!(ctx
+        .client
+        .try_deposit(&project.id, &donator, &token.address, &0)
+        .is_err());
+    let id = ctx
+        .client
+        .deposit(&project.id, &donator, &token.address, &100);
+
+    assert_eq!(id, 0);
+    assert_eq!(
+        ctx.client.get_receipts_for_donor(&donator, &0, &10).len(),
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #38)")]
+fn test_unknown_receipt() {
+    let ctx = TestContext::new();
+
+    ctx.client.get_receipt(&7);
+}
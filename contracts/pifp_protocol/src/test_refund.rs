@@ -2,10 +2,11 @@ extern crate std;
 
 use soroban_sdk::{
     testutils::{Address as _, Ledger, LedgerInfo},
-    token, Address, BytesN, Env,
+    token, Address, BytesN, Env, String,
 };
 
-use crate::{PifpProtocol, PifpProtocolClient, ProjectStatus, Role};
+use crate::test_utils::TestContext;
+use crate::{PifpProtocol, PifpProtocolClient, ProjectOptions, ProjectStatus, Role};
 
 fn setup() -> (Env, PifpProtocolClient<'static>) {
     let env = Env::default();
@@ -52,8 +53,15 @@ fn test_refund_success_after_expiry() {
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
     let tokens = soroban_sdk::vec![&env, token.address.clone()];
-    let project =
-        client.register_project(&creator, &tokens, &1_000i128, &dummy_proof(&env), &deadline);
+    let project = client.register_project(
+        &creator,
+        &tokens,
+        &1_000i128,
+        &dummy_proof(&env),
+        &deadline,
+        &String::from_str(&env, "ipfs://metadata"),
+        &ProjectOptions::default(),
+    );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
     token_sac.mint(&donator, &1_000i128);
@@ -102,8 +110,15 @@ fn test_refund_fails_when_not_expired() {
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
     let tokens = soroban_sdk::vec![&env, token.address.clone()];
-    let project =
-        client.register_project(&creator, &tokens, &1_000i128, &dummy_proof(&env), &deadline);
+    let project = client.register_project(
+        &creator,
+        &tokens,
+        &1_000i128,
+        &dummy_proof(&env),
+        &deadline,
+        &String::from_str(&env, "ipfs://metadata"),
+        &ProjectOptions::default(),
+    );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
     token_sac.mint(&donator, &1_000i128);
@@ -124,8 +139,15 @@ fn test_refund_double_refund_fails() {
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
     let tokens = soroban_sdk::vec![&env, token.address.clone()];
-    let project =
-        client.register_project(&creator, &tokens, &1_000i128, &dummy_proof(&env), &deadline);
+    let project = client.register_project(
+        &creator,
+        &tokens,
+        &1_000i128,
+        &dummy_proof(&env),
+        &deadline,
+        &String::from_str(&env, "ipfs://metadata"),
+        &ProjectOptions::default(),
+    );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
     token_sac.mint(&donator, &1_000i128);
@@ -159,8 +181,15 @@ fn test_refund_wrong_donator_fails() {
 
     client.grant_role(&super_admin, &creator, &Role::ProjectManager);
     let tokens = soroban_sdk::vec![&env, token.address.clone()];
-    let project =
-        client.register_project(&creator, &tokens, &1_000i128, &dummy_proof(&env), &deadline);
+    let project = client.register_project(
+        &creator,
+        &tokens,
+        &1_000i128,
+        &dummy_proof(&env),
+        &deadline,
+        &String::from_str(&env, "ipfs://metadata"),
+        &ProjectOptions::default(),
+    );
 
     let token_sac = token::StellarAssetClient::new(&env, &token.address);
     token_sac.mint(&donator, &1_000i128);
@@ -179,3 +208,60 @@ fn test_refund_wrong_donator_fails() {
 
     client.refund(&attacker, &project.id, &token.address);
 }
+
+#[test]
+fn test_refund_to_sends_funds_to_recipient() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    let recipient = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400i128);
+
+    ctx.jump_time(86_401);
+    ctx.client
+        .refund_to(&donator, &project.id, &token.address, &recipient);
+
+    assert_eq!(token.balance(&recipient), 400);
+    assert_eq!(token.balance(&donator), 0);
+    assert_eq!(
+        ctx.client
+            .get_contribution(&project.id, &token.address, &donator),
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_refund_after_refund_to_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    let recipient = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400i128);
+
+    ctx.jump_time(86_401);
+    ctx.client
+        .refund_to(&donator, &project.id, &token.address, &recipient);
+    ctx.client.refund(&donator, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_refund_to_by_non_donator_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    let recipient = ctx.generate_address();
+    sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400i128);
+
+    // The recipient never donated, so it cannot claim a refund for itself.
+    ctx.jump_time(86_401);
+    ctx.client
+        .refund_to(&recipient, &project.id, &token.address, &recipient);
+}
@@ -0,0 +1,210 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, Symbol, TryIntoVal};
+
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectOptions};
+
+/// Number of `Refunded` events published by the last contract invocation.
+fn refunded_event_count(ctx: &TestContext) -> usize {
+    ctx.env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            let topic: Result<Symbol, _> = topics.get(0).unwrap().try_into_val(&ctx.env);
+            matches!(topic, Ok(t) if t == symbol_short!("refunded"))
+        })
+        .count()
+}
+
+fn three_token_project(
+    ctx: &TestContext,
+) -> (
+    Project,
+    std::vec::Vec<(
+        soroban_sdk::token::Client<'static>,
+        soroban_sdk::token::StellarAssetClient<'static>,
+    )>,
+) {
+    let tokens: std::vec::Vec<_> = (0..3).map(|_| ctx.create_token()).collect();
+    let mut addresses = vec![&ctx.env];
+    for (token, _) in tokens.iter() {
+        addresses.push_back(token.address.clone());
+    }
+    let project = ctx.register_project(&addresses, 10_000);
+    (project, tokens)
+}
+
+#[test]
+fn test_refund_all_returns_every_token() {
+    let ctx = TestContext::new();
+    let (project, tokens) = three_token_project(&ctx);
+    let donator = ctx.generate_address();
+    for (i, (token, sac)) in tokens.iter().enumerate() {
+        let amount = 100 * (i as i128 + 1);
+        sac.mint(&donator, &amount);
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &amount);
+    }
+    ctx.jump_time(project.deadline + 1);
+
+    ctx.client.refund_all(&donator, &project.id);
+
+    assert_eq!(refunded_event_count(&ctx), 3);
+    for (i, (token, _)) in tokens.iter().enumerate() {
+        assert_eq!(token.balance(&donator), 100 * (i as i128 + 1));
+        assert_eq!(
+            ctx.client
+                .get_contribution(&project.id, &token.address, &donator),
+            0
+        );
+    }
+}
+
+#[test]
+fn test_refund_all_after_explicit_expiry_returns_both_tokens() {
+    let ctx = TestContext::new();
+    let (first, first_sac) = ctx.create_token();
+    let (second, second_sac) = ctx.create_token();
+    let project = ctx.register_project(
+        &vec![&ctx.env, first.address.clone(), second.address.clone()],
+        10_000,
+    );
+    let donator = ctx.generate_address();
+    first_sac.mint(&donator, &300);
+    second_sac.mint(&donator, &700);
+    ctx.client
+        .deposit(&project.id, &donator, &first.address, &300);
+    ctx.client
+        .deposit(&project.id, &donator, &second.address, &700);
+    ctx.jump_time(project.deadline + 1);
+    ctx.client.expire_project(&project.id);
+
+    ctx.client.refund_all(&donator, &project.id);
+
+    assert_eq!(refunded_event_count(&ctx), 2);
+    assert_eq!(first.balance(&donator), 300);
+    assert_eq!(second.balance(&donator), 700);
+    assert_eq!(first.balance(&ctx.client.address), 0);
+    assert_eq!(second.balance(&ctx.client.address), 0);
+}
+
+#[test]
+fn test_refund_all_skips_tokens_without_balance() {
+    let ctx = TestContext::new();
+    let (project, tokens) = three_token_project(&ctx);
+    let donator = ctx.generate_address();
+    let (token, sac) = &tokens[1];
+    sac.mint(&donator, &250);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &250);
+    ctx.jump_time(project.deadline + 1);
+
+    ctx.client.refund_all(&donator, &project.id);
+
+    assert_eq!(refunded_event_count(&ctx), 1);
+    assert_eq!(token.balance(&donator), 250);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_refund_all_twice_fails() {
+    let ctx = TestContext::new();
+    let (project, tokens) = three_token_project(&ctx);
+    let donator = ctx.generate_address();
+    let (token, sac) = &tokens[0];
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    ctx.jump_time(project.deadline + 1);
+
+    ctx.client.refund_all(&donator, &project.id);
+    ctx.client.refund_all(&donator, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_refund_all_without_contributions_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = three_token_project(&ctx);
+    ctx.jump_time(project.deadline + 1);
+
+    ctx.client.refund_all(&ctx.generate_address(), &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_refund_all_before_deadline_fails() {
+    let ctx = TestContext::new();
+    let (project, tokens) = three_token_project(&ctx);
+    let donator = ctx.generate_address();
+    let (token, sac) = &tokens[0];
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+
+    ctx.client.refund_all(&donator, &project.id);
+}
+
+#[test]
+fn test_refund_all_after_cancellation() {
+    let ctx = TestContext::new();
+    let (project, tokens) = three_token_project(&ctx);
+    let donator = ctx.generate_address();
+    for (token, sac) in tokens.iter() {
+        sac.mint(&donator, &100);
+        ctx.client
+            .deposit(&project.id, &donator, &token.address, &100);
+    }
+    ctx.client.slash_project(
+        &ctx.admin,
+        &project.id,
+        &soroban_sdk::BytesN::from_array(&ctx.env, &[1u8; 32]),
+    );
+
+    ctx.client.refund_all(&donator, &project.id);
+
+    for (token, _) in tokens.iter() {
+        assert_eq!(token.balance(&donator), 100);
+    }
+}
+
+#[test]
+fn test_refund_all_pays_excess_shares() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let project = ctx.register_project_with_options(
+        &vec![&ctx.env, token.address.clone()],
+        1000,
+        ProjectOptions {
+            refund_excess: true,
+            ..Default::default()
+        },
+    );
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1500);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client.refund_all(&donator, &project.id);
+
+    assert_eq!(token.balance(&donator), 500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_refund_all_completed_without_excess_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1500);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client.refund_all(&donator, &project.id);
+}
@@ -0,0 +1,128 @@
+extern crate std;
+
+use soroban_sdk::{token, Address, Vec};
+
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectOptions};
+
+fn refund_excess_project(
+    ctx: &TestContext,
+    goal: i128,
+) -> (
+    Project,
+    token::Client<'static>,
+    token::StellarAssetClient<'static>,
+) {
+    let (token, sac) = ctx.create_token();
+    let project = ctx.register_project_with_options(
+        &Vec::from_array(&ctx.env, [token.address.clone()]),
+        goal,
+        ProjectOptions {
+            refund_excess: true,
+            ..Default::default()
+        },
+    );
+    (project, token, sac)
+}
+
+/// Fund `project` with one deposit per amount, each from a fresh donator.
+fn fund(
+    ctx: &TestContext,
+    project: &Project,
+    token: &token::Client<'static>,
+    sac: &token::StellarAssetClient<'static>,
+    amounts: &[i128],
+) -> std::vec::Vec<Address> {
+    amounts
+        .iter()
+        .map(|amount| {
+            let donator = ctx.generate_address();
+            sac.mint(&donator, amount);
+            ctx.client
+                .deposit(&project.id, &donator, &token.address, amount);
+            donator
+        })
+        .collect()
+}
+
+#[test]
+fn test_excess_refunded_pro_rata() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = refund_excess_project(&ctx, 1000);
+    let donators = fund(&ctx, &project, &token, &sac, &[700, 800]);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 500);
+
+    for donator in donators.iter() {
+        ctx.client.refund(donator, &project.id, &token.address);
+    }
+
+    // 700/1500 and 800/1500 of the 500 excess, floored cumulatively.
+    assert_eq!(token.balance(&donators[0]), 233);
+    assert_eq!(token.balance(&donators[1]), 267);
+    let total_out = token.balance(&ctx.manager) + 233 + 267;
+    assert_eq!(total_out, 1500);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+fn test_odd_split_conserves_funds_in_any_order() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = refund_excess_project(&ctx, 100);
+    let donators = fund(&ctx, &project, &token, &sac, &[37, 41, 29]);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    for donator in donators.iter().rev() {
+        ctx.client.refund(donator, &project.id, &token.address);
+    }
+
+    let refunded: i128 = donators.iter().map(|d| token.balance(d)).sum();
+    assert_eq!(refunded, 7);
+    assert_eq!(token.balance(&ctx.manager) + refunded, 107);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+#[test]
+fn test_excess_goes_to_creator_by_default() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    fund(&ctx, &project, &token, &sac, &[700, 800]);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert!(!ctx.client.refunds_excess(&project.id));
+    assert_eq!(token.balance(&ctx.manager), 1500);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_no_refund_without_excess() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = refund_excess_project(&ctx, 1000);
+    let donators = fund(&ctx, &project, &token, &sac, &[1000]);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(token.balance(&ctx.manager), 1000);
+
+    ctx.client.refund(&donators[0], &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_excess_share_claimed_once() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = refund_excess_project(&ctx, 1000);
+    let donators = fund(&ctx, &project, &token, &sac, &[700, 800]);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client.refund(&donators[0], &project.id, &token.address);
+    ctx.client.refund(&donators[0], &project.id, &token.address);
+}
@@ -0,0 +1,130 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal};
+
+use crate::events::RegistrationFeePaid;
+use crate::test_utils::TestContext;
+use crate::{ProjectOptions, Role};
+
+#[test]
+fn test_registration_is_free_by_default() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_registration_fee(), None);
+
+    let (project, _, _) = ctx.setup_project(1000);
+    assert_eq!(project.id, 0);
+}
+
+#[test]
+fn test_registration_fee_is_charged_to_creator() {
+    let ctx = TestContext::new();
+    let (fee_token, fee_sac) = ctx.create_token();
+    fee_sac.mint(&ctx.manager, &150);
+    ctx.client
+        .set_registration_fee(&ctx.admin, &fee_token.address, &100);
+    assert_eq!(
+        ctx.client.get_registration_fee(),
+        Some((fee_token.address.clone(), 100))
+    );
+
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let last_event = ctx.env.events().all().last().unwrap();
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("reg_fee").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let data: RegistrationFeePaid = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        data,
+        RegistrationFeePaid {
+            project_id: project.id,
+            creator: ctx.manager.clone(),
+            token: fee_token.address.clone(),
+            amount: 100,
+        }
+    );
+
+    assert_eq!(fee_token.balance(&ctx.manager), 50);
+    assert_eq!(fee_token.balance(&ctx.client.address), 100);
+    assert_eq!(ctx.client.get_fee_balance(&fee_token.address), 100);
+}
+
+#[test]
+fn test_failed_fee_transfer_saves_no_project() {
+    let ctx = TestContext::new();
+    let (fee_token, _) = ctx.create_token();
+    ctx.client
+        .set_registration_fee(&ctx.admin, &fee_token.address, &100);
+
+    let (token, _) = ctx.create_token();
+    let result = ctx.client.try_register_project(
+        &ctx.manager,
+        &vec![&ctx.env, token.address.clone()],
+        &1000,
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions::default(),
+    );
+    assert!(result.is_err());
+    assert!(ctx.client.try_get_project(&0).is_err());
+    assert_eq!(ctx.client.get_fee_balance(&fee_token.address), 0);
+}
+
+#[test]
+fn test_zero_fee_disables_registration_fee() {
+    let ctx = TestContext::new();
+    let (fee_token, _) = ctx.create_token();
+    ctx.client
+        .set_registration_fee(&ctx.admin, &fee_token.address, &100);
+    ctx.client
+        .set_registration_fee(&ctx.admin, &fee_token.address, &0);
+    assert_eq!(ctx.client.get_registration_fee(), None);
+
+    // The creator holds none of the fee token, yet registration succeeds.
+    ctx.setup_project(1000);
+}
+
+#[test]
+fn test_treasurer_withdraws_registration_fees() {
+    let ctx = TestContext::new();
+    let (fee_token, fee_sac) = ctx.create_token();
+    fee_sac.mint(&ctx.manager, &200);
+    ctx.client
+        .set_registration_fee(&ctx.admin, &fee_token.address, &100);
+    ctx.setup_project(1000);
+    ctx.setup_project(1000);
+
+    let treasurer = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &treasurer, &Role::Treasurer);
+    ctx.client
+        .withdraw_fees(&treasurer, &fee_token.address, &200, &treasurer);
+
+    assert_eq!(fee_token.balance(&treasurer), 200);
+    assert_eq!(ctx.client.get_fee_balance(&fee_token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_negative_registration_fee_rejected() {
+    let ctx = TestContext::new();
+    let (fee_token, _) = ctx.create_token();
+    ctx.client
+        .set_registration_fee(&ctx.admin, &fee_token.address, &-1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_set_registration_fee() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    let (fee_token, _) = ctx.create_token();
+
+    ctx.client
+        .set_registration_fee(&admin, &fee_token.address, &100);
+}
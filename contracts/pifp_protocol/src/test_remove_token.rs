@@ -0,0 +1,144 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, token, vec, IntoVal, TryIntoVal};
+
+use crate::events::AcceptedTokenRemoved;
+use crate::test_utils::TestContext;
+use crate::Project;
+
+fn setup_two_token_project(
+    ctx: &TestContext,
+) -> (
+    Project,
+    (token::Client<'static>, token::StellarAssetClient<'static>),
+    (token::Client<'static>, token::StellarAssetClient<'static>),
+) {
+    let first = ctx.create_token();
+    let second = ctx.create_token();
+    let tokens = vec![&ctx.env, first.0.address.clone(), second.0.address.clone()];
+    let project = ctx.register_project(&tokens, 1000);
+    (project, first, second)
+}
+
+#[test]
+fn test_remove_unfunded_token() {
+    let ctx = TestContext::new();
+    let (project, (first, _), (second, _)) = setup_two_token_project(&ctx);
+
+    ctx.client
+        .remove_accepted_token(&ctx.manager, &project.id, &second.address);
+
+    let last_event = ctx.env.events().all().last().unwrap();
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("tok_rm").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let data: AcceptedTokenRemoved = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        data,
+        AcceptedTokenRemoved {
+            project_id: project.id,
+            token: second.address.clone(),
+        }
+    );
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).accepted_tokens,
+        vec![&ctx.env, first.address.clone()]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #33)")]
+fn test_deposit_with_removed_token_fails() {
+    let ctx = TestContext::new();
+    let (project, _, (second, second_sac)) = setup_two_token_project(&ctx);
+    ctx.client
+        .remove_accepted_token(&ctx.manager, &project.id, &second.address);
+
+    let donator = ctx.generate_address();
+    second_sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &second.address, &100i128);
+}
+
+#[test]
+fn test_removing_first_token_moves_goal_to_next() {
+    let ctx = TestContext::new();
+    let (project, (first, _), (second, second_sac)) = setup_two_token_project(&ctx);
+    ctx.client
+        .remove_accepted_token(&ctx.manager, &project.id, &first.address);
+
+    let donator = ctx.generate_address();
+    second_sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &second.address, &1000i128);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        crate::ProjectStatus::Active
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_cannot_remove_funded_token() {
+    let ctx = TestContext::new();
+    let (project, _, (second, second_sac)) = setup_two_token_project(&ctx);
+    let donator = ctx.generate_address();
+    second_sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &second.address, &100i128);
+
+    ctx.client
+        .remove_accepted_token(&ctx.manager, &project.id, &second.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #17)")]
+fn test_cannot_remove_last_token() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+
+    ctx.client
+        .remove_accepted_token(&ctx.manager, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #33)")]
+fn test_cannot_remove_unaccepted_token() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let (other, _) = ctx.create_token();
+
+    ctx.client
+        .remove_accepted_token(&ctx.manager, &project.id, &other.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_only_creator_can_remove_token() {
+    let ctx = TestContext::new();
+    let (project, _, (second, _)) = setup_two_token_project(&ctx);
+
+    ctx.client
+        .remove_accepted_token(&ctx.admin, &project.id, &second.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_cannot_remove_token_after_completion() {
+    let ctx = TestContext::new();
+    let (project, (first, first_sac), (second, _)) = setup_two_token_project(&ctx);
+    let donator = ctx.generate_address();
+    first_sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &first.address, &1000i128);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client
+        .remove_accepted_token(&ctx.manager, &project.id, &second.address);
+}
@@ -0,0 +1,207 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    xdr::{ScErrorCode, ScErrorType, ToXdr},
+    Address, BytesN, Env,
+};
+
+use crate::test_utils::TestContext;
+use crate::{ProjectStatus, Role};
+
+fn signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32])
+}
+
+fn pubkey(env: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &key.verifying_key().to_bytes())
+}
+
+/// Sign the canonical `"pifp:verify" || contract || project_id || proof_hash`
+/// payload for the contract at `contract`.
+fn sign_for(
+    env: &Env,
+    key: &SigningKey,
+    contract: &Address,
+    project_id: u64,
+    proof_hash: &BytesN<32>,
+) -> BytesN<64> {
+    let mut payload = std::vec::Vec::new();
+    payload.extend_from_slice(b"pifp:verify");
+    payload.extend(contract.clone().to_xdr(env).iter());
+    payload.extend_from_slice(&project_id.to_be_bytes());
+    payload.extend_from_slice(&proof_hash.to_array());
+    BytesN::from_array(env, &key.sign(&payload).to_bytes())
+}
+
+fn sign(
+    ctx: &TestContext,
+    key: &SigningKey,
+    project_id: u64,
+    proof_hash: &BytesN<32>,
+) -> BytesN<64> {
+    sign_for(&ctx.env, key, &ctx.client.address, project_id, proof_hash)
+}
+
+/// The host error a bad signature surfaces as: `ed25519_verify` traps, so
+/// the invocation fails without a contract error code.
+fn trapped() -> soroban_sdk::Error {
+    soroban_sdk::Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction)
+}
+
+#[test]
+fn test_verify_with_signature_releases_funds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &800);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &800i128);
+
+    let key = signing_key();
+    let pk = pubkey(&ctx.env, &key);
+    ctx.client.register_oracle_key(&ctx.admin, &ctx.oracle, &pk);
+
+    let proof = ctx.dummy_proof();
+    let signature = sign(&ctx, &key, project.id, &proof);
+    ctx.client
+        .verify_with_signature(&project.id, &proof, &pk, &signature);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 800);
+}
+
+#[test]
+fn test_invalid_signature_moves_nothing() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &800);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &800i128);
+
+    let key = signing_key();
+    let pk = pubkey(&ctx.env, &key);
+    ctx.client.register_oracle_key(&ctx.admin, &ctx.oracle, &pk);
+
+    // Signed for a different project id.
+    let proof = ctx.dummy_proof();
+    let signature = sign(&ctx, &key, project.id + 1, &proof);
+    let result = ctx
+        .client
+        .try_verify_with_signature(&project.id, &proof, &pk, &signature);
+    assert_eq!(result, Err(Ok(trapped())));
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 800);
+    assert_eq!(token.balance(&ctx.manager), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_unregistered_key_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let key = signing_key();
+    let pk = pubkey(&ctx.env, &key);
+    let proof = ctx.dummy_proof();
+    let signature = sign(&ctx, &key, project.id, &proof);
+    ctx.client
+        .verify_with_signature(&project.id, &proof, &pk, &signature);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_key_of_revoked_oracle_rejected() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let key = signing_key();
+    let pk = pubkey(&ctx.env, &key);
+    ctx.client.register_oracle_key(&ctx.admin, &ctx.oracle, &pk);
+    ctx.client.revoke_role(&ctx.admin, &ctx.oracle);
+
+    let proof = ctx.dummy_proof();
+    let signature = sign(&ctx, &key, project.id, &proof);
+    ctx.client
+        .verify_with_signature(&project.id, &proof, &pk, &signature);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #16)")]
+fn test_signed_wrong_hash_fails_verification() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    let key = signing_key();
+    let pk = pubkey(&ctx.env, &key);
+    ctx.client.register_oracle_key(&ctx.admin, &ctx.oracle, &pk);
+
+    let wrong = BytesN::from_array(&ctx.env, &[0x01u8; 32]);
+    let signature = sign(&ctx, &key, project.id, &wrong);
+    ctx.client
+        .verify_with_signature(&project.id, &wrong, &pk, &signature);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_register_oracle_key_requires_admin() {
+    let ctx = TestContext::new();
+    let key = signing_key();
+    let pk = pubkey(&ctx.env, &key);
+
+    ctx.client
+        .register_oracle_key(&ctx.oracle, &ctx.oracle, &pk);
+}
+
+#[test]
+fn test_register_oracle_key_by_admin_role() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    let key = signing_key();
+    let pk = pubkey(&ctx.env, &key);
+    ctx.client.register_oracle_key(&admin, &ctx.oracle, &pk);
+
+    let proof = ctx.dummy_proof();
+    let signature = sign(&ctx, &key, project.id, &proof);
+    ctx.client
+        .verify_with_signature(&project.id, &proof, &pk, &signature);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_signature_for_another_deployment_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &800);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &800i128);
+
+    let key = signing_key();
+    let pk = pubkey(&ctx.env, &key);
+    ctx.client.register_oracle_key(&ctx.admin, &ctx.oracle, &pk);
+
+    // Same key, project id and proof, but signed for another contract.
+    let proof = ctx.dummy_proof();
+    let elsewhere = ctx.generate_address();
+    let signature = sign_for(&ctx.env, &key, &elsewhere, project.id, &proof);
+    let result = ctx
+        .client
+        .try_verify_with_signature(&project.id, &proof, &pk, &signature);
+    assert_eq!(result, Err(Ok(trapped())));
+    assert_eq!(token.balance(&ctx.manager), 0);
+}
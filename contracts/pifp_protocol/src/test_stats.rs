@@ -0,0 +1,150 @@
+extern crate std;
+
+use crate::test_utils::TestContext;
+use crate::ProtocolStats;
+
+#[test]
+fn test_stats_start_empty() {
+    let ctx = TestContext::new();
+
+    assert_eq!(
+        ctx.client.get_protocol_stats(),
+        ProtocolStats {
+            total_projects: 0,
+            total_completed: 0,
+            total_expired: 0,
+            total_refunds: 0,
+            funding_count: 0,
+            active_count: 0,
+            verified_count: 0,
+            total_cancelled: 0,
+        }
+    );
+}
+
+#[test]
+fn test_stats_follow_full_lifecycle() {
+    let ctx = TestContext::new();
+    let (completed, token_a, sac_a) = ctx.setup_project(1000);
+    let (expired, token_b, sac_b) = ctx.setup_project(1000);
+    let (untouched, _, _) = ctx.setup_project(1000);
+
+    let donator = ctx.generate_address();
+    sac_a.mint(&donator, &1000);
+    ctx.client
+        .deposit(&completed.id, &donator, &token_a.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &completed.id, &ctx.dummy_proof());
+
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac_b.mint(&alice, &100);
+    sac_b.mint(&bob, &200);
+    ctx.client
+        .deposit(&expired.id, &alice, &token_b.address, &100);
+    ctx.client
+        .deposit(&expired.id, &bob, &token_b.address, &200);
+
+    ctx.jump_time(86_401);
+    ctx.client.expire_project(&expired.id);
+    ctx.client.refund(&alice, &expired.id, &token_b.address);
+    ctx.client.refund(&bob, &expired.id, &token_b.address);
+
+    assert_eq!(
+        ctx.client.get_protocol_stats(),
+        ProtocolStats {
+            total_projects: 3,
+            total_completed: 1,
+            total_expired: 1,
+            total_refunds: 2,
+            // The untouched project has lapsed but is still stored as Funding.
+            funding_count: 1,
+            active_count: 0,
+            verified_count: 0,
+            total_cancelled: 0,
+        }
+    );
+
+    // A lapsed project is only counted once its expiry is persisted.
+    ctx.client.touch_expired(&untouched.id);
+    let stats = ctx.client.get_protocol_stats();
+    assert_eq!(stats.total_expired, 2);
+    assert_eq!(stats.funding_count, 0);
+}
+
+#[test]
+fn test_get_stats_matches_get_protocol_stats() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+
+    let stats = ctx.client.get_stats();
+    assert_eq!(stats.total_projects, 1);
+    assert_eq!(stats, ctx.client.get_protocol_stats());
+}
+
+#[test]
+fn test_failed_refund_leaves_stats_unchanged() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+    ctx.jump_time(86_401);
+    ctx.client.refund(&donator, &project.id, &token.address);
+
+    assert!(ctx
+        .client
+        .try_refund(&donator, &project.id, &token.address)
+        .is_err());
+
+    let stats = ctx.client.get_protocol_stats();
+    assert_eq!(stats.total_expired, 1);
+    assert_eq!(stats.total_refunds, 1);
+}
+
+#[test]
+fn test_status_counts_follow_transitions() {
+    let ctx = TestContext::new();
+    let (active, token, sac) = ctx.setup_project(1000);
+    let (cancelled, _, _) = ctx.setup_project(1000);
+    ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+
+    ctx.client
+        .deposit(&active.id, &donator, &token.address, &1000);
+    ctx.client.cancel_project(&ctx.manager, &cancelled.id);
+
+    let stats = ctx.client.get_protocol_stats();
+    assert_eq!(stats.total_projects, 3);
+    assert_eq!(stats.funding_count, 1);
+    assert_eq!(stats.active_count, 1);
+    assert_eq!(stats.total_cancelled, 1);
+}
+
+#[test]
+fn test_total_volume_spans_projects_and_survives_payouts() {
+    let ctx = TestContext::new();
+    let (first, token, sac) = ctx.setup_project(1000);
+    let second = ctx.register_project(&soroban_sdk::vec![&ctx.env, token.address.clone()], 1000);
+    let (_, other, _) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+
+    ctx.client
+        .deposit(&first.id, &donator, &token.address, &1000);
+    ctx.client
+        .deposit(&second.id, &donator, &token.address, &500);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &first.id, &ctx.dummy_proof());
+    ctx.jump_time(86_401);
+    ctx.client.refund(&donator, &second.id, &token.address);
+
+    assert_eq!(ctx.client.get_total_volume(&token.address), 1500);
+    assert_eq!(ctx.client.get_total_volume(&other.address), 0);
+}
@@ -0,0 +1,196 @@
+extern crate std;
+
+use crate::types::can_transition;
+use crate::{test_utils::TestContext, ProjectStatus};
+
+/// Every status variant. The exhaustive match in `variant_index` forces this
+/// list (and the expectation table below) to be revisited whenever a new
+/// variant is added.
+const ALL: [ProjectStatus; 6] = [
+    ProjectStatus::Funding,
+    ProjectStatus::Active,
+    ProjectStatus::Completed,
+    ProjectStatus::Expired,
+    ProjectStatus::Cancelled,
+    ProjectStatus::Verified,
+];
+
+fn variant_index(status: &ProjectStatus) -> usize {
+    match status {
+        ProjectStatus::Funding => 0,
+        ProjectStatus::Active => 1,
+        ProjectStatus::Completed => 2,
+        ProjectStatus::Expired => 3,
+        ProjectStatus::Cancelled => 4,
+        ProjectStatus::Verified => 5,
+    }
+}
+
+/// A fresh address holding the Auditor role, to read the stored status.
+fn auditor(ctx: &TestContext) -> soroban_sdk::Address {
+    let auditor = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &auditor, &crate::Role::Auditor);
+    auditor
+}
+
+/// Expected result of `from.can_transition_to(to)`, indexed `[from][to]`.
+const EXPECTED: [[bool; 6]; 6] = [
+    //  Funding Active Completed Expired Cancelled Verified
+    [false, true, true, true, true, true],      // Funding
+    [false, false, true, true, true, true],     // Active
+    [false, false, false, false, false, false], // Completed
+    [false, false, false, false, false, false], // Expired
+    [false, false, false, false, false, false], // Cancelled
+    [false, true, true, false, true, false],    // Verified
+];
+
+#[test]
+fn test_transition_matrix_is_exhaustive() {
+    for (i, from) in ALL.iter().enumerate() {
+        assert_eq!(variant_index(from), i);
+        for (j, to) in ALL.iter().enumerate() {
+            assert_eq!(
+                from.can_transition_to(to),
+                EXPECTED[i][j],
+                "unexpected result for {:?} -> {:?}",
+                from,
+                to
+            );
+        }
+    }
+}
+
+#[test]
+fn test_transition_matrix_agrees_with_invariant() {
+    for from in ALL.iter() {
+        for to in ALL.iter() {
+            if from.can_transition_to(to) {
+                crate::invariants::assert_valid_status_transition(from, to);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_free_function_matches_method() {
+    for from in ALL.iter() {
+        for to in ALL.iter() {
+            assert_eq!(can_transition(from, to), from.can_transition_to(to));
+        }
+    }
+}
+
+#[test]
+fn test_no_self_transitions() {
+    for status in ALL.iter() {
+        assert!(!status.can_transition_to(status));
+    }
+}
+
+#[test]
+fn test_terminal_states_have_no_exits() {
+    for terminal in [
+        ProjectStatus::Completed,
+        ProjectStatus::Expired,
+        ProjectStatus::Cancelled,
+    ] {
+        for to in ALL.iter() {
+            assert!(!terminal.can_transition_to(to));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_deposit_into_completed_project_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100i128);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_verify_expired_project_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.jump_time(project.deadline + 1);
+    ctx.client.expire_project(&project.id);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_expire_twice_fails_with_invalid_state_transition() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.jump_time(project.deadline + 1);
+    ctx.client.expire_project(&project.id);
+    ctx.client.expire_project(&project.id);
+}
+
+#[test]
+fn test_funding_active_completed_happy_path() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Active
+    );
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Completed
+    );
+}
+
+#[test]
+fn test_refunds_on_expired_active_project_keep_it_expired() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac.mint(&alice, &600);
+    sac.mint(&bob, &400);
+    ctx.client
+        .deposit(&project.id, &alice, &token.address, &600);
+    ctx.client.deposit(&project.id, &bob, &token.address, &400);
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Active
+    );
+
+    ctx.jump_time(project.deadline + 1);
+    ctx.client.refund(&alice, &project.id, &token.address);
+
+    // The refund persisted Active -> Expired; the balance dropping below the
+    // goal does not send the project back to Funding.
+    assert_eq!(
+        ctx.client.audit_project(&auditor(&ctx), &project.id).status,
+        ProjectStatus::Expired
+    );
+    ctx.client.refund(&bob, &project.id, &token.address);
+    assert_eq!(
+        ctx.client.get_project_status(&project.id),
+        ProjectStatus::Expired
+    );
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
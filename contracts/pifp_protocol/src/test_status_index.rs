@@ -0,0 +1,208 @@
+extern crate std;
+
+use soroban_sdk::{token, Vec};
+
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectOptions, ProjectStatus};
+
+const ALL: [ProjectStatus; 6] = [
+    ProjectStatus::Funding,
+    ProjectStatus::Active,
+    ProjectStatus::Completed,
+    ProjectStatus::Expired,
+    ProjectStatus::Cancelled,
+    ProjectStatus::Verified,
+];
+
+/// Assert `project_id` is indexed under `expected` and no other status.
+fn assert_indexed(ctx: &TestContext, project_id: u64, expected: ProjectStatus) {
+    for status in ALL {
+        let ids = ctx.client.get_projects_by_status(&status, &0, &25);
+        assert_eq!(ids.contains(project_id), status == expected, "{status:?}");
+    }
+}
+
+fn fund(ctx: &TestContext, project: &Project, sac: &token::StellarAssetClient, amount: i128) {
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &amount);
+    ctx.client.deposit(
+        &project.id,
+        &donator,
+        &project.accepted_tokens.get(0).unwrap(),
+        &amount,
+    );
+}
+
+#[test]
+fn test_registration_indexes_funding() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    assert_indexed(&ctx, project.id, ProjectStatus::Funding);
+}
+
+#[test]
+fn test_deposit_reaching_goal_moves_to_active() {
+    let ctx = TestContext::new();
+    let (project, _, sac) = ctx.setup_project(1000);
+
+    fund(&ctx, &project, &sac, 400);
+    assert_indexed(&ctx, project.id, ProjectStatus::Funding);
+
+    fund(&ctx, &project, &sac, 600);
+    assert_indexed(&ctx, project.id, ProjectStatus::Active);
+}
+
+#[test]
+fn test_verification_moves_to_completed() {
+    let ctx = TestContext::new();
+    let (project, _, sac) = ctx.setup_project(1000);
+    fund(&ctx, &project, &sac, 1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_indexed(&ctx, project.id, ProjectStatus::Completed);
+}
+
+#[test]
+fn test_timelocked_release_passes_through_verified() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    let project = ctx.register_project_with_options(
+        &Vec::from_array(&ctx.env, [token.address.clone()]),
+        1000,
+        ProjectOptions {
+            release_delay: 3600,
+            ..Default::default()
+        },
+    );
+    fund(&ctx, &project, &sac, 1000);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_indexed(&ctx, project.id, ProjectStatus::Verified);
+
+    ctx.jump_time(3600);
+    ctx.client.claim_release(&project.id);
+    assert_indexed(&ctx, project.id, ProjectStatus::Completed);
+}
+
+#[test]
+fn test_expiry_moves_to_expired() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    // Lapsed but not yet expired: still listed under its stored status.
+    ctx.jump_time(project.deadline + 1);
+    assert_indexed(&ctx, project.id, ProjectStatus::Funding);
+
+    ctx.client.expire_project(&project.id);
+    assert_indexed(&ctx, project.id, ProjectStatus::Expired);
+}
+
+#[test]
+fn test_cancellation_moves_to_cancelled() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+
+    ctx.client.cancel_project(&ctx.manager, &project.id);
+
+    assert_indexed(&ctx, project.id, ProjectStatus::Cancelled);
+}
+
+#[test]
+fn test_pages_start_at_id_and_respect_limit() {
+    let ctx = TestContext::new();
+    for _ in 0..5 {
+        ctx.setup_project(1000);
+    }
+    ctx.client.cancel_project(&ctx.manager, &2);
+
+    let first: std::vec::Vec<u64> = ctx
+        .client
+        .get_projects_by_status(&ProjectStatus::Funding, &0, &2)
+        .iter()
+        .collect();
+    assert_eq!(first, [0, 1]);
+
+    let rest: std::vec::Vec<u64> = ctx
+        .client
+        .get_projects_by_status(&ProjectStatus::Funding, &2, &10)
+        .iter()
+        .collect();
+    assert_eq!(rest, [3, 4]);
+
+    assert!(ctx
+        .client
+        .get_projects_by_status(&ProjectStatus::Funding, &5, &10)
+        .is_empty());
+    assert!(ctx
+        .client
+        .get_projects_by_status(&ProjectStatus::Active, &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_limit_is_capped() {
+    let ctx = TestContext::new();
+    let (_, token, _) = ctx.setup_project(1000);
+    let tokens = soroban_sdk::vec![&ctx.env, token.address.clone()];
+    for _ in 0..29 {
+        ctx.register_project(&tokens, 1000);
+    }
+
+    assert_eq!(
+        ctx.client
+            .get_projects_by_status(&ProjectStatus::Funding, &0, &100)
+            .len(),
+        25
+    );
+}
+
+#[test]
+fn test_pages_cross_index_buckets() {
+    let ctx = TestContext::new();
+    let (_, token, _) = ctx.setup_project(1000);
+    let tokens = soroban_sdk::vec![&ctx.env, token.address.clone()];
+    for _ in 1..70 {
+        ctx.register_project(&tokens, 1000);
+    }
+    ctx.client.cancel_project(&ctx.manager, &63);
+    ctx.client.cancel_project(&ctx.manager, &64);
+
+    let funding: std::vec::Vec<u64> = ctx
+        .client
+        .get_projects_by_status(&ProjectStatus::Funding, &60, &5)
+        .iter()
+        .collect();
+    assert_eq!(funding, [60, 61, 62, 65, 66]);
+    let cancelled: std::vec::Vec<u64> = ctx
+        .client
+        .get_projects_by_status(&ProjectStatus::Cancelled, &0, &25)
+        .iter()
+        .collect();
+    assert_eq!(cancelled, [63, 64]);
+}
+
+#[test]
+fn test_scan_is_bounded_to_a_window() {
+    let ctx = TestContext::new();
+    let (_, token, _) = ctx.setup_project(1000);
+    let tokens = soroban_sdk::vec![&ctx.env, token.address.clone()];
+    for _ in 1..260 {
+        ctx.register_project(&tokens, 1000);
+    }
+    ctx.client.cancel_project(&ctx.manager, &258);
+
+    // Ids 0..256 hold no cancelled project: an empty page, not the end.
+    assert!(ctx
+        .client
+        .get_projects_by_status(&ProjectStatus::Cancelled, &0, &25)
+        .is_empty());
+    assert_eq!(
+        ctx.client
+            .get_projects_by_status(&ProjectStatus::Cancelled, &256, &25),
+        soroban_sdk::vec![&ctx.env, 258]
+    );
+}
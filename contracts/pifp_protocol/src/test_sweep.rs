@@ -0,0 +1,97 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+use crate::test_utils::TestContext;
+use crate::{ProjectOptions, Role};
+
+#[test]
+fn test_sweep_only_takes_untracked_surplus() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600);
+
+    // Sent straight to the contract, bypassing `deposit`.
+    sac.mint(&ctx.client.address, &250);
+    let recipient = ctx.generate_address();
+
+    let swept = ctx
+        .client
+        .sweep_untracked(&ctx.admin, &token.address, &recipient);
+
+    assert_eq!(swept, 250);
+    assert_eq!(token.balance(&recipient), 250);
+    assert_eq!(token.balance(&ctx.client.address), 600);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 600);
+
+    // Escrow is untouched: the donor can still be refunded in full.
+    ctx.jump_time(project.deadline + 1);
+    ctx.client.refund(&donator, &project.id, &token.address);
+    assert_eq!(token.balance(&donator), 600);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_sweep_without_surplus_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600);
+
+    ctx.client
+        .sweep_untracked(&ctx.admin, &token.address, &ctx.admin);
+}
+
+#[test]
+fn test_sweep_leaves_fees_bonds_and_vesting() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    ctx.client.set_fee_bps(&ctx.admin, &100);
+    ctx.client.set_creator_bond(&ctx.admin, &token.address, &50);
+    sac.mint(&ctx.manager, &100);
+
+    let vesting = ctx.register_project_with_options(
+        &vec![&ctx.env, token.address.clone()],
+        1000,
+        ProjectOptions {
+            vesting_duration_secs: 1000,
+            ..Default::default()
+        },
+    );
+    let bonded = ctx.register_project(&vec![&ctx.env, token.address.clone()], 1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&vesting.id, &donator, &token.address, &1000);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &vesting.id, &ctx.dummy_proof());
+
+    // Fee of 10, 990 vesting and the second project's bond of 50 all stay.
+    assert_eq!(ctx.client.get_fee_balance(&token.address), 10);
+    assert!(ctx.client.get_project_bond(&bonded.id).is_some());
+    sac.mint(&ctx.client.address, &7);
+    let recipient = ctx.generate_address();
+
+    ctx.client
+        .sweep_untracked(&ctx.admin, &token.address, &recipient);
+
+    assert_eq!(token.balance(&recipient), 7);
+    assert_eq!(token.balance(&ctx.client.address), 10 + 990 + 50);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_sweep_requires_super_admin() {
+    let ctx = TestContext::new();
+    let (token, sac) = ctx.create_token();
+    sac.mint(&ctx.client.address, &100);
+    let admin = Address::generate(&ctx.env);
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.sweep_untracked(&admin, &token.address, &admin);
+}
@@ -0,0 +1,166 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Ledger, token, Address};
+
+use crate::storage::DEFAULT_REFUND_GRACE_SECS;
+use crate::test_utils::TestContext;
+use crate::Project;
+
+const GRACE: u64 = 1_000;
+
+/// A project with two donors of 400 and 600 whose deadline has passed,
+/// under a refund grace of `GRACE`.
+fn lapsed_project(ctx: &TestContext) -> (Project, token::Client<'static>, Address, Address) {
+    ctx.client.set_refund_grace(&ctx.admin, &GRACE);
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac.mint(&alice, &400);
+    sac.mint(&bob, &600);
+    ctx.client
+        .deposit(&project.id, &alice, &token.address, &400);
+    ctx.client.deposit(&project.id, &bob, &token.address, &600);
+    ctx.env.ledger().set_timestamp(project.deadline);
+    (project, token, alice, bob)
+}
+
+#[test]
+fn test_grace_boundary() {
+    let ctx = TestContext::new();
+    let (project, token, _, _) = lapsed_project(&ctx);
+    let treasury = ctx.generate_address();
+
+    // Exactly `GRACE` past the deadline is still inside the window.
+    ctx.env.ledger().set_timestamp(project.deadline + GRACE);
+    let result = ctx
+        .client
+        .try_sweep_unclaimed(&ctx.admin, &project.id, &token.address, &treasury);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::GracePeriodActive as u32
+        )))
+    );
+
+    ctx.env.ledger().set_timestamp(project.deadline + GRACE + 1);
+    let swept = ctx
+        .client
+        .sweep_unclaimed(&ctx.admin, &project.id, &token.address, &treasury);
+
+    assert_eq!(swept, 1000);
+    assert_eq!(token.balance(&treasury), 1000);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 0);
+}
+
+#[test]
+fn test_sweep_takes_only_unclaimed_remainder() {
+    let ctx = TestContext::new();
+    let (project, token, alice, _) = lapsed_project(&ctx);
+    ctx.client.refund(&alice, &project.id, &token.address);
+    let treasury = ctx.generate_address();
+
+    ctx.env.ledger().set_timestamp(project.deadline + GRACE + 1);
+    ctx.client
+        .sweep_unclaimed(&ctx.admin, &project.id, &token.address, &treasury);
+
+    assert_eq!(token.balance(&alice), 400);
+    assert_eq!(token.balance(&treasury), 600);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_refund_after_sweep_fails() {
+    let ctx = TestContext::new();
+    let (project, token, _, bob) = lapsed_project(&ctx);
+    ctx.env.ledger().set_timestamp(project.deadline + GRACE + 1);
+    ctx.client.sweep_unclaimed(
+        &ctx.admin,
+        &project.id,
+        &token.address,
+        &ctx.generate_address(),
+    );
+
+    ctx.client.refund(&bob, &project.id, &token.address);
+}
+
+#[test]
+fn test_sweep_to_contract_credits_fee_treasury() {
+    let ctx = TestContext::new();
+    let (project, token, _, _) = lapsed_project(&ctx);
+    ctx.env.ledger().set_timestamp(project.deadline + GRACE + 1);
+
+    ctx.client
+        .sweep_unclaimed(&ctx.admin, &project.id, &token.address, &ctx.client.address);
+
+    assert_eq!(ctx.client.get_fee_balance(&token.address), 1000);
+    assert_eq!(token.balance(&ctx.client.address), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #37)")]
+fn test_default_grace_applies() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(10_000);
+    assert_eq!(ctx.client.get_refund_grace(), DEFAULT_REFUND_GRACE_SECS);
+    ctx.env
+        .ledger()
+        .set_timestamp(project.deadline + DEFAULT_REFUND_GRACE_SECS);
+
+    ctx.client.sweep_unclaimed(
+        &ctx.admin,
+        &project.id,
+        &token.address,
+        &ctx.generate_address(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_sweep_requires_expired_project() {
+    let ctx = TestContext::new();
+    let (project, token, _, _) = lapsed_project(&ctx);
+    ctx.env.ledger().set_timestamp(project.deadline - 1);
+
+    ctx.client.sweep_unclaimed(
+        &ctx.admin,
+        &project.id,
+        &token.address,
+        &ctx.generate_address(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_sweep_twice_fails() {
+    let ctx = TestContext::new();
+    let (project, token, _, _) = lapsed_project(&ctx);
+    ctx.env.ledger().set_timestamp(project.deadline + GRACE + 1);
+    let treasury = ctx.generate_address();
+
+    ctx.client
+        .sweep_unclaimed(&ctx.admin, &project.id, &token.address, &treasury);
+    ctx.client
+        .sweep_unclaimed(&ctx.admin, &project.id, &token.address, &treasury);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_sweep_unclaimed_requires_admin() {
+    let ctx = TestContext::new();
+    let (project, token, alice, _) = lapsed_project(&ctx);
+    ctx.env.ledger().set_timestamp(project.deadline + GRACE + 1);
+
+    ctx.client
+        .sweep_unclaimed(&alice, &project.id, &token.address, &alice);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_set_refund_grace() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client
+        .grant_role(&ctx.admin, &admin, &crate::Role::Admin);
+
+    ctx.client.set_refund_grace(&admin, &0);
+}
@@ -0,0 +1,352 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, token, vec, IntoVal, TryIntoVal, Vec};
+
+use crate::events::{ReleaseScheduled, ReleaseVetoed};
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectOptions, ProjectStatus, Role};
+
+const DELAY: u64 = 3600;
+
+fn setup_timelocked_project(ctx: &TestContext) -> (Project, token::Client<'static>) {
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_options(
+        &tokens,
+        1000,
+        ProjectOptions {
+            release_delay: DELAY,
+            ..Default::default()
+        },
+    );
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+    (project, token)
+}
+
+#[test]
+fn test_verify_with_delay_schedules_release() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_timelocked_project(&ctx);
+    assert_eq!(project.release_delay, DELAY);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert_eq!(token.balance(&ctx.client.address), 1000);
+}
+
+#[test]
+fn test_verify_with_delay_emits_release_scheduled() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("rel_sched").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    let event_data: ReleaseScheduled = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        ReleaseScheduled {
+            project_id: project.id,
+            release_after: ctx.env.ledger().timestamp() + DELAY,
+        }
+    );
+}
+
+#[test]
+fn test_claim_release_after_delay() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_timelocked_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(DELAY);
+    ctx.client.claim_release(&project.id);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #29)")]
+fn test_claim_release_too_early_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(DELAY - 1);
+    ctx.client.claim_release(&project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_claim_release_of_unverified_project_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+
+    ctx.client.claim_release(&project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_claim_release_twice_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(DELAY);
+    ctx.client.claim_release(&project.id);
+    ctx.client.claim_release(&project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_verify_verified_project_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+}
+
+#[test]
+fn test_release_delay_defaults_to_zero() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.get_release_delay(), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_set_release_delay() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+
+    ctx.client.set_release_delay(&admin, &DELAY);
+}
+
+#[test]
+fn test_protocol_delay_locks_release_until_creator_claims() {
+    let ctx = TestContext::new();
+    ctx.client.set_release_delay(&ctx.admin, &DELAY);
+    assert_eq!(ctx.client.get_release_delay(), DELAY);
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+
+    ctx.jump_time(DELAY);
+    ctx.client.claim_released_funds(&ctx.manager, &project.id);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 1000);
+}
+
+#[test]
+fn test_longer_of_project_and_protocol_delay_applies() {
+    let ctx = TestContext::new();
+    ctx.client.set_release_delay(&ctx.admin, &(DELAY * 2));
+    let (project, _) = setup_timelocked_project(&ctx);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    let event_data: ReleaseScheduled = ctx
+        .env
+        .events()
+        .all()
+        .last()
+        .unwrap()
+        .2
+        .try_into_val(&ctx.env)
+        .unwrap();
+    assert_eq!(
+        event_data.release_after,
+        ctx.env.ledger().timestamp() + DELAY * 2
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #29)")]
+fn test_claim_released_funds_early_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client.claim_released_funds(&ctx.manager, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_claim_released_funds_by_non_creator_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(DELAY);
+
+    let stranger = ctx.generate_address();
+    ctx.client.claim_released_funds(&stranger, &project.id);
+}
+
+#[test]
+fn test_veto_returns_project_to_active() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_timelocked_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client.veto_release(&ctx.admin, &project.id);
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("rel_veto").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+    let event_data: ReleaseVetoed = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        ReleaseVetoed {
+            project_id: project.id,
+            vetoed_by: ctx.admin.clone(),
+        }
+    );
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+    assert_eq!(token.balance(&ctx.client.address), 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_claim_after_veto_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client.veto_release(&ctx.admin, &project.id);
+
+    ctx.jump_time(DELAY);
+    ctx.client.claim_release(&project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_veto_after_unlock_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(DELAY);
+    ctx.client.veto_release(&ctx.admin, &project.id);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_veto_by_oracle_fails() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client.veto_release(&ctx.oracle, &project.id);
+}
+
+#[test]
+fn test_veto_discards_votes() {
+    let ctx = TestContext::new();
+    ctx.client.set_quorum_threshold(&ctx.admin, &2);
+    let (project, token) = setup_timelocked_project(&ctx);
+    let second = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &second, &Role::Oracle);
+
+    ctx.client
+        .submit_verification_vote(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client
+        .submit_verification_vote(&second, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Verified
+    );
+
+    ctx.client.veto_release(&ctx.admin, &project.id);
+    assert!(ctx.client.get_votes(&project.id).is_empty());
+
+    // One new vote is no longer enough to reach the quorum of two.
+    ctx.client
+        .submit_verification_vote(&second, &project.id, &ctx.dummy_proof());
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+    ctx.jump_time(DELAY);
+    let result = ctx.client.try_claim_release(&project.id);
+    assert!(result.is_err());
+    assert_eq!(token.balance(&ctx.client.address), 1000);
+}
+
+#[test]
+fn test_veto_discards_pending_approval() {
+    let ctx = TestContext::new();
+    let (project, _) = setup_timelocked_project(&ctx);
+    ctx.client
+        .approve_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client.veto_release(&ctx.admin, &project.id);
+
+    assert_eq!(ctx.client.get_release_approval(&project.id), None);
+    let result = ctx.client.try_finalize_release(&ctx.admin, &project.id);
+    assert_eq!(
+        result,
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            crate::Error::NotAuthorized as u32
+        )))
+    );
+}
@@ -0,0 +1,242 @@
+extern crate std;
+
+use soroban_sdk::{vec, Address, Vec};
+
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectOptions, ProjectStatus, TokenProgress};
+
+fn register_v2(
+    ctx: &TestContext,
+    token_goals: &Vec<(Address, i128)>,
+    require_all_goals: bool,
+) -> Project {
+    ctx.client.register_project_v2(
+        &ctx.manager,
+        token_goals,
+        &ctx.dummy_proof(),
+        &(ctx.env.ledger().timestamp() + 86400),
+        &ctx.dummy_metadata(),
+        &ProjectOptions {
+            require_all_goals,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn test_register_v2_stores_per_token_goals() {
+    let ctx = TestContext::new();
+    let (usdc, _) = ctx.create_token();
+    let (xlm, _) = ctx.create_token();
+    let goals = vec![
+        &ctx.env,
+        (usdc.address.clone(), 1_000),
+        (xlm.address.clone(), 50_000),
+    ];
+
+    let project = register_v2(&ctx, &goals, false);
+
+    assert_eq!(project.token_goals, goals);
+    assert_eq!(
+        project.accepted_tokens,
+        vec![&ctx.env, usdc.address, xlm.address]
+    );
+    // The legacy field mirrors the first token's goal.
+    assert_eq!(project.goal, 1_000);
+    assert_eq!(ctx.client.get_project(&project.id), project);
+}
+
+#[test]
+fn test_any_goal_met_activates() {
+    let ctx = TestContext::new();
+    let (usdc, _) = ctx.create_token();
+    let (xlm, xlm_sac) = ctx.create_token();
+    let project = register_v2(
+        &ctx,
+        &vec![
+            &ctx.env,
+            (usdc.address.clone(), 1_000),
+            (xlm.address.clone(), 50_000),
+        ],
+        false,
+    );
+
+    let donator = ctx.generate_address();
+    xlm_sac.mint(&donator, &50_000);
+    ctx.client
+        .deposit(&project.id, &donator, &xlm.address, &49_999);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+
+    ctx.client.deposit(&project.id, &donator, &xlm.address, &1);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_all_goals_required_when_flagged() {
+    let ctx = TestContext::new();
+    let (usdc, usdc_sac) = ctx.create_token();
+    let (xlm, xlm_sac) = ctx.create_token();
+    let project = register_v2(
+        &ctx,
+        &vec![
+            &ctx.env,
+            (usdc.address.clone(), 1_000),
+            (xlm.address.clone(), 50_000),
+        ],
+        true,
+    );
+
+    let donator = ctx.generate_address();
+    usdc_sac.mint(&donator, &1_000);
+    xlm_sac.mint(&donator, &50_000);
+
+    ctx.client
+        .deposit(&project.id, &donator, &usdc.address, &1_000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Funding
+    );
+
+    ctx.client
+        .deposit(&project.id, &donator, &xlm.address, &50_000);
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+fn test_funding_progress_per_token() {
+    let ctx = TestContext::new();
+    let (usdc, usdc_sac) = ctx.create_token();
+    let (xlm, _) = ctx.create_token();
+    let project = register_v2(
+        &ctx,
+        &vec![
+            &ctx.env,
+            (usdc.address.clone(), 1_000),
+            (xlm.address.clone(), 50_000),
+        ],
+        false,
+    );
+
+    let donator = ctx.generate_address();
+    usdc_sac.mint(&donator, &400);
+    ctx.client
+        .deposit(&project.id, &donator, &usdc.address, &400);
+
+    assert_eq!(
+        ctx.client.get_funding_progress(&project.id).tokens,
+        vec![
+            &ctx.env,
+            TokenProgress {
+                token: usdc.address,
+                raised: 400,
+                goal: 1_000,
+            },
+            TokenProgress {
+                token: xlm.address,
+                raised: 0,
+                goal: 50_000,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_funding_progress_for_single_goal_project() {
+    let ctx = TestContext::new();
+    let (first, _) = ctx.create_token();
+    let (second, _) = ctx.create_token();
+    let project = ctx.register_project(
+        &vec![&ctx.env, first.address.clone(), second.address.clone()],
+        1_000,
+    );
+
+    assert!(project.token_goals.is_empty());
+    assert_eq!(
+        ctx.client.get_funding_progress(&project.id).tokens,
+        vec![
+            &ctx.env,
+            TokenProgress {
+                token: first.address,
+                raised: 0,
+                goal: 1_000,
+            },
+            TokenProgress {
+                token: second.address,
+                raised: 0,
+                goal: 0,
+            },
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+fn test_funding_progress_unknown_project() {
+    let ctx = TestContext::new();
+    ctx.client.get_funding_progress(&42);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+fn test_register_v2_rejects_non_positive_goal() {
+    let ctx = TestContext::new();
+    let (usdc, _) = ctx.create_token();
+    let (xlm, _) = ctx.create_token();
+    register_v2(
+        &ctx,
+        &vec![&ctx.env, (usdc.address, 1_000), (xlm.address, 0)],
+        false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #17)")]
+fn test_register_v2_rejects_empty_goals() {
+    let ctx = TestContext::new();
+    register_v2(&ctx, &Vec::new(&ctx.env), false);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+fn test_register_v2_rejects_duplicate_token() {
+    let ctx = TestContext::new();
+    let (usdc, _) = ctx.create_token();
+    register_v2(
+        &ctx,
+        &vec![
+            &ctx.env,
+            (usdc.address.clone(), 1_000),
+            (usdc.address, 2_000),
+        ],
+        false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #20)")]
+fn test_token_with_goal_cannot_be_removed() {
+    let ctx = TestContext::new();
+    let (usdc, _) = ctx.create_token();
+    let (xlm, _) = ctx.create_token();
+    let project = register_v2(
+        &ctx,
+        &vec![
+            &ctx.env,
+            (usdc.address.clone(), 1_000),
+            (xlm.address.clone(), 50_000),
+        ],
+        false,
+    );
+
+    ctx.client
+        .remove_accepted_token(&ctx.manager, &project.id, &xlm.address);
+}
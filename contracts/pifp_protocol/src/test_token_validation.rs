@@ -0,0 +1,46 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+use crate::test_utils::TestContext;
+use crate::PifpProtocol;
+
+#[test]
+fn test_registration_with_sac_succeeds() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+
+    let project = ctx.register_project(&vec![&ctx.env, token.address.clone()], 1000);
+    assert_eq!(project.accepted_tokens, vec![&ctx.env, token.address]);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #34)")]
+fn test_registration_with_non_token_contract_rejected() {
+    let ctx = TestContext::new();
+    // A deployed contract that does not implement the token interface.
+    let not_a_token = ctx.env.register(PifpProtocol, ());
+
+    ctx.register_project(&vec![&ctx.env, not_a_token], 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #34)")]
+fn test_registration_with_undeployed_address_rejected() {
+    let ctx = TestContext::new();
+    let (token, _) = ctx.create_token();
+    let nothing_deployed = Address::generate(&ctx.env);
+
+    ctx.register_project(&vec![&ctx.env, token.address, nothing_deployed], 1000);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #34)")]
+fn test_add_accepted_token_rejects_non_token_contract() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let not_a_token = ctx.env.register(PifpProtocol, ());
+
+    ctx.client
+        .add_accepted_token(&ctx.manager, &ctx.admin, &project.id, &not_a_token);
+}
@@ -0,0 +1,137 @@
+extern crate std;
+
+use soroban_sdk::vec;
+
+use crate::test_utils::TestContext;
+use crate::ProjectStatus;
+
+#[test]
+fn test_total_raised_starts_at_zero() {
+    let ctx = TestContext::new();
+    let (project, token, _) = ctx.setup_project(1000);
+
+    assert_eq!(ctx.client.get_total_raised(&project.id, &token.address), 0);
+}
+
+#[test]
+fn test_total_raised_survives_refunds() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let alice = ctx.generate_address();
+    let bob = ctx.generate_address();
+    sac.mint(&alice, &300);
+    sac.mint(&bob, &200);
+
+    ctx.client
+        .deposit(&project.id, &alice, &token.address, &100);
+    ctx.client.deposit(&project.id, &bob, &token.address, &200);
+    ctx.client
+        .deposit(&project.id, &alice, &token.address, &200);
+    assert_eq!(
+        ctx.client.get_total_raised(&project.id, &token.address),
+        500
+    );
+
+    ctx.jump_time(86401);
+    ctx.client.expire_project(&project.id);
+    ctx.client.refund(&alice, &project.id, &token.address);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 200);
+    assert_eq!(
+        ctx.client.get_total_raised(&project.id, &token.address),
+        500
+    );
+}
+
+#[test]
+fn test_total_raised_keeps_counting_after_partial_release() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1500);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000);
+    let amounts = vec![&ctx.env, (token.address.clone(), 600i128)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &amounts);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &500);
+
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 900);
+    assert_eq!(
+        ctx.client.get_total_raised(&project.id, &token.address),
+        1500
+    );
+
+    let balances = ctx.client.get_project_balances(&project.id).balances;
+    let entry = balances.get(0).unwrap();
+    assert_eq!(entry.balance, 900);
+    assert_eq!(entry.total_raised, 1500);
+}
+
+#[test]
+fn test_total_raised_is_per_token() {
+    let ctx = TestContext::new();
+    let (first, first_sac) = ctx.create_token();
+    let (second, second_sac) = ctx.create_token();
+    let project = ctx.register_project(
+        &vec![&ctx.env, first.address.clone(), second.address.clone()],
+        1000,
+    );
+    let donator = ctx.generate_address();
+    first_sac.mint(&donator, &100);
+    second_sac.mint(&donator, &40);
+
+    ctx.client
+        .deposit(&project.id, &donator, &first.address, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &second.address, &40);
+
+    assert_eq!(
+        ctx.client.get_total_raised(&project.id, &first.address),
+        100
+    );
+    assert_eq!(
+        ctx.client.get_total_raised(&project.id, &second.address),
+        40
+    );
+}
+
+#[test]
+fn test_activation_uses_lifetime_total() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &600);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &400);
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Active
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #18)")]
+fn test_total_raised_overflow_rejected() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &i128::MAX);
+
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &(i128::MAX - 10));
+    // Releasing frees room in the escrowed balance, but lifetime totals
+    // are never reduced, so the next deposit overflows.
+    let amounts = vec![&ctx.env, (token.address.clone(), i128::MAX - 20)];
+    ctx.client
+        .verify_and_release_partial(&ctx.oracle, &project.id, &ctx.dummy_proof(), &amounts);
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+}
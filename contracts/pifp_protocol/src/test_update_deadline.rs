@@ -0,0 +1,109 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Events, vec, IntoVal, TryIntoVal};
+
+use crate::events::DeadlineExtended;
+use crate::test_utils::TestContext;
+use crate::ProjectStatus;
+
+#[test]
+fn test_creator_can_shorten_deadline_before_deposits() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let new_deadline = project.deadline - 3_600;
+
+    ctx.client
+        .update_deadline(&ctx.manager, &project.id, &new_deadline);
+
+    let updated = ctx.client.get_project(&project.id);
+    assert_eq!(updated.deadline, new_deadline);
+    assert_eq!(updated.status, ProjectStatus::Funding);
+}
+
+#[test]
+fn test_admin_can_update_deadline() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let new_deadline = project.deadline + 86_400;
+
+    ctx.client
+        .update_deadline(&ctx.admin, &project.id, &new_deadline);
+
+    assert_eq!(ctx.client.get_project(&project.id).deadline, new_deadline);
+}
+
+#[test]
+fn test_update_deadline_emits_event() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let new_deadline = project.deadline + 60;
+
+    ctx.client
+        .update_deadline(&ctx.manager, &project.id, &new_deadline);
+
+    let all_events = ctx.env.events().all();
+    let last_event = all_events.last().expect("No events found");
+
+    let expected_topics = vec![
+        &ctx.env,
+        symbol_short!("dl_ext").into_val(&ctx.env),
+        project.id.into_val(&ctx.env),
+    ];
+    assert_eq!(last_event.1, expected_topics);
+
+    let event_data: DeadlineExtended = last_event.2.try_into_val(&ctx.env).unwrap();
+    assert_eq!(
+        event_data,
+        DeadlineExtended {
+            project_id: project.id,
+            old_deadline: project.deadline,
+            new_deadline,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_update_deadline_by_stranger_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let stranger = ctx.generate_address();
+
+    ctx.client
+        .update_deadline(&stranger, &project.id, &(project.deadline + 60));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #22)")]
+fn test_update_deadline_after_deposit_fails() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(1000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &10);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &10i128);
+
+    ctx.client
+        .update_deadline(&ctx.manager, &project.id, &(project.deadline + 60));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_update_deadline_in_past_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let now = ctx.env.ledger().timestamp();
+
+    ctx.client.update_deadline(&ctx.manager, &project.id, &now);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+fn test_update_deadline_beyond_cap_fails() {
+    let ctx = TestContext::new();
+    let (project, _, _) = ctx.setup_project(1000);
+    let too_far = ctx.env.ledger().timestamp() + 157_680_001;
+
+    ctx.client
+        .update_deadline(&ctx.manager, &project.id, &too_far);
+}
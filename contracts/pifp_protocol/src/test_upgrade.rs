@@ -0,0 +1,44 @@
+extern crate std;
+
+use soroban_sdk::BytesN;
+
+use crate::test_utils::TestContext;
+use crate::Role;
+
+#[test]
+fn test_version_starts_at_one() {
+    let ctx = TestContext::new();
+    assert_eq!(ctx.client.version(), 1);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #30)")]
+fn test_upgrade_requires_pause() {
+    let ctx = TestContext::new();
+    let hash = BytesN::from_array(&ctx.env, &[0x11u8; 32]);
+
+    ctx.client.upgrade(&ctx.admin, &hash);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_admin_cannot_upgrade() {
+    let ctx = TestContext::new();
+    let admin = ctx.generate_address();
+    ctx.client.grant_role(&ctx.admin, &admin, &Role::Admin);
+    ctx.client.pause(&ctx.admin);
+    let hash = BytesN::from_array(&ctx.env, &[0x11u8; 32]);
+
+    ctx.client.upgrade(&admin, &hash);
+}
+
+#[test]
+fn test_upgrade_to_unknown_wasm_keeps_version() {
+    let ctx = TestContext::new();
+    ctx.client.pause(&ctx.admin);
+    // No code has been uploaded under this hash, so the swap is rejected.
+    let hash = BytesN::from_array(&ctx.env, &[0x11u8; 32]);
+
+    assert!(ctx.client.try_upgrade(&ctx.admin, &hash).is_err());
+    assert_eq!(ctx.client.version(), 1);
+}
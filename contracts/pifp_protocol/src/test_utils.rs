@@ -2,10 +2,10 @@ extern crate std;
 
 use soroban_sdk::{
     testutils::{Address as _, Ledger, LedgerInfo},
-    token, Address, BytesN, Env, Vec,
+    token, Address, BytesN, Env, String, Vec,
 };
 
-use crate::{types::Project, PifpProtocol, PifpProtocolClient, Role};
+use crate::{types::Project, PifpProtocol, PifpProtocolClient, ProjectOptions, Role};
 
 pub struct TestContext {
     pub env: Env,
@@ -77,16 +77,36 @@ impl TestContext {
     }
 
     pub fn register_project(&self, tokens: &Vec<Address>, goal: i128) -> Project {
+        self.register_project_with_options(tokens, goal, ProjectOptions::default())
+    }
+
+    pub fn register_project_with_options(
+        &self,
+        tokens: &Vec<Address>,
+        goal: i128,
+        options: ProjectOptions,
+    ) -> Project {
         let proof_hash = self.dummy_proof();
         let deadline = self.env.ledger().timestamp() + 86400;
-        self.client
-            .register_project(&self.manager, tokens, &goal, &proof_hash, &deadline)
+        self.client.register_project(
+            &self.manager,
+            tokens,
+            &goal,
+            &proof_hash,
+            &deadline,
+            &self.dummy_metadata(),
+            &options,
+        )
     }
 
     pub fn dummy_proof(&self) -> BytesN<32> {
         BytesN::from_array(&self.env, &[0xabu8; 32])
     }
 
+    pub fn dummy_metadata(&self) -> String {
+        String::from_str(&self.env, "ipfs://bafybeigdyrztexample")
+    }
+
     pub fn jump_time(&self, seconds: u64) {
         let mut ledger = self.env.ledger().get();
         ledger.timestamp += seconds;
@@ -0,0 +1,154 @@
+extern crate std;
+
+use soroban_sdk::{token, Vec};
+
+use crate::test_utils::TestContext;
+use crate::{Project, ProjectOptions, ProjectStatus, VestingSchedule};
+
+const VESTING: u64 = 1000;
+
+fn setup_vesting_project(ctx: &TestContext) -> (Project, token::Client<'static>) {
+    let (token, sac) = ctx.create_token();
+    let tokens = Vec::from_array(&ctx.env, [token.address.clone()]);
+    let project = ctx.register_project_with_options(
+        &tokens,
+        1000,
+        ProjectOptions {
+            vesting_duration_secs: VESTING,
+            ..Default::default()
+        },
+    );
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &1000);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &1000i128);
+    (project, token)
+}
+
+#[test]
+fn test_release_starts_vesting_instead_of_paying_out() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_vesting_project(&ctx);
+    assert_eq!(project.vesting_duration_secs, VESTING);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    assert_eq!(
+        ctx.client.get_project(&project.id).status,
+        ProjectStatus::Completed
+    );
+    assert_eq!(token.balance(&ctx.manager), 0);
+    assert_eq!(token.balance(&ctx.client.address), 1000);
+    assert_eq!(
+        ctx.client.get_vesting(&project.id, &token.address),
+        Some(VestingSchedule {
+            total: 1000,
+            claimed_so_far: 0,
+            released_at: ctx.env.ledger().timestamp(),
+        })
+    );
+}
+
+#[test]
+fn test_claim_vested_linearly_then_remainder() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_vesting_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(250);
+    let claimed = ctx
+        .client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+    assert_eq!(claimed, 250);
+    assert_eq!(token.balance(&ctx.manager), 250);
+
+    ctx.jump_time(250);
+    ctx.client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+    assert_eq!(token.balance(&ctx.manager), 500);
+
+    // Past the full duration everything left is claimable.
+    ctx.jump_time(VESTING);
+    let claimed = ctx
+        .client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+    assert_eq!(claimed, 500);
+    assert_eq!(token.balance(&ctx.manager), 1000);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+    assert_eq!(
+        ctx.client
+            .get_vesting(&project.id, &token.address)
+            .unwrap()
+            .claimed_so_far,
+        1000
+    );
+}
+
+#[test]
+fn test_vesting_amount_is_net_of_fee() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.admin, &500);
+    let (project, token) = setup_vesting_project(&ctx);
+
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    assert_eq!(ctx.client.get_fee_balance(&token.address), 50);
+
+    ctx.jump_time(VESTING);
+    ctx.client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+    assert_eq!(token.balance(&ctx.manager), 950);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_claim_with_nothing_vested_fails() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_vesting_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_claim_after_full_claim_fails() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_vesting_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+
+    ctx.jump_time(VESTING);
+    ctx.client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+    ctx.client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+fn test_claim_before_release_fails() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_vesting_project(&ctx);
+
+    ctx.client
+        .claim_vested(&ctx.manager, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_claim_by_non_creator_fails() {
+    let ctx = TestContext::new();
+    let (project, token) = setup_vesting_project(&ctx);
+    ctx.client
+        .verify_and_release(&ctx.oracle, &project.id, &ctx.dummy_proof());
+    ctx.jump_time(VESTING);
+
+    let stranger = ctx.generate_address();
+    ctx.client
+        .claim_vested(&stranger, &project.id, &token.address);
+}
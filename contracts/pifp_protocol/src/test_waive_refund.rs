@@ -0,0 +1,141 @@
+extern crate std;
+
+use soroban_sdk::{token, Address};
+
+use crate::test_utils::TestContext;
+use crate::Project;
+
+/// An expired project with one donator per amount.
+fn expired_project(
+    ctx: &TestContext,
+    amounts: &[i128],
+) -> (Project, token::Client<'static>, std::vec::Vec<Address>) {
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donators = amounts
+        .iter()
+        .map(|amount| {
+            let donator = ctx.generate_address();
+            sac.mint(&donator, amount);
+            ctx.client
+                .deposit(&project.id, &donator, &token.address, amount);
+            donator
+        })
+        .collect();
+    ctx.jump_time(project.deadline + 1);
+    (project, token, donators)
+}
+
+#[test]
+fn test_waiving_and_refunding_conserves_total() {
+    let ctx = TestContext::new();
+    let (project, token, donators) = expired_project(&ctx, &[100, 200, 300, 400]);
+    let [alice, bob, carol, dave] = [0, 1, 2, 3].map(|i| donators[i].clone());
+
+    assert_eq!(
+        ctx.client.waive_refund(&alice, &project.id, &token.address),
+        100
+    );
+    ctx.client.refund(&bob, &project.id, &token.address);
+    ctx.client.waive_refund(&carol, &project.id, &token.address);
+
+    assert_eq!(ctx.client.get_waived(&project.id, &token.address), 400);
+    assert_eq!(ctx.client.get_balance(&project.id, &token.address), 400);
+
+    let claimed = ctx
+        .client
+        .claim_waived(&ctx.manager, &project.id, &token.address);
+    ctx.client.refund(&dave, &project.id, &token.address);
+
+    assert_eq!(claimed, 400);
+    assert_eq!(token.balance(&ctx.manager), 400);
+    assert_eq!(token.balance(&bob), 200);
+    assert_eq!(token.balance(&dave), 400);
+    assert_eq!(token.balance(&alice) + token.balance(&carol), 0);
+    assert_eq!(
+        token.balance(&ctx.manager) + token.balance(&bob) + token.balance(&dave),
+        1000
+    );
+    assert_eq!(token.balance(&ctx.client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_refund_after_waiving_fails() {
+    let ctx = TestContext::new();
+    let (project, token, donators) = expired_project(&ctx, &[100]);
+    ctx.client
+        .waive_refund(&donators[0], &project.id, &token.address);
+
+    ctx.client.refund(&donators[0], &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #21)")]
+fn test_waive_requires_expired_project() {
+    let ctx = TestContext::new();
+    let (project, token, sac) = ctx.setup_project(10_000);
+    let donator = ctx.generate_address();
+    sac.mint(&donator, &100);
+    ctx.client
+        .deposit(&project.id, &donator, &token.address, &100);
+
+    ctx.client
+        .waive_refund(&donator, &project.id, &token.address);
+}
+
+#[test]
+fn test_sweep_leaves_waived_funds_to_creator() {
+    let ctx = TestContext::new();
+    ctx.client.set_refund_grace(&ctx.admin, &0);
+    let (project, token, donators) = expired_project(&ctx, &[100, 200]);
+    ctx.client
+        .waive_refund(&donators[0], &project.id, &token.address);
+    let treasury = ctx.generate_address();
+
+    ctx.client
+        .sweep_unclaimed(&ctx.admin, &project.id, &token.address, &treasury);
+    ctx.client
+        .claim_waived(&ctx.manager, &project.id, &token.address);
+
+    assert_eq!(token.balance(&treasury), 200);
+    assert_eq!(token.balance(&ctx.manager), 100);
+}
+
+#[test]
+fn test_claim_waived_takes_protocol_fee() {
+    let ctx = TestContext::new();
+    ctx.client.set_fee_bps(&ctx.admin, &100);
+    let (project, token, donators) = expired_project(&ctx, &[1000]);
+    ctx.client
+        .waive_refund(&donators[0], &project.id, &token.address);
+
+    let claimed = ctx
+        .client
+        .claim_waived(&ctx.manager, &project.id, &token.address);
+
+    assert_eq!(claimed, 990);
+    assert_eq!(ctx.client.get_fee_balance(&token.address), 10);
+    assert_eq!(ctx.client.get_waived(&project.id, &token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #4)")]
+fn test_claim_waived_without_waivers_fails() {
+    let ctx = TestContext::new();
+    let (project, token, _) = expired_project(&ctx, &[100]);
+
+    ctx.client
+        .claim_waived(&ctx.manager, &project.id, &token.address);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+fn test_claim_waived_requires_creator() {
+    let ctx = TestContext::new();
+    let (project, token, donators) = expired_project(&ctx, &[100]);
+    ctx.client
+        .waive_refund(&donators[0], &project.id, &token.address);
+
+    ctx.client
+        .claim_waived(&donators[0], &project.id, &token.address);
+}
@@ -8,7 +8,8 @@
 //!
 //! A `Project` is internally stored as two separate ledger entries:
 //!
-//! - [`ProjectConfig`] — written once at registration; never mutated.
+//! - [`ProjectConfig`] — written at registration; only rewritten by rare
+//!   administrative updates (e.g. a deadline extension).
 //! - [`ProjectState`] — written on every deposit and on verification.
 //!
 //! The public API exposes the reconstructed [`Project`] struct for convenience.
@@ -21,13 +22,20 @@
 //! Funding ──► Active ──► Completed
 //!     └──────────────────►┘
 //!     └──► Expired
+//!     └──► Cancelled
 //! Active ──► Expired
+//! Funding | Active ──► Verified ──► Completed   (timelocked release)
+//! Verified ──► Active                           (vetoed release)
+//! Active | Verified ──► Cancelled               (slashed)
 //! ```
 //!
-//! Backward transitions and transitions out of terminal states (`Completed`,
-//! `Expired`) are rejected by `verify_and_release`.
+//! The full transition table lives in [`ProjectStatus::can_transition_to`].
+//! Every entry point that moves a project between states consults it, so
+//! backward transitions (other than a vetoed release) and transitions out of
+//! terminal states (`Completed`, `Expired`, `Cancelled`) are rejected
+//! uniformly with `Error::InvalidStateTransition`.
 
-use soroban_sdk::{contracttype, Address, BytesN, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 /// Current lifecycle state of a funding project.
 #[contracttype]
@@ -41,9 +49,57 @@ pub enum ProjectStatus {
     Completed,
     /// Deadline passed without reaching goal or verification.
     Expired,
+    /// Withdrawn by its creator before any funds arrived, or slashed by an
+    /// admin; donors may refund.
+    Cancelled,
+    /// Oracle verified the proof; funds are timelocked until `claim_release`.
+    Verified,
 }
 
-/// Immutable project configuration, written once at registration.
+/// Return `true` if the state machine allows moving a project from `from`
+/// to `to`. Every status write goes through this check.
+///
+/// | From      | Allowed targets                                 |
+/// |-----------|-------------------------------------------------|
+/// | Funding   | Active, Verified, Completed, Expired, Cancelled |
+/// | Active    | Verified, Completed, Expired, Cancelled         |
+/// | Verified  | Active, Completed, Cancelled                    |
+/// | Completed | —                                               |
+/// | Expired   | —                                               |
+/// | Cancelled | —                                               |
+///
+/// Self-transitions are never allowed. Nothing leads back to `Funding`:
+/// refunds only open once a project is `Expired` or `Cancelled`, so an
+/// `Active` project that is refunded stays in its terminal state.
+pub fn can_transition(from: &ProjectStatus, to: &ProjectStatus) -> bool {
+    matches!(
+        (from, to),
+        (ProjectStatus::Funding, ProjectStatus::Active)
+            | (ProjectStatus::Funding, ProjectStatus::Completed)
+            | (ProjectStatus::Funding, ProjectStatus::Expired)
+            | (ProjectStatus::Funding, ProjectStatus::Cancelled)
+            | (ProjectStatus::Funding, ProjectStatus::Verified)
+            | (ProjectStatus::Active, ProjectStatus::Completed)
+            | (ProjectStatus::Active, ProjectStatus::Expired)
+            | (ProjectStatus::Active, ProjectStatus::Verified)
+            | (ProjectStatus::Active, ProjectStatus::Cancelled)
+            | (ProjectStatus::Verified, ProjectStatus::Active)
+            | (ProjectStatus::Verified, ProjectStatus::Completed)
+            | (ProjectStatus::Verified, ProjectStatus::Cancelled)
+    )
+}
+
+impl ProjectStatus {
+    /// Method form of [`can_transition`].
+    pub fn can_transition_to(&self, next: &ProjectStatus) -> bool {
+        can_transition(self, next)
+    }
+}
+
+/// Project configuration, written at registration.
+///
+/// Treated as immutable by the hot paths (deposits, verification); only
+/// explicit administrative entry points such as `extend_deadline` rewrite it.
 ///
 /// Stored separately from mutable state to reduce write costs on deposits
 /// and verification (only ~20 bytes for state vs ~150 bytes for the full struct).
@@ -56,6 +112,273 @@ pub struct ProjectConfig {
     pub goal: i128,
     pub proof_hash: BytesN<32>,
     pub deadline: u64,
+    /// URI of the off-chain project description (e.g. an IPFS CID).
+    pub metadata_uri: String,
+    /// Oracle exclusively allowed to verify this project, if any.
+    pub assigned_oracle: Option<Address>,
+    /// Release needs an oracle approval plus an admin co-signature.
+    pub requires_admin_cosign: bool,
+    /// Seconds over which released funds vest to the creator; 0 pays at once.
+    pub vesting_duration_secs: u64,
+    /// Seconds between verification and `claim_release`; 0 releases at once.
+    pub release_delay: u64,
+    /// Recipient of released funds; `None` pays the creator.
+    pub payout_address: Option<Address>,
+    /// Per-donor, per-token contribution ceiling; 0 means unlimited.
+    pub max_contribution: i128,
+    /// Per-token funding goals; empty for single-goal projects.
+    pub token_goals: Vec<(Address, i128)>,
+    /// Go `Active` only once every per-token goal is met.
+    pub require_all_goals: bool,
+}
+
+impl ProjectConfig {
+    /// Address that receives released funds.
+    pub fn payout_recipient(&self) -> Address {
+        self.payout_address
+            .clone()
+            .unwrap_or_else(|| self.creator.clone())
+    }
+}
+
+/// Optional settings supplied at registration.
+///
+/// Every field defaults to "off", so `ProjectOptions::default()` registers a
+/// project with the protocol-wide behaviour.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProjectOptions {
+    /// Restrict verification of the project to this oracle.
+    pub assigned_oracle: Option<Address>,
+    /// Require an admin co-signature on top of the oracle's approval.
+    pub requires_admin_cosign: bool,
+    /// Vest released funds linearly over this many seconds instead of
+    /// paying them out at once. 0 disables vesting.
+    pub vesting_duration_secs: u64,
+    /// Hold verified funds for this many seconds before they can be
+    /// released with `claim_release`. 0 releases on verification.
+    pub release_delay: u64,
+    /// Send released funds here (e.g. a treasury or multisig) instead of
+    /// to the creator.
+    pub payout_address: Option<Address>,
+    /// Cap on how much of each token a single donor may contribute.
+    /// 0 means unlimited.
+    pub max_contribution: i128,
+    /// With per-token goals (`register_project_v2`), go `Active` only once
+    /// every goal is met instead of any one. Ignored for single-goal projects.
+    pub require_all_goals: bool,
+    /// Reject deposits that would lift the lifetime total above the goal: a
+    /// single goal caps all accepted tokens combined, per-token goals cap
+    /// each token. Tokens without a goal stay uncapped.
+    pub reject_overfunding: bool,
+    /// On release, pay the creator only up to each token's goal and leave
+    /// the excess for donors to reclaim pro rata through `refund`.
+    pub refund_excess: bool,
+    /// Smallest amount a single deposit may carry, in any token.
+    /// 0 means no minimum.
+    pub min_deposit: i128,
+}
+
+/// A pending oracle approval waiting for an admin co-signature.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseApproval {
+    /// Oracle that approved the release.
+    pub oracle: Address,
+    /// Proof hash the oracle approved.
+    pub proof_hash: BytesN<32>,
+    /// Ledger sequence at which the approval was recorded.
+    pub approved_at: u32,
+}
+
+/// Bond a creator posted at registration, held until release or slashing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectBond {
+    /// Token the bond was paid in.
+    pub token: Address,
+    /// Amount held in escrow.
+    pub amount: i128,
+}
+
+/// Excess of one token left in escrow for donors after a `refund_excess`
+/// release.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExcessRefund {
+    /// Amount set aside for donors at release.
+    pub excess: i128,
+    /// Sum of all donor contributions the excess is shared across.
+    pub basis: i128,
+    /// Contributions of donors who have already claimed their share.
+    pub claimed_basis: i128,
+}
+
+/// Sponsor funds set aside to match donations in one token of a project.
+///
+/// Held outside the project's escrow balance until a deposit draws on it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchingPool {
+    pub sponsor: Address,
+    /// Amount not yet used to match a donation.
+    pub remaining: i128,
+}
+
+/// Funding progress of one accepted token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenProgress {
+    pub token: Address,
+    /// Lifetime deposits in this token, unaffected by releases and refunds.
+    pub raised: i128,
+    /// Goal for this token; 0 when the token carries no goal.
+    pub goal: i128,
+}
+
+/// Funding progress of a project, returned by `get_funding_progress`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingProgress {
+    /// Goal in the first accepted token's units.
+    pub goal: i128,
+    /// Lifetime deposits counted towards `goal`: across every accepted token
+    /// for a single goal, in the first accepted token with per-token goals.
+    pub raised: i128,
+    /// Progress towards the goal in basis points, capped at 10_000.
+    pub progress_bps: u32,
+    /// Per-token breakdown, in accepted-token order.
+    pub tokens: Vec<TokenProgress>,
+    /// Seconds until the deadline; 0 once it has passed.
+    pub time_remaining: u64,
+    pub status: ProjectStatus,
+}
+
+/// Snapshot of a project for auditors, returned by `audit_project`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditReport {
+    pub project_id: u64,
+    pub creator: Address,
+    /// Status as stored; a lapsed project stays `Funding`/`Active` here
+    /// until its expiry is persisted, so compare with `deadline`.
+    pub status: ProjectStatus,
+    pub deadline: u64,
+    /// Escrowed and lifetime amounts for every accepted token.
+    pub balances: Vec<TokenBalance>,
+    pub donation_count: u32,
+    pub unique_donors: u32,
+    pub total_deposited: i128,
+}
+
+/// Registration limits, tunable by a `SuperAdmin` via `set_protocol_params`.
+///
+/// Limits apply when a project is registered or modified; tightening them
+/// never invalidates projects that already exist.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolParams {
+    /// Most tokens a single project may accept.
+    pub max_accepted_tokens: u32,
+    /// Largest funding goal a project may declare.
+    pub max_goal: i128,
+    /// Furthest a deadline may lie in the future, in seconds.
+    pub max_project_duration_secs: u64,
+}
+
+/// Record of a single deposit, issued by `deposit` and `deposit_many`.
+///
+/// Lets a donor prove a contribution on-chain without relying on an indexer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationReceipt {
+    /// Globally unique, increasing across all projects.
+    pub id: u64,
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Ledger timestamp of the deposit.
+    pub timestamp: u64,
+}
+
+/// A recurring donation the contract pulls from the donor's token allowance.
+///
+/// Created by `create_pledge`; each `execute_pledge` moves one period's
+/// amount into the project and pushes `next_due` forward by `period_secs`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pledge {
+    pub id: u64,
+    pub project_id: u64,
+    pub donator: Address,
+    pub token: Address,
+    pub amount_per_period: i128,
+    pub period_secs: u64,
+    /// Pulls still to come; the pledge is removed once this reaches 0.
+    pub remaining_periods: u32,
+    /// Earliest ledger timestamp of the next pull.
+    pub next_due: u64,
+}
+
+/// Protocol-wide counters for dashboards, returned by `get_protocol_stats`.
+///
+/// Status counts follow the stored status, like `get_projects_by_status`:
+/// a lapsed project stays under `funding_count` or `active_count` until its
+/// expiry is persisted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolStats {
+    /// Projects ever registered.
+    pub total_projects: u64,
+    /// Projects whose funds were released.
+    pub total_completed: u64,
+    /// Projects that expired, whether or not donors have reclaimed funds.
+    pub total_expired: u64,
+    /// Refund payouts made; a donor refunded in two tokens counts twice.
+    pub total_refunds: u64,
+    /// Projects currently `Funding`.
+    pub funding_count: u64,
+    /// Projects currently `Active`.
+    pub active_count: u64,
+    /// Projects currently `Verified`, awaiting their timelocked release.
+    pub verified_count: u64,
+    /// Projects cancelled by their creator or slashed by an admin.
+    pub total_cancelled: u64,
+}
+
+/// Linear vesting of one token's released funds to the creator.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /// Amount vesting to the creator, net of the protocol fee.
+    pub total: i128,
+    /// Amount already transferred through `claim_vested`.
+    pub claimed_so_far: i128,
+    /// Ledger timestamp of the release that started vesting.
+    pub released_at: u64,
+}
+
+/// Project configuration as stored by schema version 1.
+///
+/// Only read by `storage::migrate_project` to upgrade old entries.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectConfigV1 {
+    pub id: u64,
+    pub creator: Address,
+    pub accepted_tokens: Vec<Address>,
+    pub goal: i128,
+    pub proof_hash: BytesN<32>,
+    pub deadline: u64,
+}
+
+/// Project state as stored by schema version 1.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectStateV1 {
+    pub status: ProjectStatus,
+    pub donation_count: u32,
 }
 
 /// Mutable project state, updated on deposits and verification.
@@ -67,6 +390,11 @@ pub struct ProjectState {
     pub status: ProjectStatus,
     /// Count of unique (donator, token) pairs that have deposited.
     pub donation_count: u32,
+    /// Count of distinct donator addresses, regardless of token.
+    pub unique_donors: u32,
+    /// Lifetime sum of all deposits across tokens, in raw token units.
+    /// Refunds do not reduce it.
+    pub total_deposited: i128,
 }
 
 /// Full on-chain representation of a funding project.
@@ -78,24 +406,53 @@ pub struct ProjectState {
 pub struct Project {
     /// Auto-incremented unique ID.
     pub id: u64,
-    /// Address that registered and will receive released funds.
+    /// Address that registered the project. Released funds go to it unless
+    /// `payout_address` is set.
     pub creator: Address,
     /// Ordered list of SAC token addresses this project accepts.
-    /// Set once at registration; cannot be changed after creation.
-    /// Length: 1–10 tokens.
+    /// Set at registration; afterwards the creator may add tokens with an
+    /// admin's co-signature or remove unfunded ones. Length: 1–10 tokens.
     pub accepted_tokens: soroban_sdk::Vec<Address>,
     /// Funding goal expressed in the *first* accepted token's units.
-    /// Used as a reference denominator; cross-token goals require off-chain logic.
+    /// For per-token goals this mirrors the first token's entry in `token_goals`.
     pub goal: i128,
     /// Content hash (e.g. IPFS CID digest) of proof artifacts.
     pub proof_hash: soroban_sdk::BytesN<32>,
     /// Ledger timestamp by which the project must be completed.
     pub deadline: u64,
+    /// URI of the off-chain project description (title, summary, media),
+    /// e.g. an IPFS CID. 1–200 bytes.
+    pub metadata_uri: String,
+    /// Oracle exclusively allowed to verify this project.
+    /// `None` means any holder of the global `Oracle` role may verify.
+    pub assigned_oracle: Option<Address>,
+    /// Whether release needs an oracle approval plus an admin co-signature.
+    pub requires_admin_cosign: bool,
+    /// Seconds over which released funds vest to the creator.
+    /// 0 means funds are transferred in full on release.
+    pub vesting_duration_secs: u64,
+    /// Cooling-off period between verification and payout, in seconds.
+    pub release_delay: u64,
+    /// Recipient of released funds. `None` means the creator.
+    pub payout_address: Option<Address>,
+    /// Maximum tracked balance a single donor may hold per token.
+    /// 0 means unlimited.
+    pub max_contribution: i128,
+    /// Funding goal of each token, for projects registered through
+    /// `register_project_v2`. Empty means only `goal` applies.
+    pub token_goals: soroban_sdk::Vec<(Address, i128)>,
+    /// Whether every per-token goal must be met before going `Active`.
+    pub require_all_goals: bool,
     /// Current lifecycle state.
     pub status: ProjectStatus,
     /// Count of unique (token, donator) pairs that have donated.
     /// Informational; incremented on each new deposit.
     pub donation_count: u32,
+    /// Count of distinct donator addresses, regardless of token.
+    pub unique_donors: u32,
+    /// Lifetime sum of all deposits across tokens, in raw token units.
+    /// Mixes decimals when tokens differ; refunds do not reduce it.
+    pub total_deposited: i128,
 }
 
 impl Project {
@@ -116,6 +473,8 @@ impl Project {
 pub struct TokenBalance {
     pub token: Address,
     pub balance: i128,
+    /// Lifetime deposits; unlike `balance`, not reduced by releases or refunds.
+    pub total_raised: i128,
 }
 
 /// Full balance view returned by `get_project_balances`.